@@ -0,0 +1,211 @@
+/// Positions where `unknown` would reject callbacks that extensions usually pass,
+/// because the parameters of a callback are checked contravariantly.
+pub const DEFAULT_KEEP_ANY: &[&str] = &[
+    "commands.registerCommand.callback",
+    "commands.registerTextEditorCommand.callback",
+];
+
+/// Replace every `any` in the declarations with `unknown`,
+/// except under the declaration paths in `keep` (like `commands.registerCommand.callback`).
+///
+/// Each replaced position is reported as a `Diagnostic::AnyToUnknown` with its dotted path.
+pub struct AnyToUnknown {
+    keep: Vec<Vec<String>>,
+}
+
+impl AnyToUnknown {
+    pub fn new(keep: &[String]) -> Self {
+        Self {
+            keep: keep
+                .iter()
+                .map(|path| path.split('.').map(|segment| segment.to_string()).collect())
+                .collect(),
+        }
+    }
+}
+
+impl Default for AnyToUnknown {
+    fn default() -> Self {
+        Self::new(
+            &DEFAULT_KEEP_ANY
+                .iter()
+                .map(|path| path.to_string())
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl crate::Transform for AnyToUnknown {
+    fn transform(
+        &self,
+        mut decls: Vec<crate::pickup::ResultDeclWithComments>,
+        context: &mut crate::TransformContext,
+    ) -> Result<Vec<crate::pickup::ResultDeclWithComments>, crate::Error> {
+        let mut replacer = Replacer {
+            keep: &self.keep,
+            path: vec![],
+            substitutions: vec![],
+        };
+        replacer.replace(&mut decls);
+        context.diagnostics.extend(
+            replacer
+                .substitutions
+                .into_iter()
+                .map(|path| crate::Diagnostic::AnyToUnknown { path }),
+        );
+        Ok(decls)
+    }
+}
+
+struct Replacer<'a> {
+    keep: &'a [Vec<String>],
+    path: Vec<String>,
+    substitutions: Vec<String>,
+}
+
+impl Replacer<'_> {
+    fn replace(&mut self, decls: &mut [crate::pickup::ResultDeclWithComments]) {
+        for result in decls {
+            match &mut result.decl {
+                crate::pickup::ResultDecl::Class(class) => {
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(class, self)
+                }
+                crate::pickup::ResultDecl::Fn(fn_decl) => {
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(fn_decl, self)
+                }
+                crate::pickup::ResultDecl::Var(var_decl) => {
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(var_decl, self)
+                }
+                crate::pickup::ResultDecl::TsInterface(interface) => {
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(interface, self)
+                }
+                crate::pickup::ResultDecl::TsTypeAlias(alias) => {
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(alias, self)
+                }
+                crate::pickup::ResultDecl::TsEnum(enum_decl) => {
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(enum_decl, self)
+                }
+                crate::pickup::ResultDecl::SubModule(sub_module) => {
+                    let segment = match &sub_module.name {
+                        swc_ecma_ast::TsModuleName::Ident(ident) => ident.sym.to_string(),
+                        swc_ecma_ast::TsModuleName::Str(str) => str.value.to_string(),
+                    };
+                    self.enter(segment, |v| v.replace(&mut sub_module.decl_vec));
+                }
+            }
+        }
+    }
+
+    fn enter(&mut self, segment: String, f: impl FnOnce(&mut Self)) {
+        self.path.push(segment);
+        f(self);
+        self.path.pop();
+    }
+
+    fn is_kept(&self) -> bool {
+        self.keep
+            .iter()
+            .any(|keep| self.path.len() >= keep.len() && self.path[..keep.len()] == keep[..])
+    }
+}
+
+impl swc_ecma_visit::VisitMut for Replacer<'_> {
+    fn visit_mut_ts_type_alias_decl(&mut self, decl: &mut swc_ecma_ast::TsTypeAliasDecl) {
+        self.enter(decl.id.sym.to_string(), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(decl, v)
+        });
+    }
+
+    fn visit_mut_class_decl(&mut self, decl: &mut swc_ecma_ast::ClassDecl) {
+        self.enter(decl.ident.sym.to_string(), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(decl, v)
+        });
+    }
+
+    fn visit_mut_class_member(&mut self, member: &mut swc_ecma_ast::ClassMember) {
+        let segment = match crate::decl_path::class_member_name(member) {
+            Some(name) => name.to_string(),
+            None => "[computed]".to_string(),
+        };
+        self.enter(segment, |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(member, v)
+        });
+    }
+
+    fn visit_mut_fn_decl(&mut self, decl: &mut swc_ecma_ast::FnDecl) {
+        self.enter(decl.ident.sym.to_string(), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(decl, v)
+        });
+    }
+
+    fn visit_mut_var_declarator(&mut self, declarator: &mut swc_ecma_ast::VarDeclarator) {
+        self.enter(pat_to_segment(&declarator.name), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(declarator, v)
+        });
+    }
+
+    fn visit_mut_param(&mut self, param: &mut swc_ecma_ast::Param) {
+        self.enter(pat_to_segment(&param.pat), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(param, v)
+        });
+    }
+
+    fn visit_mut_ts_param_prop(&mut self, prop: &mut swc_ecma_ast::TsParamProp) {
+        let segment = match &prop.param {
+            swc_ecma_ast::TsParamPropParam::Ident(ident) => ident.id.sym.to_string(),
+            swc_ecma_ast::TsParamPropParam::Assign(assign) => pat_to_segment(&assign.left),
+        };
+        self.enter(segment, |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(prop, v)
+        });
+    }
+
+    fn visit_mut_ts_interface_decl(&mut self, decl: &mut swc_ecma_ast::TsInterfaceDecl) {
+        self.enter(decl.id.sym.to_string(), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(decl, v)
+        });
+    }
+
+    fn visit_mut_ts_property_signature(
+        &mut self,
+        signature: &mut swc_ecma_ast::TsPropertySignature,
+    ) {
+        self.enter(crate::decl_path::key_to_segment(&signature.key), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(signature, v)
+        });
+    }
+
+    fn visit_mut_ts_method_signature(&mut self, signature: &mut swc_ecma_ast::TsMethodSignature) {
+        self.enter(crate::decl_path::key_to_segment(&signature.key), |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(signature, v)
+        });
+    }
+
+    fn visit_mut_ts_fn_param(&mut self, param: &mut swc_ecma_ast::TsFnParam) {
+        let segment = match param {
+            swc_ecma_ast::TsFnParam::Ident(ident) => ident.id.sym.to_string(),
+            swc_ecma_ast::TsFnParam::Rest(rest) => pat_to_segment(&rest.arg),
+            swc_ecma_ast::TsFnParam::Array(_) | swc_ecma_ast::TsFnParam::Object(_) => {
+                "_".to_string()
+            }
+        };
+        self.enter(segment, |v| {
+            swc_ecma_visit::VisitMutWith::visit_mut_children_with(param, v)
+        });
+    }
+
+    fn visit_mut_ts_keyword_type(&mut self, keyword_type: &mut swc_ecma_ast::TsKeywordType) {
+        if keyword_type.kind == swc_ecma_ast::TsKeywordTypeKind::TsAnyKeyword && !self.is_kept() {
+            keyword_type.kind = swc_ecma_ast::TsKeywordTypeKind::TsUnknownKeyword;
+            self.substitutions.push(self.path.join("."));
+        }
+    }
+}
+
+fn pat_to_segment(pat: &swc_ecma_ast::Pat) -> String {
+    match pat {
+        swc_ecma_ast::Pat::Ident(ident) => ident.id.sym.to_string(),
+        swc_ecma_ast::Pat::Rest(rest) => pat_to_segment(&rest.arg),
+        _ => "_".to_string(),
+    }
+}
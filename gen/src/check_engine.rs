@@ -0,0 +1,81 @@
+//! Uses of the extension API which are newer than the minimum VS Code version of an extension
+
+/// A use of a declaration or member which the minimum version does not have
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Incompatibility {
+    pub file: String,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    /// Dotted path like `window.showQuickPick`
+    pub path: String,
+    /// The first version which has `path`
+    pub since: String,
+}
+
+impl std::fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} requires VS Code {}",
+            self.file, self.line, self.column, self.path, self.since
+        )
+    }
+}
+
+/// Check the extension sources, as (file, source), against `engine`
+/// which is the `engines.vscode` of the manifest like `^1.75.0`.
+///
+/// Paths before the oldest version of `history` are taken as available in every version.
+/// The API comes from the import function of `host`.
+pub fn check(
+    history: &crate::since::History,
+    engine: &str,
+    sources: &[(String, String)],
+    host: &crate::HostModule,
+) -> Result<Vec<Incompatibility>, crate::Error> {
+    let minimum = minimum_version(engine)?;
+    let module_specifiers = crate::usage::module_specifiers(sources, host)?;
+    let mut incompatibilities = Vec::<Incompatibility>::new();
+    for (file, source) in sources {
+        for api_use in crate::usage::uses(file, source, &module_specifiers, host)? {
+            let newer = api_use.prefixes().find_map(|path| {
+                let since = history.since(path)?;
+                (crate::since::parse_version(since).ok()? > minimum).then_some((path, since))
+            });
+            if let Some((path, since)) = newer {
+                incompatibilities.push(Incompatibility {
+                    file: file.clone(),
+                    line: api_use.line,
+                    column: api_use.column,
+                    path: path.to_string(),
+                    since: since.to_string(),
+                });
+            }
+        }
+    }
+    Ok(incompatibilities)
+}
+
+/// `major.minor` of the lowest version `engine` accepts, like `[1, 75]` of `^1.75.0`
+pub fn minimum_version(engine: &str) -> Result<Vec<u32>, crate::Error> {
+    // the lower bound of a range like `>= 1.75.0 < 2` comes first
+    let version = engine
+        .trim_start_matches(['^', '~', '>', '=', ' '])
+        .split_whitespace()
+        .next()
+        .unwrap_or_default();
+    let parts = version
+        .split('.')
+        .take(2)
+        .map(|part| match part {
+            "x" | "X" | "*" => "0",
+            part => part,
+        })
+        .collect::<Vec<_>>()
+        .join(".");
+    crate::since::parse_version(&parts)
+        .map_err(|_| crate::Error::InvalidVersion(engine.to_string()))
+}
@@ -0,0 +1,482 @@
+/// Parse the generated `code` again and check that it covers the declarations in `result_vec`.
+///
+/// - every class, function, variable, enum and namespace member is a key path of the root type
+///   like `VSCodeAPI`
+/// - every class, interface, type alias and enum is exported as a type
+///   with the same type parameters
+/// - functions, methods and explicit constructors have the same overloads
+///   with the same numbers of parameters and the same type parameters
+pub fn check(
+    code: &str,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    type_name: &str,
+) -> Result<(), crate::Error> {
+    let lexer = swc_ecma_parser::lexer::Lexer::new(
+        swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsConfig::default()),
+        swc_ecma_ast::EsVersion::Es2022,
+        swc_ecma_parser::StringInput::new(
+            code,
+            swc_common::source_map::BytePos(1),
+            swc_common::source_map::BytePos(1 + code.len() as u32),
+        ),
+        None,
+    );
+    let module = swc_ecma_parser::Parser::new_from(lexer)
+        .parse_typescript_module()
+        .map_err(|error| {
+            crate::Error::CoverageMismatch(format!(
+                "generated module does not parse: {:?}",
+                error.kind()
+            ))
+        })?;
+
+    let exports = module
+        .body
+        .iter()
+        .filter_map(|module_item| match module_item {
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
+                export_decl,
+            )) => match &export_decl.decl {
+                swc_ecma_ast::Decl::TsTypeAlias(alias) => {
+                    Some((alias.id.sym.clone(), Export::TypeAlias(alias)))
+                }
+                swc_ecma_ast::Decl::TsInterface(interface) => {
+                    Some((interface.id.sym.clone(), Export::Interface(interface)))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<std::collections::HashMap<_, _>>();
+
+    let mut checker = Checker { mismatches: vec![] };
+    match exports.get(&swc_atoms::JsWord::from(type_name)) {
+        Some(Export::TypeAlias(alias)) => match &*alias.type_ann {
+            swc_ecma_ast::TsType::TsTypeLit(type_lit) => {
+                checker.check_values(&[], result_vec, &type_lit.members)
+            }
+            _ => checker.mismatch(&[], format!("{} is not an object type", type_name)),
+        },
+        _ => checker.mismatch(&[], format!("{} is not exported", type_name)),
+    }
+    checker.check_types(result_vec, &exports);
+
+    if checker.mismatches.is_empty() {
+        return Ok(());
+    }
+    Err(crate::Error::CoverageMismatch(
+        checker.mismatches.join("\n"),
+    ))
+}
+
+enum Export<'a> {
+    TypeAlias(&'a swc_ecma_ast::TsTypeAliasDecl),
+    Interface(&'a swc_ecma_ast::TsInterfaceDecl),
+}
+
+/// the parts of a call or construct signature the check compares
+#[derive(PartialEq, Debug)]
+struct Signature {
+    param_count: usize,
+    type_params: Vec<swc_atoms::JsWord>,
+}
+
+impl Signature {
+    fn new(param_count: usize, type_params: &Option<Box<swc_ecma_ast::TsTypeParamDecl>>) -> Self {
+        Signature {
+            param_count,
+            type_params: type_param_names(type_params),
+        }
+    }
+}
+
+struct Checker {
+    mismatches: Vec<String>,
+}
+
+impl Checker {
+    fn mismatch(&mut self, path: &[swc_atoms::JsWord], message: String) {
+        let path = path
+            .iter()
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        self.mismatches.push(format!("{}: {}", path, message));
+    }
+
+    fn check_values(
+        &mut self,
+        parent: &[swc_atoms::JsWord],
+        result_vec: &[crate::pickup::ResultDeclWithComments],
+        members: &[swc_ecma_ast::TsTypeElement],
+    ) {
+        let mut checked_fn_names = Vec::<swc_atoms::JsWord>::new();
+        for result in result_vec {
+            if result.decl.is_type_only() {
+                continue;
+            }
+            let Some(name) = result.decl.name() else {
+                continue;
+            };
+            let path = [parent, std::slice::from_ref(&name)].concat();
+            let elements = elements_by_name(members, &name);
+            if elements.is_empty() {
+                self.mismatch(&path, "missing in the root type".to_string());
+                continue;
+            }
+            match &result.decl {
+                crate::pickup::ResultDecl::Fn(_) => {
+                    if checked_fn_names.contains(&name) {
+                        continue;
+                    }
+                    checked_fn_names.push(name.clone());
+                    let expected = result_vec
+                        .iter()
+                        .filter_map(|result| match &result.decl {
+                            crate::pickup::ResultDecl::Fn(fn_decl) if fn_decl.ident.sym == name => {
+                                Some(Signature::new(
+                                    fn_decl.function.params.len(),
+                                    &fn_decl.function.type_params,
+                                ))
+                            }
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    self.check_signatures(&path, expected, &elements);
+                }
+                crate::pickup::ResultDecl::Class(class) => {
+                    let Some(class_members) = object_members(&elements) else {
+                        self.mismatch(&path, "is not an object type in the root type".to_string());
+                        continue;
+                    };
+                    self.check_class_statics(&path, class, class_members);
+                }
+                crate::pickup::ResultDecl::TsEnum(enum_decl) => {
+                    let Some(enum_members) = object_members(&elements) else {
+                        self.mismatch(&path, "is not an object type in the root type".to_string());
+                        continue;
+                    };
+                    for member in &enum_decl.members {
+                        let member_name = match &member.id {
+                            swc_ecma_ast::TsEnumMemberId::Ident(ident) => ident.sym.clone(),
+                            swc_ecma_ast::TsEnumMemberId::Str(str) => str.value.clone(),
+                        };
+                        if elements_by_name(enum_members, &member_name).is_empty() {
+                            self.mismatch(
+                                &[&path[..], &[member_name]].concat(),
+                                "missing in the root type".to_string(),
+                            );
+                        }
+                    }
+                }
+                crate::pickup::ResultDecl::SubModule(sub_module) => {
+                    match object_members(&elements) {
+                        Some(sub_module_members) => {
+                            self.check_values(&path, &sub_module.decl_vec, sub_module_members)
+                        }
+                        None => self
+                            .mismatch(&path, "is not an object type in the root type".to_string()),
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn check_class_statics(
+        &mut self,
+        path: &[swc_atoms::JsWord],
+        class: &swc_ecma_ast::ClassDecl,
+        members: &[swc_ecma_ast::TsTypeElement],
+    ) {
+        let constructors = class
+            .class
+            .body
+            .iter()
+            .filter_map(|member| match member {
+                swc_ecma_ast::ClassMember::Constructor(constructor) => Some(Signature::new(
+                    constructor.params.len(),
+                    &class.class.type_params,
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // a class without an explicit constructor inherits the one of its super class
+        if !constructors.is_empty() {
+            let emitted = members
+                .iter()
+                .filter_map(|member| match member {
+                    swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(signature) => Some(
+                        Signature::new(signature.params.len(), &signature.type_params),
+                    ),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if constructors != emitted {
+                self.mismatch(
+                    path,
+                    format!(
+                        "constructors {:?} are emitted as {:?}",
+                        constructors, emitted
+                    ),
+                );
+            }
+        }
+        self.check_class_members(path, class, members, true);
+    }
+
+    fn check_class_members(
+        &mut self,
+        path: &[swc_atoms::JsWord],
+        class: &swc_ecma_ast::ClassDecl,
+        members: &[swc_ecma_ast::TsTypeElement],
+        is_static: bool,
+    ) {
+        let mut checked_names = Vec::<swc_atoms::JsWord>::new();
+        for member in &class.class.body {
+            let is_method = match member {
+                swc_ecma_ast::ClassMember::Method(method) if method.is_static == is_static => true,
+                swc_ecma_ast::ClassMember::ClassProp(prop) if prop.is_static == is_static => false,
+                _ => continue,
+            };
+            let Some(name) = crate::decl_path::class_member_name(member) else {
+                continue;
+            };
+            if checked_names.contains(&name) {
+                continue;
+            }
+            checked_names.push(name.clone());
+            let member_path = [path, std::slice::from_ref(&name)].concat();
+            let elements = elements_by_name(members, &name);
+            if elements.is_empty() {
+                self.mismatch(&member_path, "missing".to_string());
+                continue;
+            }
+            if is_method {
+                let expected = class
+                    .class
+                    .body
+                    .iter()
+                    .filter_map(|member| match member {
+                        swc_ecma_ast::ClassMember::Method(method)
+                            if method.is_static == is_static
+                                && crate::decl_path::class_member_name(member).as_ref()
+                                    == Some(&name) =>
+                        {
+                            Some(Signature::new(
+                                method.function.params.len(),
+                                &method.function.type_params,
+                            ))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                self.check_signatures(&member_path, expected, &elements);
+            }
+        }
+    }
+
+    fn check_signatures(
+        &mut self,
+        path: &[swc_atoms::JsWord],
+        expected: Vec<Signature>,
+        elements: &[&swc_ecma_ast::TsTypeElement],
+    ) {
+        let emitted = elements
+            .iter()
+            .flat_map(|element| element_signatures(element))
+            .collect::<Vec<_>>();
+        if expected != emitted {
+            self.mismatch(
+                path,
+                format!("signatures {:?} are emitted as {:?}", expected, emitted),
+            );
+        }
+    }
+
+    fn check_types(
+        &mut self,
+        result_vec: &[crate::pickup::ResultDeclWithComments],
+        exports: &std::collections::HashMap<swc_atoms::JsWord, Export>,
+    ) {
+        for result in result_vec {
+            let Some(name) = result.decl.name() else {
+                continue;
+            };
+            let path = [name.clone()];
+            let (type_params, export) = match &result.decl {
+                crate::pickup::ResultDecl::Class(class) => {
+                    (&class.class.type_params, exports.get(&name))
+                }
+                crate::pickup::ResultDecl::TsInterface(interface) => {
+                    (&interface.type_params, exports.get(&name))
+                }
+                crate::pickup::ResultDecl::TsTypeAlias(alias) => {
+                    (&alias.type_params, exports.get(&name))
+                }
+                crate::pickup::ResultDecl::TsEnum(_) => (&None, exports.get(&name)),
+                crate::pickup::ResultDecl::SubModule(sub_module) => {
+                    self.check_types(&sub_module.decl_vec, exports);
+                    continue;
+                }
+                _ => continue,
+            };
+            let Some(export) = export else {
+                self.mismatch(&path, "type is not exported".to_string());
+                continue;
+            };
+            let exported_type_params = match export {
+                Export::TypeAlias(alias) => &alias.type_params,
+                Export::Interface(interface) => &interface.type_params,
+            };
+            if type_param_names(type_params) != type_param_names(exported_type_params) {
+                self.mismatch(
+                    &path,
+                    format!(
+                        "type parameters {:?} are exported as {:?}",
+                        type_param_names(type_params),
+                        type_param_names(exported_type_params)
+                    ),
+                );
+            }
+            match (&result.decl, export) {
+                (crate::pickup::ResultDecl::Class(class), Export::TypeAlias(alias)) => {
+                    match instance_members(&alias.type_ann) {
+                        Some(members) => self.check_class_members(&path, class, members, false),
+                        None => {
+                            self.mismatch(&path, "is not exported as an object type".to_string())
+                        }
+                    }
+                }
+                (
+                    crate::pickup::ResultDecl::TsInterface(interface),
+                    Export::Interface(exported),
+                ) => self.check_interface_members(&path, &interface.body.body, &exported.body.body),
+                (crate::pickup::ResultDecl::TsInterface(_), _) => {
+                    self.mismatch(&path, "is not exported as an interface".to_string())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn check_interface_members(
+        &mut self,
+        path: &[swc_atoms::JsWord],
+        expected: &[swc_ecma_ast::TsTypeElement],
+        emitted: &[swc_ecma_ast::TsTypeElement],
+    ) {
+        let mut checked_names = Vec::<swc_atoms::JsWord>::new();
+        for element in expected {
+            let Some(name) = crate::decl_path::type_element_name(element) else {
+                continue;
+            };
+            if checked_names.contains(&name) {
+                continue;
+            }
+            checked_names.push(name.clone());
+            let member_path = [path, std::slice::from_ref(&name)].concat();
+            let emitted_elements = elements_by_name(emitted, &name);
+            if emitted_elements.is_empty() {
+                self.mismatch(&member_path, "missing".to_string());
+                continue;
+            }
+            if matches!(element, swc_ecma_ast::TsTypeElement::TsMethodSignature(_)) {
+                let expected = elements_by_name(expected, &name)
+                    .iter()
+                    .flat_map(|element| element_signatures(element))
+                    .collect();
+                self.check_signatures(&member_path, expected, &emitted_elements);
+            }
+        }
+    }
+}
+
+fn elements_by_name<'a>(
+    members: &'a [swc_ecma_ast::TsTypeElement],
+    name: &swc_atoms::JsWord,
+) -> Vec<&'a swc_ecma_ast::TsTypeElement> {
+    members
+        .iter()
+        .filter(|member| crate::decl_path::type_element_name(member).as_ref() == Some(name))
+        .collect()
+}
+
+/// members of `readonly name: { ... }`
+fn object_members<'a>(
+    elements: &[&'a swc_ecma_ast::TsTypeElement],
+) -> Option<&'a [swc_ecma_ast::TsTypeElement]> {
+    match elements {
+        [swc_ecma_ast::TsTypeElement::TsPropertySignature(signature)] => {
+            match &*signature.type_ann.as_ref()?.type_ann {
+                swc_ecma_ast::TsType::TsTypeLit(type_lit) => Some(&type_lit.members),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// members of `SuperClass & { ... }` emitted for the instance type of a class
+fn instance_members(ts_type: &swc_ecma_ast::TsType) -> Option<&[swc_ecma_ast::TsTypeElement]> {
+    match ts_type {
+        swc_ecma_ast::TsType::TsTypeLit(type_lit) => Some(&type_lit.members),
+        swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(intersection),
+        ) => intersection
+            .types
+            .iter()
+            .find_map(|ts_type| instance_members(ts_type)),
+        _ => None,
+    }
+}
+
+/// the call signatures of a method, or of a function typed property
+/// as emitted with `--strict-function-types`
+fn element_signatures(element: &swc_ecma_ast::TsTypeElement) -> Vec<Signature> {
+    match element {
+        swc_ecma_ast::TsTypeElement::TsMethodSignature(signature) => {
+            vec![Signature::new(
+                signature.params.len(),
+                &signature.type_params,
+            )]
+        }
+        swc_ecma_ast::TsTypeElement::TsPropertySignature(signature) => match &signature.type_ann {
+            Some(type_ann) => fn_type_signatures(&type_ann.type_ann),
+            None => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+fn fn_type_signatures(ts_type: &swc_ecma_ast::TsType) -> Vec<Signature> {
+    match ts_type {
+        swc_ecma_ast::TsType::TsFnOrConstructorType(
+            swc_ecma_ast::TsFnOrConstructorType::TsFnType(fn_type),
+        ) => vec![Signature::new(fn_type.params.len(), &fn_type.type_params)],
+        swc_ecma_ast::TsType::TsParenthesizedType(parenthesized) => {
+            fn_type_signatures(&parenthesized.type_ann)
+        }
+        swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(intersection),
+        ) => intersection
+            .types
+            .iter()
+            .flat_map(|ts_type| fn_type_signatures(ts_type))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn type_param_names(
+    type_params: &Option<Box<swc_ecma_ast::TsTypeParamDecl>>,
+) -> Vec<swc_atoms::JsWord> {
+    match type_params {
+        Some(type_params) => type_params
+            .params
+            .iter()
+            .map(|param| param.name.sym.clone())
+            .collect(),
+        None => vec![],
+    }
+}
@@ -0,0 +1,170 @@
+//! Names of members in declaration paths like `TextEditor.options`,
+//! the cases of names derived from them and the declarations a node refers to.
+
+pub fn class_member_name(member: &swc_ecma_ast::ClassMember) -> Option<swc_atoms::JsWord> {
+    match member {
+        swc_ecma_ast::ClassMember::Constructor(_) => Some(swc_atoms::JsWord::from("constructor")),
+        swc_ecma_ast::ClassMember::Method(method) => prop_name_to_name(&method.key),
+        swc_ecma_ast::ClassMember::ClassProp(prop) => prop_name_to_name(&prop.key),
+        _ => None,
+    }
+}
+
+pub fn type_element_name(element: &swc_ecma_ast::TsTypeElement) -> Option<swc_atoms::JsWord> {
+    match element {
+        swc_ecma_ast::TsTypeElement::TsPropertySignature(signature) => expr_to_name(&signature.key),
+        swc_ecma_ast::TsTypeElement::TsMethodSignature(signature) => expr_to_name(&signature.key),
+        swc_ecma_ast::TsTypeElement::TsGetterSignature(signature) => expr_to_name(&signature.key),
+        swc_ecma_ast::TsTypeElement::TsSetterSignature(signature) => expr_to_name(&signature.key),
+        _ => None,
+    }
+}
+
+/// The segment of a class member in declaration paths, `[index]` for an index signature
+pub fn class_member_segment(member: &swc_ecma_ast::ClassMember) -> Option<String> {
+    match member {
+        swc_ecma_ast::ClassMember::TsIndexSignature(_) => Some("[index]".to_string()),
+        _ => class_member_name(member).map(|name| name.to_string()),
+    }
+}
+
+/// The segment of an interface member in declaration paths,
+/// `[call]`, `[new]` and `[index]` for call, construct and index signatures
+pub fn type_element_segment(element: &swc_ecma_ast::TsTypeElement) -> Option<String> {
+    match element {
+        swc_ecma_ast::TsTypeElement::TsCallSignatureDecl(_) => Some("[call]".to_string()),
+        swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(_) => Some("[new]".to_string()),
+        swc_ecma_ast::TsTypeElement::TsIndexSignature(_) => Some("[index]".to_string()),
+        _ => type_element_name(element).map(|name| name.to_string()),
+    }
+}
+
+pub fn enum_member_segment(member: &swc_ecma_ast::TsEnumMember) -> String {
+    match &member.id {
+        swc_ecma_ast::TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+        swc_ecma_ast::TsEnumMemberId::Str(str) => str.value.to_string(),
+    }
+}
+
+/// `window.showInformationMessage` from the path of the parent and a name, the name at the root
+pub fn join(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}.{}", parent, name)
+    }
+}
+
+/// `Window` from `window`
+pub fn upper_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str()),
+        None => String::new(),
+    }
+}
+
+/// `toWebview` from `ToWebview`
+pub fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}", first.to_lowercase(), chars.as_str()),
+        None => String::new(),
+    }
+}
+
+fn prop_name_to_name(prop_name: &swc_ecma_ast::PropName) -> Option<swc_atoms::JsWord> {
+    match prop_name {
+        swc_ecma_ast::PropName::Ident(ident) => Some(ident.sym.clone()),
+        swc_ecma_ast::PropName::Str(str) => Some(str.value.clone()),
+        _ => None,
+    }
+}
+
+/// The segment for a property key, `[computed]` when it has no static name
+pub fn key_to_segment(key: &swc_ecma_ast::Expr) -> String {
+    match expr_to_name(key) {
+        Some(name) => name.to_string(),
+        None => "[computed]".to_string(),
+    }
+}
+
+fn expr_to_name(expr: &swc_ecma_ast::Expr) -> Option<swc_atoms::JsWord> {
+    match expr {
+        swc_ecma_ast::Expr::Ident(ident) => Some(ident.sym.clone()),
+        swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str)) => Some(str.value.clone()),
+        _ => None,
+    }
+}
+
+/// The first segment of every type reference, `typeof` query,
+/// `extends` clause and super class in `node`
+pub fn referenced_names<Node: swc_ecma_visit::VisitWith<ReferenceCollector>>(
+    node: &Node,
+) -> Vec<swc_atoms::JsWord> {
+    let mut collector = ReferenceCollector { names: vec![] };
+    node.visit_with(&mut collector);
+    collector.names
+}
+
+pub struct ReferenceCollector {
+    names: Vec<swc_atoms::JsWord>,
+}
+
+impl ReferenceCollector {
+    fn push(&mut self, name: &swc_atoms::JsWord) {
+        if !self.names.contains(name) {
+            self.names.push(name.clone());
+        }
+    }
+}
+
+impl swc_ecma_visit::Visit for ReferenceCollector {
+    fn visit_ts_entity_name(&mut self, name: &swc_ecma_ast::TsEntityName) {
+        match name {
+            swc_ecma_ast::TsEntityName::Ident(ident) => self.push(&ident.sym),
+            swc_ecma_ast::TsEntityName::TsQualifiedName(qualified) => {
+                self.visit_ts_entity_name(&qualified.left)
+            }
+        }
+    }
+
+    fn visit_ts_expr_with_type_args(&mut self, expr: &swc_ecma_ast::TsExprWithTypeArgs) {
+        if let swc_ecma_ast::Expr::Ident(ident) = &*expr.expr {
+            self.push(&ident.sym);
+        }
+        swc_ecma_visit::VisitWith::visit_children_with(expr, self);
+    }
+
+    fn visit_class(&mut self, class: &swc_ecma_ast::Class) {
+        if let Some(super_class) = &class.super_class {
+            if let swc_ecma_ast::Expr::Ident(ident) = &**super_class {
+                self.push(&ident.sym);
+            }
+        }
+        swc_ecma_visit::VisitWith::visit_children_with(class, self);
+    }
+}
+
+/// [`referenced_names`] of a declaration and, for namespaces, of all its members
+pub fn decl_referenced_names(decl: &crate::pickup::ResultDecl) -> Vec<swc_atoms::JsWord> {
+    match decl {
+        crate::pickup::ResultDecl::Class(class) => referenced_names(class),
+        crate::pickup::ResultDecl::Fn(fn_decl) => referenced_names(fn_decl),
+        crate::pickup::ResultDecl::Var(var_decl) => referenced_names(var_decl),
+        crate::pickup::ResultDecl::TsInterface(interface) => referenced_names(interface),
+        crate::pickup::ResultDecl::TsTypeAlias(alias) => referenced_names(alias),
+        crate::pickup::ResultDecl::TsEnum(enum_decl) => referenced_names(enum_decl),
+        crate::pickup::ResultDecl::SubModule(sub_module) => {
+            let mut names = Vec::<swc_atoms::JsWord>::new();
+            for member in &sub_module.decl_vec {
+                for name in decl_referenced_names(&member.decl) {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+            }
+            names
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Uses of the declarations and members of the extension API which are marked `@deprecated`
+
+/// A use of a deprecated declaration or member
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeprecatedUse {
+    pub file: String,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    /// Dotted path like `workspace.rootPath`
+    pub path: String,
+    /// Text of the `@deprecated` tag
+    pub message: String,
+}
+
+impl std::fmt::Display for DeprecatedUse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} is deprecated",
+            self.file, self.line, self.column, self.path
+        )?;
+        if !self.message.is_empty() {
+            write!(
+                f,
+                ": {}",
+                self.message
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Find the uses of what the module of `host` in index.d.ts marks `@deprecated`
+/// in the extension sources, as (file, source), whose API comes from the import function of `host`
+pub fn check(
+    index_d_ts: &str,
+    sources: &[(String, String)],
+    host: &crate::HostModule,
+) -> Result<Vec<DeprecatedUse>, crate::Error> {
+    let deprecations = crate::diff::deprecations(index_d_ts, host)?
+        .into_iter()
+        .collect::<std::collections::HashMap<_, _>>();
+    let module_specifiers = crate::usage::module_specifiers(sources, host)?;
+    let mut deprecated_uses = Vec::<DeprecatedUse>::new();
+    for (file, source) in sources {
+        for api_use in crate::usage::uses(file, source, &module_specifiers, host)? {
+            let deprecated = api_use
+                .prefixes()
+                .find_map(|path| deprecations.get(path).map(|message| (path, message)));
+            if let Some((path, message)) = deprecated {
+                deprecated_uses.push(DeprecatedUse {
+                    file: file.clone(),
+                    line: api_use.line,
+                    column: api_use.column,
+                    path: path.to_string(),
+                    message: message.clone(),
+                });
+            }
+        }
+    }
+    Ok(deprecated_uses)
+}
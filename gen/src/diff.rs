@@ -0,0 +1,388 @@
+//! Changes of the extension API between two versions of index.d.ts
+
+/// Version of the JSON written for `ApiDiff`
+pub const VERSION: u32 = 1;
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiDiff {
+    pub version: u32,
+    /// Declarations and members only in the new version.
+    /// The members of an added declaration are not listed separately
+    pub added: Vec<Item>,
+    /// Declarations and members only in the old version
+    pub removed: Vec<Item>,
+    /// Declarations and members whose signatures differ
+    pub changed: Vec<Change>,
+    /// Declarations and members of the old version which are deprecated in the new version
+    pub deprecated: Vec<Deprecation>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Item {
+    /// Dotted path like `window.showQuickPick` or `TextEditor.options`
+    pub path: String,
+    pub kind: String,
+    /// One per overload, as TypeScript code
+    pub signatures: Vec<String>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    pub path: String,
+    pub kind: String,
+    pub old: Vec<String>,
+    pub new: Vec<String>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Deprecation {
+    pub path: String,
+    pub kind: String,
+    /// Text of the `@deprecated` tag
+    pub message: String,
+}
+
+/// Compare the declarations of the module of `host` in two index.d.ts sources
+pub fn diff(
+    old_source: &str,
+    new_source: &str,
+    host: &crate::HostModule,
+) -> Result<ApiDiff, crate::Error> {
+    let old = entries(old_source, host)?;
+    let new = entries(new_source, host)?;
+
+    let added = only_in(&new, &old);
+    let removed = only_in(&old, &new);
+    let changed =
+        new.iter()
+            .filter_map(|new_entry| {
+                let old_entry = old.iter().find(|entry| entry.path == new_entry.path)?;
+                (old_entry.kind != new_entry.kind || old_entry.signatures != new_entry.signatures)
+                    .then(|| Change {
+                        path: new_entry.path.clone(),
+                        kind: new_entry.kind.to_string(),
+                        old: old_entry.signatures.clone(),
+                        new: new_entry.signatures.clone(),
+                    })
+            })
+            .collect();
+    let deprecated = new
+        .iter()
+        .filter_map(|new_entry| {
+            let message = new_entry.deprecated.clone()?;
+            let old_entry = old.iter().find(|entry| entry.path == new_entry.path)?;
+            old_entry.deprecated.is_none().then(|| Deprecation {
+                path: new_entry.path.clone(),
+                kind: new_entry.kind.to_string(),
+                message,
+            })
+        })
+        .collect();
+
+    Ok(ApiDiff {
+        version: VERSION,
+        added,
+        removed,
+        changed,
+        deprecated,
+    })
+}
+
+impl ApiDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.deprecated.is_empty()
+    }
+
+    /// The report as Markdown for release notes
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        if self.is_empty() {
+            markdown.push_str("No changes to the extension API.\n");
+            return markdown;
+        }
+        for (title, items) in [("Added", &self.added), ("Removed", &self.removed)] {
+            if items.is_empty() {
+                continue;
+            }
+            markdown.push_str(&format!("## {}\n\n", title));
+            for item in items {
+                markdown.push_str(&format!("- {} `{}`\n", item.kind, item.path));
+                push_signatures(&mut markdown, &item.signatures);
+            }
+            markdown.push('\n');
+        }
+        if !self.changed.is_empty() {
+            markdown.push_str("## Changed\n\n");
+            for change in &self.changed {
+                markdown.push_str(&format!("- {} `{}`\n", change.kind, change.path));
+                markdown.push_str("  - before\n");
+                push_signatures(&mut markdown, &change.old);
+                markdown.push_str("  - after\n");
+                push_signatures(&mut markdown, &change.new);
+            }
+            markdown.push('\n');
+        }
+        if !self.deprecated.is_empty() {
+            markdown.push_str("## Deprecated\n\n");
+            for deprecation in &self.deprecated {
+                markdown.push_str(&format!("- {} `{}`", deprecation.kind, deprecation.path));
+                if !deprecation.message.is_empty() {
+                    markdown.push_str(&format!(": {}", deprecation.message.replace('\n', " ")));
+                }
+                markdown.push('\n');
+            }
+            markdown.push('\n');
+        }
+        markdown
+    }
+}
+
+fn push_signatures(markdown: &mut String, signatures: &[String]) {
+    if signatures.is_empty() {
+        return;
+    }
+    markdown.push_str("  ```ts\n");
+    for signature in signatures {
+        markdown.push_str(&format!("  {}\n", signature));
+    }
+    markdown.push_str("  ```\n");
+}
+
+/// A declaration or member with all of its overloads
+struct Entry {
+    path: String,
+    kind: &'static str,
+    signatures: Vec<String>,
+    /// Text of the `@deprecated` tag when every overload has one
+    deprecated: Option<String>,
+}
+
+/// Entries of `entries` which are not in `other`, without the members of such entries
+fn only_in(entries: &[Entry], other: &[Entry]) -> Vec<Item> {
+    let paths = entries
+        .iter()
+        .filter(|entry| !other.iter().any(|other| other.path == entry.path))
+        .map(|entry| entry.path.as_str())
+        .collect::<Vec<_>>();
+    entries
+        .iter()
+        .filter(|entry| paths.contains(&entry.path.as_str()))
+        .filter(|entry| {
+            !paths.iter().any(|path| {
+                entry
+                    .path
+                    .strip_prefix(path)
+                    .is_some_and(|rest| rest.starts_with('.'))
+            })
+        })
+        .map(|entry| Item {
+            path: entry.path.clone(),
+            kind: entry.kind.to_string(),
+            signatures: entry.signatures.clone(),
+        })
+        .collect()
+}
+
+/// Paths of the declarations and members of index.d.ts, like `window.showQuickPick`
+pub(crate) fn paths(source: &str, host: &crate::HostModule) -> Result<Vec<String>, crate::Error> {
+    Ok(entries(source, host)?
+        .into_iter()
+        .map(|entry| entry.path)
+        .collect())
+}
+
+/// Paths of the declarations and members of index.d.ts which are deprecated,
+/// every overload of them, with the text of the `@deprecated` tag
+pub(crate) fn deprecations(
+    source: &str,
+    host: &crate::HostModule,
+) -> Result<Vec<(String, String)>, crate::Error> {
+    Ok(entries(source, host)?
+        .into_iter()
+        .filter_map(|entry| Some((entry.path, entry.deprecated?)))
+        .collect())
+}
+
+fn entries(source: &str, host: &crate::HostModule) -> Result<Vec<Entry>, crate::Error> {
+    let (result_vec, comments) = crate::parse(source, &host.module_name)?;
+    let mut collector = Collector {
+        comments: &comments,
+        entries: vec![],
+    };
+    collector.decls("", &result_vec);
+    Ok(collector.entries)
+}
+
+struct Collector<'a> {
+    comments: &'a dyn swc_common::comments::Comments,
+    entries: Vec<Entry>,
+}
+
+impl Collector<'_> {
+    fn decls(&mut self, parent: &str, result_vec: &[crate::pickup::ResultDeclWithComments]) {
+        for result in result_vec {
+            let Some(name) = result.decl.name() else {
+                continue;
+            };
+            let path = crate::decl_path::join(parent, &name);
+            let deprecated = deprecation(result.comments.as_deref().unwrap_or_default());
+            match &result.decl {
+                crate::pickup::ResultDecl::SubModule(sub_module) => {
+                    self.push(path.clone(), "namespace", None, deprecated);
+                    self.decls(&path, &sub_module.decl_vec);
+                }
+                crate::pickup::ResultDecl::Class(class) => {
+                    let mut header = class.clone();
+                    header.class.body = vec![];
+                    self.push(
+                        path.clone(),
+                        "class",
+                        Some(header_code(&header)),
+                        deprecated,
+                    );
+                    for member in &class.class.body {
+                        let kind = match member {
+                            swc_ecma_ast::ClassMember::Constructor(_) => "constructor",
+                            swc_ecma_ast::ClassMember::Method(method) => match method.kind {
+                                swc_ecma_ast::MethodKind::Method => "method",
+                                swc_ecma_ast::MethodKind::Getter => "getter",
+                                swc_ecma_ast::MethodKind::Setter => "setter",
+                            },
+                            swc_ecma_ast::ClassMember::ClassProp(_) => "property",
+                            swc_ecma_ast::ClassMember::TsIndexSignature(_) => "indexSignature",
+                            _ => continue,
+                        };
+                        let Some(name) = crate::decl_path::class_member_segment(member) else {
+                            continue;
+                        };
+                        self.push(
+                            crate::decl_path::join(&path, &name),
+                            kind,
+                            Some(code(member)),
+                            self.leading_deprecation(swc_common::Spanned::span(member).lo),
+                        );
+                    }
+                }
+                crate::pickup::ResultDecl::TsInterface(interface) => {
+                    let mut header = interface.clone();
+                    header.body.body = vec![];
+                    self.push(
+                        path.clone(),
+                        "interface",
+                        Some(header_code(&header)),
+                        deprecated,
+                    );
+                    for element in &interface.body.body {
+                        let kind = match element {
+                            swc_ecma_ast::TsTypeElement::TsCallSignatureDecl(_) => "callSignature",
+                            swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(_) => {
+                                "constructSignature"
+                            }
+                            swc_ecma_ast::TsTypeElement::TsIndexSignature(_) => "indexSignature",
+                            swc_ecma_ast::TsTypeElement::TsPropertySignature(_) => "property",
+                            swc_ecma_ast::TsTypeElement::TsMethodSignature(_) => "method",
+                            swc_ecma_ast::TsTypeElement::TsGetterSignature(_) => "getter",
+                            swc_ecma_ast::TsTypeElement::TsSetterSignature(_) => "setter",
+                        };
+                        let Some(name) = crate::decl_path::type_element_segment(element) else {
+                            continue;
+                        };
+                        self.push(
+                            crate::decl_path::join(&path, &name),
+                            kind,
+                            Some(code(element)),
+                            self.leading_deprecation(swc_common::Spanned::span(element).lo),
+                        );
+                    }
+                }
+                crate::pickup::ResultDecl::TsEnum(enum_decl) => {
+                    self.push(path.clone(), "enum", None, deprecated);
+                    for member in &enum_decl.members {
+                        self.push(
+                            crate::decl_path::join(
+                                &path,
+                                &crate::decl_path::enum_member_segment(member),
+                            ),
+                            "enumMember",
+                            Some(code(member)),
+                            self.leading_deprecation(member.span.lo),
+                        );
+                    }
+                }
+                crate::pickup::ResultDecl::Fn(fn_decl) => {
+                    self.push(path, "function", Some(code(fn_decl)), deprecated)
+                }
+                crate::pickup::ResultDecl::Var(var_decl) => {
+                    self.push(path, "variable", Some(code(var_decl)), deprecated)
+                }
+                crate::pickup::ResultDecl::TsTypeAlias(alias) => {
+                    self.push(path, "typeAlias", Some(code(alias)), deprecated)
+                }
+            }
+        }
+    }
+
+    /// Add `signature` as an overload when an entry for `path` exists
+    fn push(
+        &mut self,
+        path: String,
+        kind: &'static str,
+        signature: Option<String>,
+        deprecated: Option<String>,
+    ) {
+        match self.entries.iter_mut().find(|entry| entry.path == path) {
+            Some(entry) => {
+                entry.signatures.extend(signature);
+                if deprecated.is_none() {
+                    entry.deprecated = None;
+                }
+            }
+            None => self.entries.push(Entry {
+                path,
+                kind,
+                signatures: signature.into_iter().collect(),
+                deprecated,
+            }),
+        }
+    }
+
+    fn leading_deprecation(&self, pos: swc_common::BytePos) -> Option<String> {
+        deprecation(&self.comments.get_leading(pos).unwrap_or_default())
+    }
+}
+
+fn deprecation(comments: &[swc_common::comments::Comment]) -> Option<String> {
+    crate::model::parse_doc(comments)?
+        .tags
+        .into_iter()
+        .find(|tag| tag.tag == "deprecated")
+        .map(|tag| tag.text)
+}
+
+/// TypeScript code of `node` on a single line, without comments
+fn code<Node: swc_ecma_codegen::Node>(node: &Node) -> String {
+    crate::node_to_code_string(
+        node,
+        &swc_common::comments::SingleThreadedComments::default(),
+    )
+    .unwrap_or_default()
+    .split_whitespace()
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+/// `code` of a class or interface emptied of members, without the braces
+fn header_code<Node: swc_ecma_codegen::Node>(node: &Node) -> String {
+    code(node)
+        .trim_end_matches("{ }")
+        .trim_end_matches("{}")
+        .trim()
+        .to_string()
+}
@@ -0,0 +1,486 @@
+/// Runtime of the fake API, before the generated `createFakeVSCodeAPI`
+const RUNTIME: &str = r#"/**
+ * A function of the fake API, which records its calls
+ */
+export type FakeFunction<F extends (...args: never) => unknown> = F & {
+  /**
+   * Arguments of every call, oldest first
+   */
+  readonly calls: Parameters<F>[];
+  /**
+   * What the function does. Returns `undefined` until it is replaced
+   */
+  implementation: F;
+};
+
+/**
+ * An event of the fake API, which the test fires
+ */
+export type FakeEvent<T> = ((
+  listener: (e: T) => unknown,
+  thisArgs?: unknown,
+  disposables?: { dispose(): unknown }[],
+) => { dispose(): unknown }) & {
+  fire(data: T): void;
+  readonly listenerCount: number;
+};
+
+/**
+ * An instance of a class of the fake API
+ */
+export type FakeInstance = {
+  readonly constructorArgs: readonly unknown[];
+};
+
+const fakeMark = Symbol("fake");
+
+/**
+ * The type of the function follows where it is put, so the whole fake is checked against the API
+ */
+function fakeFunction<F extends (...args: never) => unknown>(name: string): FakeFunction<F> {
+  const fake = Object.assign(
+    function (this: unknown, ...args: unknown[]): unknown {
+      fake.calls.push(args);
+      return fake.implementation.apply(this, args);
+    },
+    {
+      calls: [] as unknown[][],
+      implementation: (..._args: unknown[]): unknown => undefined,
+      [fakeMark]: name,
+    },
+  );
+  return fake as unknown as FakeFunction<F>;
+}
+
+function fakeEvent<T>(name: string): FakeEvent<T> {
+  const listeners = new Set<(e: T) => unknown>();
+  return Object.assign(
+    (
+      listener: (e: T) => unknown,
+      thisArgs?: unknown,
+      disposables?: { dispose(): unknown }[],
+    ) => {
+      const bound = (e: T) => listener.call(thisArgs, e);
+      listeners.add(bound);
+      const disposable = { dispose: () => listeners.delete(bound) };
+      disposables?.push(disposable);
+      return disposable;
+    },
+    {
+      fire: (data: T) => {
+        for (const listener of [...listeners]) {
+          listener(data);
+        }
+      },
+      get listenerCount() {
+        return listeners.size;
+      },
+      [fakeMark]: name,
+    },
+  );
+}
+
+/**
+ * A variable which the test sets. It is `undefined` until then, whatever its type
+ */
+function unsetVariable<T>(): T {
+  return undefined as T;
+}
+
+/**
+ * Methods are recording functions and events are fireable on every instance.
+ * `fire` of a class with an `event` fires it, like EventEmitter
+ */
+function fakeClass<C>(name: string, members: {
+  readonly methods: readonly string[];
+  readonly events: readonly string[];
+  readonly staticMethods: readonly string[];
+  readonly staticEvents: readonly string[];
+}): C {
+  const instances: unknown[] = [];
+  const fake = class {
+    static readonly [fakeMark] = name;
+    static readonly instances = instances;
+    readonly constructorArgs: readonly unknown[];
+    constructor(...args: unknown[]) {
+      this.constructorArgs = args;
+      const self = this as unknown as Record<string, unknown>;
+      for (const method of members.methods) {
+        self[method] = fakeFunction(`${name}.${method}`);
+      }
+      for (const event of members.events) {
+        self[event] = fakeEvent(`${name}.${event}`);
+      }
+      if (members.methods.includes("fire") && members.events.includes("event")) {
+        (self.fire as FakeFunction<(data: unknown) => unknown>).implementation = (data) =>
+          (self.event as FakeEvent<unknown>).fire(data);
+      }
+      instances.push(this);
+    }
+  };
+  for (const method of members.staticMethods) {
+    Object.assign(fake, { [method]: fakeFunction(`${name}.${method}`) });
+  }
+  for (const event of members.staticEvents) {
+    Object.assign(fake, { [event]: fakeEvent(`${name}.${event}`) });
+  }
+  return fake as unknown as C;
+}
+
+function assertFake(value: unknown): void {
+  if (
+    (typeof value !== "function" && typeof value !== "object") || value === null ||
+    !(fakeMark in value)
+  ) {
+    throw new Error("not a part of the API created by createFakeVSCodeAPI");
+  }
+}
+
+/**
+ * The recording function of the fake API behind `func`
+ *
+ * ```ts
+ * const api = createFakeVSCodeAPI();
+ * asFake(api.window.showInformationMessage).implementation = () => Promise.resolve("OK");
+ * ```
+ */
+export function asFake<F extends (...args: never) => unknown>(func: F): FakeFunction<F> {
+  assertFake(func);
+  return func as FakeFunction<F>;
+}
+
+/**
+ * The fireable emitter of the fake API behind `event`
+ */
+export function asFakeEvent<T>(
+  event: (listener: (e: T) => unknown, ...rest: never[]) => unknown,
+): FakeEvent<T> {
+  assertFake(event);
+  return event as unknown as FakeEvent<T>;
+}
+
+/**
+ * Instances constructed from a class of the fake API, oldest first
+ */
+export function fakeInstances<T>(
+  fakeClass: abstract new (...args: never) => T,
+): readonly (T & FakeInstance)[] {
+  assertFake(fakeClass);
+  return (fakeClass as unknown as { instances: (T & FakeInstance)[] }).instances;
+}
+"#;
+
+/// ```ts
+/// import type { VSCodeAPI } from "./mod.ts";
+/// // FakeFunction, FakeEvent, asFake, asFakeEvent, fakeInstances ...
+/// export function createFakeVSCodeAPI(): VSCodeAPI {
+///   return {
+///     window: {
+///       showInformationMessage: fakeFunction("window.showInformationMessage"),
+///       onDidChangeActiveTextEditor: fakeEvent("window.onDidChangeActiveTextEditor"),
+///       activeTextEditor: undefined,
+///       tabGroups: { close: fakeFunction("window.tabGroups.close"), ... },
+///     },
+///     ViewColumn: { Active: -1, One: 1 },
+///     Disposable: fakeClass("Disposable", { methods: ["dispose"], ... }),
+///   } satisfies VSCodeAPI;
+/// }
+/// ```
+///
+/// Functions are recording stubs and variables typed `Event` are fireable emitters.
+/// Variables typed with an interface are objects of them,
+/// and other variables start `undefined` for the test to set.
+/// The fake functions take their types from the places they are put in,
+/// so the literal is checked against the API type.
+/// The names follow `host`, like `createFakeAzdata`.
+pub fn module(
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    main_module_specifier: &str,
+    host: &crate::HostModule,
+) -> Result<String, crate::Error> {
+    let type_name = &host.type_name;
+    let code = format!(
+        "import type {{ {type_name} }} from {};
+
+{}
+/**
+ * A fake of the API for unit tests outside the host.
+ * Every call creates a new fake
+ */
+export function createFake{type_name}(): {type_name} {{
+  return {} satisfies {type_name};
+}}
+",
+        crate::snippet::string_literal(main_module_specifier),
+        RUNTIME.replace("createFakeVSCodeAPI", &format!("createFake{}", type_name)),
+        object("", result_vec, result_vec, 1),
+    );
+    // the text is kept as written, parsing only checks it
+    crate::snippet::module_items(&code, &swc_common::comments::NoopComments)?;
+    Ok(code)
+}
+
+/// Object literal of the values of `result_vec` whose path starts with `parent`.
+/// `root` is the whole module, where the interfaces are looked up
+fn object(
+    parent: &str,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    root: &[crate::pickup::ResultDeclWithComments],
+    depth: usize,
+) -> String {
+    let mut names = Vec::<String>::new();
+    let mut properties = Vec::<String>::new();
+    for result in result_vec {
+        let Some(name) = result.decl.name() else {
+            continue;
+        };
+        let name = name.to_string();
+        // every overload of a function is a declaration
+        if result.decl.is_type_only() || names.contains(&name) {
+            continue;
+        }
+        let path = crate::decl_path::join(parent, &name);
+        let value = match &result.decl {
+            crate::pickup::ResultDecl::Fn(_) => {
+                format!("fakeFunction({})", crate::snippet::string_literal(&path))
+            }
+            crate::pickup::ResultDecl::Var(var_decl) => {
+                match var_decl.decls.first().map(|decl| &decl.name) {
+                    Some(swc_ecma_ast::Pat::Ident(ident)) => variable_value(
+                        &path,
+                        ident.type_ann.as_deref(),
+                        root,
+                        depth + 1,
+                        &mut vec![],
+                    ),
+                    _ => "unsetVariable()".to_string(),
+                }
+            }
+            crate::pickup::ResultDecl::Class(class) => class_value(&path, &class.class),
+            crate::pickup::ResultDecl::TsEnum(enum_decl) => enum_value(enum_decl, depth + 1),
+            crate::pickup::ResultDecl::SubModule(sub_module) => {
+                object(&path, &sub_module.decl_vec, root, depth + 1)
+            }
+            crate::pickup::ResultDecl::TsInterface(_)
+            | crate::pickup::ResultDecl::TsTypeAlias(_) => continue,
+        };
+        properties.push(format!(
+            "{}: {}",
+            crate::snippet::property_key(&name),
+            value
+        ));
+        names.push(name);
+    }
+    block(&properties, depth)
+}
+
+/// Value of a variable or property typed `type_ann`.
+/// `interfaces` are the names of the interfaces whose objects enclose it, to stop at cycles
+fn variable_value(
+    path: &str,
+    type_ann: Option<&swc_ecma_ast::TsTypeAnn>,
+    root: &[crate::pickup::ResultDeclWithComments],
+    depth: usize,
+    interfaces: &mut Vec<String>,
+) -> String {
+    if crate::snippet::is_event(type_ann) {
+        return format!("fakeEvent({})", crate::snippet::string_literal(path));
+    }
+    match type_ann.map(|type_ann| &*type_ann.type_ann) {
+        Some(swc_ecma_ast::TsType::TsFnOrConstructorType(
+            swc_ecma_ast::TsFnOrConstructorType::TsFnType(_),
+        )) => format!("fakeFunction({})", crate::snippet::string_literal(path)),
+        Some(swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+            type_name: swc_ecma_ast::TsEntityName::Ident(ident),
+            ..
+        })) => interface_value(path, &ident.sym, root, depth, interfaces)
+            .unwrap_or_else(|| "unsetVariable()".to_string()),
+        Some(swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(union),
+        )) if union.types.iter().any(|ty| {
+            matches!(
+                &**ty,
+                swc_ecma_ast::TsType::TsKeywordType(swc_ecma_ast::TsKeywordType {
+                    kind: swc_ecma_ast::TsKeywordTypeKind::TsUndefinedKeyword,
+                    ..
+                })
+            )
+        }) =>
+        {
+            "undefined".to_string()
+        }
+        _ => "unsetVariable()".to_string(),
+    }
+}
+
+/// Object literal of the members of the interface or class `name` and its bases, or `None`
+/// when it is not declared by the module or has call, construct or index signatures
+fn interface_value(
+    path: &str,
+    name: &str,
+    root: &[crate::pickup::ResultDeclWithComments],
+    depth: usize,
+    interfaces: &mut Vec<String>,
+) -> Option<String> {
+    if interfaces.iter().any(|interface| interface == name) {
+        return None;
+    }
+    let elements = crate::type_decls::instance_elements(name, root)?;
+    interfaces.push(name.to_string());
+    let mut names = Vec::<String>::new();
+    let mut properties = Vec::<String>::new();
+    for element in &elements {
+        let Some(member_name) = crate::decl_path::type_element_name(element) else {
+            continue;
+        };
+        let member_name = member_name.to_string();
+        if names.contains(&member_name) {
+            continue;
+        }
+        let member_path = crate::decl_path::join(path, &member_name);
+        let value = match element {
+            swc_ecma_ast::TsTypeElement::TsMethodSignature(_) => {
+                format!(
+                    "fakeFunction({})",
+                    crate::snippet::string_literal(&member_path)
+                )
+            }
+            swc_ecma_ast::TsTypeElement::TsPropertySignature(signature) if signature.optional => {
+                "undefined".to_string()
+            }
+            swc_ecma_ast::TsTypeElement::TsPropertySignature(signature) => variable_value(
+                &member_path,
+                signature.type_ann.as_deref(),
+                root,
+                depth + 1,
+                interfaces,
+            ),
+            swc_ecma_ast::TsTypeElement::TsGetterSignature(signature) => variable_value(
+                &member_path,
+                signature.type_ann.as_deref(),
+                root,
+                depth + 1,
+                interfaces,
+            ),
+            _ => continue,
+        };
+        properties.push(format!(
+            "{}: {}",
+            crate::snippet::property_key(&member_name),
+            value
+        ));
+        names.push(member_name);
+    }
+    interfaces.pop();
+    Some(block(&properties, depth))
+}
+
+fn class_value(path: &str, class: &swc_ecma_ast::Class) -> String {
+    let mut methods = Vec::<String>::new();
+    let mut events = Vec::<String>::new();
+    let mut static_methods = Vec::<String>::new();
+    let mut static_events = Vec::<String>::new();
+    for member in &class.body {
+        let (list, name) = match member {
+            swc_ecma_ast::ClassMember::Method(method)
+                if method.kind == swc_ecma_ast::MethodKind::Method =>
+            {
+                let list = if method.is_static {
+                    &mut static_methods
+                } else {
+                    &mut methods
+                };
+                (list, crate::decl_path::class_member_name(member))
+            }
+            swc_ecma_ast::ClassMember::ClassProp(prop)
+                if crate::snippet::is_event(prop.type_ann.as_deref()) =>
+            {
+                let list = if prop.is_static {
+                    &mut static_events
+                } else {
+                    &mut events
+                };
+                (list, crate::decl_path::class_member_name(member))
+            }
+            _ => continue,
+        };
+        if let Some(name) = name.map(|name| name.to_string()) {
+            if !list.contains(&name) {
+                list.push(name);
+            }
+        }
+    }
+    format!(
+        "fakeClass({}, {{ methods: {}, events: {}, staticMethods: {}, staticEvents: {} }})",
+        crate::snippet::string_literal(path),
+        crate::snippet::string_array(&methods),
+        crate::snippet::string_array(&events),
+        crate::snippet::string_array(&static_methods),
+        crate::snippet::string_array(&static_events),
+    )
+}
+
+/// The values of the members, counting up from the previous number like TypeScript
+fn enum_value(enum_decl: &swc_ecma_ast::TsEnumDecl, depth: usize) -> String {
+    let mut next = Some(0.0);
+    let members = enum_decl
+        .members
+        .iter()
+        .map(|member| {
+            let value = match member.init.as_deref() {
+                None => next.map(number_literal),
+                Some(swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Num(num))) => {
+                    next = Some(num.value + 1.0);
+                    Some(number_literal(num.value))
+                }
+                Some(swc_ecma_ast::Expr::Unary(swc_ecma_ast::UnaryExpr {
+                    op: swc_ecma_ast::UnaryOp::Minus,
+                    arg,
+                    ..
+                })) => match &**arg {
+                    swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Num(num)) => {
+                        next = Some(1.0 - num.value);
+                        Some(number_literal(-num.value))
+                    }
+                    _ => None,
+                },
+                Some(swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str))) => {
+                    next = None;
+                    Some(crate::snippet::string_literal(&str.value))
+                }
+                Some(_) => None,
+            };
+            format!(
+                "{}: {}",
+                crate::snippet::property_key(&crate::decl_path::enum_member_segment(member)),
+                value.unwrap_or_else(|| "undefined".to_string())
+            )
+        })
+        .collect::<Vec<_>>();
+    block(&members, depth)
+}
+
+/// `{ a, b }` with a property per line, inside `depth` levels of indent
+fn block(properties: &[String], depth: usize) -> String {
+    if properties.is_empty() {
+        return "{}".to_string();
+    }
+    let indent = "  ".repeat(depth + 1);
+    format!(
+        "{{\n{}\n{}}}",
+        properties
+            .iter()
+            .map(|property| format!("{}{},", indent, property))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "  ".repeat(depth)
+    )
+}
+
+fn number_literal(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,258 @@
+/// Which declarations end up in the generated module, read from a TOML file
+///
+/// ```toml
+/// exclude = ["notebooks", "scm", "tests", "chat", "lm", "Notebook*"]
+/// ```
+///
+/// Paths are dotted like `window.showInformationMessage` or `TextEditor.options`.
+/// A segment ending with `*` matches every name starting with the rest of it.
+/// Types referenced by kept declarations are always kept, so the output still type-checks.
+/// Interfaces, type aliases, classes and enums which only removed declarations reference
+/// are removed with them, while the ones nothing references are kept.
+#[derive(serde::Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Filter {
+    /// When not empty, only these declarations and the types they reference are kept
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Declarations to remove, together with the types only they reference
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl Filter {
+    pub fn from_toml(source: &str) -> Result<Self, crate::Error> {
+        Ok(toml::from_str(source)?)
+    }
+}
+
+/// Remove the declarations and members selected by `filter`
+/// and the types which are referenced only by removed ones.
+///
+/// Returns the kept declarations in their original order and diagnostics for the user.
+pub fn apply(
+    filter: &Filter,
+    result_vec: Vec<crate::pickup::ResultDeclWithComments>,
+) -> (
+    Vec<crate::pickup::ResultDeclWithComments>,
+    Vec<crate::Diagnostic>,
+) {
+    let mut selector = Selector {
+        include: parse_patterns(&filter.include),
+        exclude: parse_patterns(&filter.exclude),
+        removed_references: vec![],
+    };
+    let entries = selector.select(&[], result_vec);
+
+    // merged declarations like an interface and a namespace share the name
+    let mut references =
+        std::collections::HashMap::<swc_atoms::JsWord, Vec<swc_atoms::JsWord>>::new();
+    for (result, _) in &entries {
+        if let Some(name) = result.decl.name() {
+            references
+                .entry(name)
+                .or_default()
+                .extend(crate::decl_path::decl_referenced_names(&result.decl));
+        }
+    }
+
+    let only_removed_candidates = closure(selector.removed_references.clone(), &references);
+    let roots = entries
+        .iter()
+        .filter_map(|(result, selection)| {
+            let name = result.decl.name()?;
+            let is_root = match selection {
+                Selection::Selected => {
+                    !(is_removed_with_referrers(&result.decl)
+                        && only_removed_candidates.contains(&name)
+                        && !selector.is_explicitly_included(std::slice::from_ref(&name)))
+                }
+                Selection::Removed { .. } => false,
+            };
+            is_root.then_some(name)
+        })
+        .collect::<Vec<_>>();
+    let reachable = closure(roots, &references);
+
+    let mut diagnostics = Vec::<crate::Diagnostic>::new();
+    let result_vec = entries
+        .into_iter()
+        .filter(|(result, selection)| {
+            let Some(name) = result.decl.name() else {
+                return matches!(selection, Selection::Selected);
+            };
+            match selection {
+                Selection::Selected => {
+                    !is_removed_with_referrers(&result.decl) || reachable.contains(&name)
+                }
+                Selection::Removed { explicitly } => {
+                    let is_referenced = reachable.contains(&name);
+                    if is_referenced && *explicitly {
+                        diagnostics.push(crate::Diagnostic::ExcludedButReferenced {
+                            name: name.to_string(),
+                        });
+                    }
+                    is_referenced
+                }
+            }
+        })
+        .map(|(result, _)| result)
+        .collect();
+    (result_vec, diagnostics)
+}
+
+/// Whether the declaration goes away when every declaration referencing it is removed.
+/// Classes and enums are values of the API too, but mostly used through the references
+fn is_removed_with_referrers(decl: &crate::pickup::ResultDecl) -> bool {
+    decl.is_type_only()
+        || matches!(
+            decl,
+            crate::pickup::ResultDecl::Class(_) | crate::pickup::ResultDecl::TsEnum(_)
+        )
+}
+
+enum Selection {
+    Selected,
+    Removed { explicitly: bool },
+}
+
+struct Selector {
+    include: Vec<Vec<String>>,
+    exclude: Vec<Vec<String>>,
+    /// names referenced by the removed declarations and members
+    removed_references: Vec<swc_atoms::JsWord>,
+}
+
+impl Selector {
+    fn select(
+        &mut self,
+        parent: &[swc_atoms::JsWord],
+        result_vec: Vec<crate::pickup::ResultDeclWithComments>,
+    ) -> Vec<(crate::pickup::ResultDeclWithComments, Selection)> {
+        result_vec
+            .into_iter()
+            .map(|mut result| {
+                let Some(name) = result.decl.name() else {
+                    return (result, Selection::Selected);
+                };
+                let path = [parent, &[name]].concat();
+                if self.is_excluded(&path) {
+                    self.remove(&result.decl);
+                    return (result, Selection::Removed { explicitly: true });
+                }
+                if self.is_included(&path) || self.leads_to_included(&path) {
+                    self.select_members(&path, &mut result.decl);
+                    return (result, Selection::Selected);
+                }
+                self.remove(&result.decl);
+                (result, Selection::Removed { explicitly: false })
+            })
+            .collect()
+    }
+
+    fn select_members(&mut self, path: &[swc_atoms::JsWord], decl: &mut crate::pickup::ResultDecl) {
+        match decl {
+            crate::pickup::ResultDecl::Class(class) => {
+                class.class.body.retain(|member| {
+                    let Some(name) = crate::decl_path::class_member_name(member) else {
+                        return true;
+                    };
+                    let is_kept = self.is_member_kept(&[path, &[name]].concat());
+                    if !is_kept {
+                        self.removed_references
+                            .extend(crate::decl_path::referenced_names(member));
+                    }
+                    is_kept
+                });
+            }
+            crate::pickup::ResultDecl::TsInterface(interface) => {
+                interface.body.body.retain(|element| {
+                    let Some(name) = crate::decl_path::type_element_name(element) else {
+                        return true;
+                    };
+                    let is_kept = self.is_member_kept(&[path, &[name]].concat());
+                    if !is_kept {
+                        self.removed_references
+                            .extend(crate::decl_path::referenced_names(element));
+                    }
+                    is_kept
+                });
+            }
+            crate::pickup::ResultDecl::SubModule(sub_module) => {
+                sub_module.decl_vec = self
+                    .select(path, std::mem::take(&mut sub_module.decl_vec))
+                    .into_iter()
+                    .filter_map(|(result, selection)| match selection {
+                        Selection::Selected => Some(result),
+                        Selection::Removed { .. } => None,
+                    })
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+
+    fn is_member_kept(&self, path: &[swc_atoms::JsWord]) -> bool {
+        !self.is_excluded(path) && self.is_included(path)
+    }
+
+    fn remove(&mut self, decl: &crate::pickup::ResultDecl) {
+        self.removed_references
+            .extend(crate::decl_path::decl_referenced_names(decl));
+    }
+
+    fn is_excluded(&self, path: &[swc_atoms::JsWord]) -> bool {
+        self.exclude.iter().any(|pattern| covers(pattern, path))
+    }
+
+    fn is_included(&self, path: &[swc_atoms::JsWord]) -> bool {
+        self.include.is_empty() || self.is_explicitly_included(path)
+    }
+
+    /// whether `path` or one of its ancestors is in `include`
+    fn is_explicitly_included(&self, path: &[swc_atoms::JsWord]) -> bool {
+        self.include.iter().any(|pattern| covers(pattern, path))
+    }
+
+    /// whether one of the descendants of `path` is in `include`
+    fn leads_to_included(&self, path: &[swc_atoms::JsWord]) -> bool {
+        self.include
+            .iter()
+            .any(|pattern| pattern.len() > path.len() && covers(&pattern[..path.len()], path))
+    }
+}
+
+fn parse_patterns(paths: &[String]) -> Vec<Vec<String>> {
+    paths
+        .iter()
+        .map(|path| path.split('.').map(|segment| segment.to_string()).collect())
+        .collect()
+}
+
+/// whether `path` is the declaration `pattern` points to or one of its members
+fn covers(pattern: &[String], path: &[swc_atoms::JsWord]) -> bool {
+    pattern.len() <= path.len()
+        && pattern
+            .iter()
+            .zip(path)
+            .all(|(segment, name)| match segment.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => segment == &**name,
+            })
+}
+
+fn closure(
+    start: Vec<swc_atoms::JsWord>,
+    references: &std::collections::HashMap<swc_atoms::JsWord, Vec<swc_atoms::JsWord>>,
+) -> std::collections::HashSet<swc_atoms::JsWord> {
+    let mut reached = std::collections::HashSet::<swc_atoms::JsWord>::new();
+    let mut stack = start;
+    while let Some(name) = stack.pop() {
+        if let Some(referenced) = references.get(&name) {
+            if reached.insert(name) {
+                stack.extend(referenced.iter().cloned());
+            }
+        }
+    }
+    reached
+}
@@ -0,0 +1,55 @@
+/// The module provided by the host at runtime which the generated code wraps.
+/// `Default` is the extension API of VS Code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostModule {
+    /// Name of the `declare module "<name>"` block to generate from,
+    /// which is also the specifier given to `require`
+    pub module_name: String,
+    /// Name of the type of the whole module, like `VSCodeAPI`
+    pub type_name: String,
+    /// Name of the function which imports the module, like `importVsCodeApi`
+    pub import_function: String,
+    /// Doc comment of the type of the whole module, without `/**` and `*/`
+    pub doc_header: String,
+}
+
+impl Default for HostModule {
+    fn default() -> Self {
+        HostModule {
+            module_name: "vscode".to_string(),
+            type_name: "VSCodeAPI".to_string(),
+            import_function: "importVsCodeApi".to_string(),
+            doc_header: "Type Definition for Visual Studio Code 1.80 Extension API\nSee https://code.visualstudio.com/api for more information".to_string(),
+        }
+    }
+}
+
+impl HostModule {
+    pub(crate) fn type_ident(&self) -> swc_ecma_ast::Ident {
+        swc_ecma_ast::Ident::new(
+            string_cache::Atom::from(self.type_name.as_str()),
+            swc_common::Span::default(),
+        )
+    }
+
+    /// `setVsCodeApi` of `importVsCodeApi`
+    pub(crate) fn inject_function(&self) -> String {
+        let name = self
+            .import_function
+            .strip_prefix("import")
+            .unwrap_or(&self.import_function);
+        format!("set{}", crate::decl_path::upper_first(name))
+    }
+
+    /// The doc header as the text of a block comment
+    pub(crate) fn doc_comment(&self) -> String {
+        format!(
+            "*\n{}\n ",
+            self.doc_header
+                .lines()
+                .map(|line| format!(" * {}", line).trim_end().to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    }
+}
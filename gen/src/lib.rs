@@ -0,0 +1,513 @@
+//! Generator of a Deno module which gives typed access to the VSCode extension API
+//!
+//! ```no_run
+//! let source = std::fs::read_to_string("index.d.ts").unwrap();
+//! let generated = gen::Generator::from_source(&source)
+//!     .strict_function_types(true)
+//!     .generate()
+//!     .unwrap();
+//! for diagnostic in &generated.diagnostics {
+//!     eprintln!("{}", diagnostic);
+//! }
+//! std::fs::write("out.ts", generated.code).unwrap();
+//! ```
+
+mod any_to_unknown;
+pub mod check_engine;
+mod coverage;
+mod decl_path;
+pub mod deprecated_uses;
+pub mod diff;
+mod fake;
+mod filter;
+mod fn_to_type;
+mod host_module;
+pub mod migrate;
+pub mod model;
+mod npm;
+mod overrides;
+mod pickup;
+mod promise_api;
+pub mod protocol;
+mod require_vs_code;
+mod resolve;
+mod rust_bindings;
+mod scope;
+pub mod since;
+mod snippet;
+mod split;
+mod strict_function_types;
+mod strip_deprecated;
+mod trace;
+mod transform;
+mod type_decls;
+mod usage;
+mod vs_code_api_type;
+pub mod webview;
+
+pub use any_to_unknown::{AnyToUnknown, DEFAULT_KEEP_ANY};
+pub use filter::Filter;
+pub use host_module::HostModule;
+pub use npm::NpmPackage;
+pub use overrides::{Action, Override, Overrides};
+pub use pickup::{ResultDecl, ResultDeclWithComments, SubModule};
+pub use require_vs_code::{Loader, DEFAULT_LOADERS};
+pub use strip_deprecated::StripDeprecated;
+pub use transform::{Transform, TransformContext};
+
+/// Options for generating the module from the source of index.d.ts
+pub struct Generator<'a> {
+    source: &'a str,
+    strict_function_types: bool,
+    any_to_unknown: bool,
+    keep_any: Vec<String>,
+    default_keep_any: bool,
+    promise_facade: bool,
+    strip_deprecated: bool,
+    emit_model: bool,
+    rust_bindings: bool,
+    split: bool,
+    npm: Option<NpmPackage>,
+    fake: bool,
+    trace: bool,
+    loaders: Vec<Loader>,
+    host: HostModule,
+    filter: Option<Filter>,
+    overrides: Option<Overrides>,
+    history: Option<since::History>,
+    passes: Vec<Box<dyn Transform>>,
+}
+
+impl<'a> Generator<'a> {
+    pub fn from_source(source: &'a str) -> Self {
+        Generator {
+            source,
+            strict_function_types: false,
+            any_to_unknown: false,
+            keep_any: vec![],
+            default_keep_any: true,
+            promise_facade: false,
+            strip_deprecated: false,
+            emit_model: false,
+            rust_bindings: false,
+            split: false,
+            npm: None,
+            fake: false,
+            trace: false,
+            loaders: DEFAULT_LOADERS.to_vec(),
+            host: HostModule::default(),
+            filter: None,
+            overrides: None,
+            history: None,
+            passes: vec![],
+        }
+    }
+
+    /// Emit methods as readonly function typed properties
+    /// so that their parameters are checked under strictFunctionTypes
+    pub fn strict_function_types(mut self, enabled: bool) -> Self {
+        self.strict_function_types = enabled;
+        self
+    }
+
+    /// Replace `any` with `unknown` across the generated API
+    pub fn any_to_unknown(mut self, enabled: bool) -> Self {
+        self.any_to_unknown = enabled;
+        self
+    }
+
+    /// More declaration paths (like `commands.registerCommand.callback`) where `any` is kept
+    /// by `any_to_unknown`, besides `DEFAULT_KEEP_ANY`
+    pub fn keep_any(mut self, paths: Vec<String>) -> Self {
+        self.keep_any.extend(paths);
+        self
+    }
+
+    /// Whether `any` is kept at `DEFAULT_KEEP_ANY` too. On by default
+    pub fn default_keep_any(mut self, enabled: bool) -> Self {
+        self.default_keep_any = enabled;
+        self
+    }
+
+    /// Also emit VSCodeAPIPromises and importVsCodeApiPromises,
+    /// whose functions return Promise instead of Thenable
+    pub fn promise_facade(mut self, enabled: bool) -> Self {
+        self.promise_facade = enabled;
+        self
+    }
+
+    /// Also emit `traceVSCodeAPI(api, sink)`,
+    /// which passes every call, constructor invocation and event subscription to `sink`
+    pub fn trace(mut self, enabled: bool) -> Self {
+        self.trace = enabled;
+        self
+    }
+
+    /// How `importVsCodeApi` gets the API at runtime, tried in order.
+    /// Defaults to `DEFAULT_LOADERS`. `Loader::Inject` also emits `setVsCodeApi(api)`
+    pub fn loaders(mut self, loaders: Vec<Loader>) -> Self {
+        self.loaders = loaders;
+        self
+    }
+
+    /// The `declare module` block to generate from and the names of the generated module.
+    /// Defaults to the extension API of VS Code
+    pub fn host_module(mut self, host: HostModule) -> Self {
+        self.host = host;
+        self
+    }
+
+    /// Remove the declarations and members marked `@deprecated`
+    pub fn strip_deprecated(mut self, enabled: bool) -> Self {
+        self.strip_deprecated = enabled;
+        self
+    }
+
+    /// Also build the serializable `model::Model` of the generated declarations
+    pub fn emit_model(mut self, enabled: bool) -> Self {
+        self.emit_model = enabled;
+        self
+    }
+
+    /// Also emit the Rust bindings of the host module for wasm-bindgen,
+    /// built from the same `model::Model`
+    pub fn rust_bindings(mut self, enabled: bool) -> Self {
+        self.rust_bindings = enabled;
+        self
+    }
+
+    /// Also split the module into `GeneratedModule::files`: mod.ts re-exporting the others,
+    /// `types/<Name>.ts` per declaration and `namespaces/<name>.ts` per namespace
+    pub fn split(mut self, enabled: bool) -> Self {
+        self.split = enabled;
+        self
+    }
+
+    /// Also emit the module as the npm `package`, with index.d.ts, index.mjs and index.cjs,
+    /// into `GeneratedModule::npm`
+    pub fn npm(mut self, package: NpmPackage) -> Self {
+        self.npm = Some(package);
+        self
+    }
+
+    /// Also emit fake.ts, whose `createFakeVSCodeAPI()` returns a recording fake of the API
+    /// for unit tests outside the extension host
+    pub fn fake(mut self, enabled: bool) -> Self {
+        self.fake = enabled;
+        self
+    }
+
+    /// Narrow the generated API to the declarations selected by `filter`
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Patch the upstream declarations before any other pass
+    pub fn overrides(mut self, overrides: Overrides) -> Self {
+        self.overrides = Some(overrides);
+        self
+    }
+
+    /// Add `@since` to the docs of the declarations and members which appeared in `history`
+    pub fn history(mut self, history: since::History) -> Self {
+        self.history = Some(history);
+        self
+    }
+
+    /// Run `pass` over the declarations after the built-in passes
+    /// and the passes registered before it
+    pub fn pass(mut self, pass: impl Transform + 'static) -> Self {
+        self.passes.push(Box::new(pass));
+        self
+    }
+
+    pub fn generate(&self) -> Result<GeneratedModule, Error> {
+        let (mut result, comments) = parse(self.source, &self.host.module_name)?;
+
+        let any_to_unknown = self.any_to_unknown.then(|| {
+            let defaults = DEFAULT_KEEP_ANY
+                .iter()
+                .filter(|_| self.default_keep_any)
+                .map(|path| path.to_string());
+            AnyToUnknown::new(&defaults.chain(self.keep_any.clone()).collect::<Vec<_>>())
+        });
+        let built_in_passes = [
+            self.overrides
+                .as_ref()
+                .map(|overrides| overrides as &dyn Transform),
+            self.filter.as_ref().map(|filter| filter as &dyn Transform),
+            self.strip_deprecated
+                .then_some(&StripDeprecated as &dyn Transform),
+            any_to_unknown.as_ref().map(|pass| pass as &dyn Transform),
+            self.history
+                .as_ref()
+                .map(|history| history as &dyn Transform),
+        ];
+        let mut context = TransformContext {
+            comments: &comments,
+            diagnostics: vec![],
+        };
+        for pass in built_in_passes
+            .into_iter()
+            .flatten()
+            .chain(self.passes.iter().map(|pass| &**pass))
+        {
+            result = pass.transform(result, &mut context)?;
+        }
+        let diagnostics = context.diagnostics;
+
+        // before emitting, which takes the comments of the members
+        let model =
+            (self.emit_model || self.rust_bindings).then(|| model::build(&result, &comments));
+        let rust = self.rust_bindings.then(|| {
+            rust_bindings::module(model.as_ref().expect("built for the bindings"), &self.host)
+        });
+        let model = model.filter(|_| self.emit_model);
+
+        let fake = if self.fake {
+            Some(fake::module(&result, "./mod.ts", &self.host)?)
+        } else {
+            None
+        };
+
+        let mut module_map = Vec::<swc_ecma_ast::ModuleItem>::new();
+
+        let (code, files, npm) =
+            swc_common::GLOBALS.set(&swc_common::Globals::default(), || {
+                module_map.extend(require_vs_code::module_items(
+                    &comments,
+                    &self.loaders,
+                    &self.host,
+                )?);
+
+                module_map.push(vs_code_api_type::module_item(
+                    &comments, &result, &self.host,
+                ));
+
+                module_map.push(type_decls::value_of_type());
+
+                if self.promise_facade {
+                    module_map.extend(promise_api::module_items(&comments, &result, &self.host)?);
+                }
+
+                if self.trace {
+                    module_map.extend(trace::module_items(&comments, &result, &self.host)?);
+                }
+
+                for module_item in &result {
+                    if let Some(new_module_item) =
+                        type_decls::module_item_transform(module_item, &comments, &self.host)
+                    {
+                        module_map.push(new_module_item);
+                    }
+                }
+
+                let mut module_block = swc_ecma_ast::TsModuleBlock {
+                    span: swc_common::Span::default(),
+                    body: module_map,
+                };
+
+                if self.strict_function_types {
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(
+                        &mut module_block,
+                        &mut strict_function_types::StrictFunctionTypes,
+                    );
+                }
+
+                resolve::check(&module_block, &self.host.type_name)?;
+
+                let files = if self.split {
+                    split::files(module_block.clone(), &result, &comments, &self.host)?
+                } else {
+                    vec![]
+                };
+                let npm = match &self.npm {
+                    Some(package) => npm::files(&module_block, &comments, &self.host, package)?,
+                    None => vec![],
+                };
+                let code = node_to_code_string(&module_block, &comments)?;
+
+                Ok::<_, Error>((code, files, npm))
+            })?;
+
+        coverage::check(&code, &result, &self.host.type_name)?;
+
+        Ok(GeneratedModule {
+            code,
+            files,
+            npm,
+            model,
+            rust,
+            fake,
+            diagnostics,
+        })
+    }
+}
+
+/// Parse index.d.ts and pick the declarations of `declare module "<module_name>"`
+fn parse(
+    source: &str,
+    module_name: &str,
+) -> Result<
+    (
+        Vec<pickup::ResultDeclWithComments>,
+        swc_common::comments::SingleThreadedComments,
+    ),
+    Error,
+> {
+    let (module_items, comments) = parse_d_ts(source)?;
+    let result = pickup::pick_ambient_module(&module_items, module_name, &comments)?;
+    Ok((result, comments))
+}
+
+fn parse_d_ts(
+    source: &str,
+) -> Result<
+    (
+        Vec<swc_ecma_ast::ModuleItem>,
+        swc_common::comments::SingleThreadedComments,
+    ),
+    Error,
+> {
+    let comments = swc_common::comments::SingleThreadedComments::default();
+    let lexer = swc_ecma_parser::lexer::Lexer::new(
+        swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsConfig {
+            dts: true,
+            ..Default::default()
+        }),
+        swc_ecma_ast::EsVersion::Es2022,
+        swc_ecma_parser::StringInput::new(
+            source,
+            swc_common::source_map::BytePos(0),
+            swc_common::source_map::BytePos(source.len().saturating_sub(1) as u32),
+        ),
+        Some(&comments),
+    );
+    let mut parser = swc_ecma_parser::Parser::new_from(lexer);
+    let module = parser
+        .parse_typescript_module()
+        .map_err(|_| Error::ParseModuleError)?;
+    Ok((module.body, comments))
+}
+
+/// Result of `Generator::generate`
+pub struct GeneratedModule {
+    /// TypeScript source of the module
+    pub code: String,
+    /// The module split into files when `Generator::split` is on
+    pub files: Vec<GeneratedFile>,
+    /// Files of the npm package when `Generator::npm` is set
+    pub npm: Vec<GeneratedFile>,
+    /// Model of the declarations when `Generator::emit_model` is on
+    pub model: Option<model::Model>,
+    /// Source of fake.ts when `Generator::fake` is on, importing the types from `./mod.ts`
+    pub fake: Option<String>,
+    /// Source of the Rust bindings when `Generator::rust_bindings` is on
+    pub rust: Option<String>,
+    /// Notes for the user which do not prevent generating the module
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A file of the split module or the npm package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// Path relative to the output directory, like `types/TextEditor.ts`
+    pub path: String,
+    pub code: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// An excluded declaration is kept because kept declarations reference it
+    ExcludedButReferenced { name: String },
+    /// `any` at this declaration path is replaced with `unknown`
+    AnyToUnknown { path: String },
+    /// The deprecated declaration or member at this path is removed
+    StrippedDeprecated { path: String },
+    /// The target of an override does not exist upstream, so the override is skipped
+    OverrideTargetNotFound { path: String },
+    /// Reported by a user `Transform`
+    Pass { pass: String, message: String },
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::ExcludedButReferenced { name } => write!(
+                f,
+                "filter: {} is excluded but kept because kept declarations reference it",
+                name
+            ),
+            Diagnostic::AnyToUnknown { path } => write!(f, "any -> unknown: {}", path),
+            Diagnostic::StrippedDeprecated { path } => write!(f, "strip deprecated: {}", path),
+            Diagnostic::OverrideTargetNotFound { path } => {
+                write!(f, "override: {} does not exist upstream", path)
+            }
+            Diagnostic::Pass { pass, message } => write!(f, "{}: {}", pass, message),
+        }
+    }
+}
+
+/// Emitting takes the comments, so the outputs emitted after the module use a copy
+fn copy_comments(
+    comments: &swc_common::comments::SingleThreadedComments,
+) -> swc_common::comments::SingleThreadedComments {
+    let (leading, trailing) = comments.borrow_all();
+    swc_common::comments::SingleThreadedComments::from_leading_and_trailing(
+        std::rc::Rc::new(std::cell::RefCell::new(leading.clone())),
+        std::rc::Rc::new(std::cell::RefCell::new(trailing.clone())),
+    )
+}
+
+fn node_to_code_string<Node: swc_ecma_codegen::Node>(
+    node: &Node,
+    comments: &dyn swc_common::comments::Comments,
+) -> Result<String, Error> {
+    let cm = swc_common::sync::Lrc::<swc_common::SourceMap>::default();
+    let mut buf = vec![];
+    let writer = swc_ecma_codegen::text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, None);
+
+    let mut emitter = swc_ecma_codegen::Emitter {
+        cfg: Default::default(),
+        comments: Some(&comments),
+        cm: cm.clone(),
+        wr: writer,
+    };
+
+    swc_ecma_codegen::Node::emit_with(&node, &mut emitter).map_err(Error::EmitError)?;
+
+    String::from_utf8(buf).map_err(|error| {
+        Error::EmitError(std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+    })
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("parse module error")]
+    ParseModuleError,
+    #[error("parse snippet error\n{0}")]
+    ParseSnippetError(String),
+    #[error("invalid filter\n{0}")]
+    InvalidFilter(#[from] toml::de::Error),
+    #[error("invalid overrides\n{0}")]
+    InvalidOverrides(String),
+    #[error("invalid loader {0}, expected one of require, global-this-require, create-require and inject")]
+    InvalidLoader(String),
+    #[error("no `declare module \"{0}\"` in the source")]
+    AmbientModuleNotFound(String),
+    #[error("no {0} in the typings of vscode-webview")]
+    WebviewDeclarationNotFound(&'static str),
+    #[error("invalid message protocol\n{0}")]
+    InvalidProtocol(String),
+    #[error("invalid version {0}")]
+    InvalidVersion(String),
+    #[error("parse extension source error\n{0}")]
+    ParseExtensionSourceError(String),
+    #[error("emit error\n{0}")]
+    EmitError(std::io::Error),
+    #[error("dangling type references\n{0}")]
+    DanglingTypeReferences(String),
+    #[error("generated module does not cover the input\n{0}")]
+    CoverageMismatch(String),
+}
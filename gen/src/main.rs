@@ -1,95 +1,611 @@
-mod fn_to_type;
-mod ident;
-mod pickup;
-mod require_vs_code;
-mod type_decls;
-mod vs_code_api_type;
+#[derive(clap::Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    generate: Args,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// generate out.ts (the default)
+    Generate(Box<Args>),
+    /// report the changes of the extension API between two index.d.ts
+    Diff(DiffArgs),
+    /// report the uses of the extension API which are newer than `engines.vscode` of the manifest,
+    /// failing when there is one
+    CheckEngine(CheckEngineArgs),
+    /// report the uses of the extension API which are marked `@deprecated`, failing when there is one
+    Deprecated(DeprecatedArgs),
+    /// rewrite the imports of "vscode" in the extension to this module and importVsCodeApiOrThrow()
+    Migrate(MigrateArgs),
+    /// generate the module for webviews, getWebviewApi() around acquireVsCodeApi
+    Webview(WebviewArgs),
+    /// generate typed messengers for the extension and its webview from two discriminated unions
+    Protocol(ProtocolArgs),
+}
+
+#[derive(clap::Args)]
+struct WebviewArgs {
+    /// read the typings of vscode-webview from this path instead of downloading them from DefinitelyTyped
+    #[arg(long)]
+    input: Option<std::path::PathBuf>,
+
+    /// where to write the module
+    #[arg(long, value_name = "FILE", default_value = "webview.ts")]
+    output: std::path::PathBuf,
+}
+
+#[derive(clap::Args)]
+struct ProtocolArgs {
+    /// TypeScript file declaring the unions of the messages
+    file: std::path::PathBuf,
+
+    /// union of the messages the extension sends to the webview
+    #[arg(long, value_name = "NAME", default_value = "ExtensionToWebview")]
+    to_webview: String,
+
+    /// union of the messages the webview sends to the extension
+    #[arg(long, value_name = "NAME", default_value = "WebviewToExtension")]
+    to_extension: String,
+
+    /// property which tells the messages apart
+    #[arg(long, value_name = "NAME", default_value = "type")]
+    discriminant: String,
+
+    /// specifier to import the unions from. defaults to `./` and the file name of FILE
+    #[arg(long, value_name = "SPECIFIER")]
+    types_module: Option<String>,
+
+    /// where to write the module
+    #[arg(long, value_name = "FILE", default_value = "protocol.ts")]
+    output: std::path::PathBuf,
+}
+
+#[derive(clap::Args)]
+struct DiffArgs {
+    /// index.d.ts of the old version
+    old: std::path::PathBuf,
+
+    /// index.d.ts of the new version
+    new: std::path::PathBuf,
+
+    #[command(flatten)]
+    host: HostArgs,
+
+    /// write the report as JSON instead of Markdown
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct CheckEngineArgs {
+    /// TypeScript sources of the extension, or directories of them
+    #[arg(required = true)]
+    sources: Vec<std::path::PathBuf>,
+
+    /// directory of historical index.d.ts named by version, like `1.76.0.d.ts` or `1.76.0/index.d.ts`
+    #[arg(long, value_name = "DIR")]
+    history: std::path::PathBuf,
+
+    /// package.json of the extension
+    #[arg(long, value_name = "FILE", default_value = "package.json")]
+    manifest: std::path::PathBuf,
+
+    #[command(flatten)]
+    host: HostArgs,
+
+    /// write the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct DeprecatedArgs {
+    /// TypeScript sources of the extension, or directories of them
+    #[arg(required = true)]
+    sources: Vec<std::path::PathBuf>,
+
+    #[command(flatten)]
+    host: HostArgs,
+
+    /// read index.d.ts from this path instead of downloading it from DefinitelyTyped
+    #[arg(long)]
+    input: Option<std::path::PathBuf>,
+
+    /// write the report as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args)]
+struct MigrateArgs {
+    /// TypeScript sources of the extension, or directories of them, rewritten in place
+    #[arg(required = true)]
+    sources: Vec<std::path::PathBuf>,
+
+    /// module specifier of this module in the rewritten imports
+    #[arg(long, value_name = "SPECIFIER", default_value = gen::migrate::DEFAULT_MODULE_SPECIFIER)]
+    module: String,
+
+    #[command(flatten)]
+    host: HostArgs,
+
+    /// only report the files which would change and what has to be rewritten by hand
+    #[arg(long)]
+    dry_run: bool,
+}
+
+/// The host module which is generated from, and which the extension was generated against
+#[derive(clap::Args)]
+struct HostArgs {
+    /// name of the `declare module` block of the host, like "vscode", also given to require
+    #[arg(long, value_name = "NAME", default_value = "vscode")]
+    module_name: String,
+
+    /// name of the type of the whole module
+    #[arg(long, value_name = "NAME", default_value = "VSCodeAPI")]
+    type_name: String,
+
+    /// name of the function which imports the module
+    #[arg(long, value_name = "NAME", default_value = "importVsCodeApi")]
+    import_function: String,
+}
+
+impl HostArgs {
+    fn host_module(self) -> gen::HostModule {
+        gen::HostModule {
+            module_name: self.module_name,
+            type_name: self.type_name,
+            import_function: self.import_function,
+            ..gen::HostModule::default()
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct Args {
+    /// read index.d.ts from this path instead of downloading it from DefinitelyTyped
+    #[arg(long)]
+    input: Option<std::path::PathBuf>,
+
+    /// emit methods as readonly function typed properties
+    /// so that their parameters are checked under strictFunctionTypes
+    #[arg(long)]
+    strict_function_types: bool,
+
+    /// replace `any` with `unknown` across the generated API
+    #[arg(long)]
+    any_to_unknown: bool,
+
+    /// declaration path (like `commands.registerCommand.callback`) where `any` is kept,
+    /// besides the positions in gen::DEFAULT_KEEP_ANY
+    #[arg(long, value_name = "PATH")]
+    keep_any: Vec<String>,
+
+    /// replace `any` at the positions in gen::DEFAULT_KEEP_ANY too,
+    /// unless they are given with --keep-any
+    #[arg(long)]
+    no_default_keep_any: bool,
+
+    /// also list every position where `any` is replaced with `unknown`
+    #[arg(long)]
+    verbose: bool,
+
+    /// also emit VSCodeAPIPromises and importVsCodeApiPromises,
+    /// whose functions return Promise instead of Thenable
+    #[arg(long)]
+    promise_facade: bool,
+
+    /// also emit traceVSCodeAPI(api, sink), which passes every call, constructor invocation
+    /// and event subscription to sink
+    #[arg(long)]
+    trace: bool,
+
+    /// how importVsCodeApi gets the API at runtime, tried in order:
+    /// require, global-this-require, create-require or inject (which adds setVsCodeApi(api)).
+    /// defaults to require
+    #[arg(long, value_name = "LOADER", value_delimiter = ',')]
+    loader: Vec<gen::Loader>,
+
+    #[command(flatten)]
+    host: HostArgs,
+
+    /// doc comment of the type of the whole module. defaults to the one of VS Code
+    #[arg(long, value_name = "TEXT")]
+    doc_header: Option<String>,
+
+    /// remove the declarations and members marked `@deprecated`
+    #[arg(long)]
+    strip_deprecated: bool,
+
+    /// TOML file with `include` and `exclude` declaration paths
+    /// to narrow the generated API
+    #[arg(long, value_name = "FILE")]
+    filter: Option<std::path::PathBuf>,
+
+    /// also write the generated declarations as a versioned JSON model to this path
+    #[arg(long, value_name = "FILE")]
+    emit_json: Option<std::path::PathBuf>,
+
+    /// write the module split into mod.ts, types/ and namespaces/ to this directory
+    /// instead of out.ts
+    #[arg(long, value_name = "DIR")]
+    split: Option<std::path::PathBuf>,
+
+    /// also write the module as an npm package with package.json, index.d.ts, index.mjs
+    /// and index.cjs to this directory
+    #[arg(long, value_name = "DIR")]
+    npm: Option<std::path::PathBuf>,
+
+    /// `name` of package.json
+    #[arg(long, default_value_t = gen::NpmPackage::default().name)]
+    npm_name: String,
+
+    /// `version` of package.json
+    #[arg(long, default_value_t = gen::NpmPackage::default().version)]
+    npm_version: String,
+
+    /// also write the Rust bindings of the API for wasm-bindgen to this path
+    #[arg(long, value_name = "FILE")]
+    emit_rust: Option<std::path::PathBuf>,
+
+    /// also write fake.ts, whose createFakeVSCodeAPI() returns a recording fake of the API
+    /// for unit tests, to this path. it imports the types from ./mod.ts
+    #[arg(long, value_name = "FILE")]
+    emit_fake: Option<std::path::PathBuf>,
+
+    /// directory of historical index.d.ts named by version, like `1.76.0.d.ts` or `1.76.0/index.d.ts`,
+    /// to add `@since` to the docs
+    #[arg(long, value_name = "DIR")]
+    history: Option<std::path::PathBuf>,
+
+    /// write the first version of every declaration and member to this path as JSON.
+    /// requires --history
+    #[arg(long, value_name = "FILE", requires = "history")]
+    since_json: Option<std::path::PathBuf>,
+
+    /// TOML file with overrides which replace, remove or add
+    /// upstream declarations and members
+    #[arg(long, value_name = "FILE")]
+    overrides: Option<std::path::PathBuf>,
+}
 
 #[tokio::main]
 pub async fn main() -> anyhow::Result<()> {
-    let result = reqwest::get(
-        "https://raw.githubusercontent.com/DefinitelyTyped/DefinitelyTyped/master/types/vscode/index.d.ts",
-    )
-    .await?
-    .text()
-    .await?;
-
-    let comments = swc_common::comments::SingleThreadedComments::default();
-    let lexer = swc_ecma_parser::lexer::Lexer::new(
-        swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsConfig {
-            dts: true,
-            ..Default::default()
-        }),
-        swc_ecma_ast::EsVersion::Es2022,
-        swc_ecma_parser::StringInput::new(
-            &result,
-            swc_common::source_map::BytePos(0),
-            swc_common::source_map::BytePos((result.as_bytes().len() - 1) as u32),
-        ),
-        Some(&comments),
-    );
-    let mut parser = swc_ecma_parser::Parser::new_from(lexer);
-    let module = parser
-        .parse_typescript_module()
-        .map_err(|_| Error::ParseModuleError)?;
-
-    let result = pickup::pick_module_item(&module.body, &comments);
-
-    let mut module_map = Vec::<swc_ecma_ast::ModuleItem>::new();
-
-    let result = swc_common::GLOBALS.set(&swc_common::Globals::default(), || {
-        for module_item in require_vs_code::module_items(&comments) {
-            module_map.push(module_item);
-        }
+    let cli = <Cli as clap::Parser>::parse();
+    match cli.command {
+        Some(Command::Generate(args)) => generate(*args).await,
+        None => generate(cli.generate).await,
+        Some(Command::Diff(args)) => diff(args),
+        Some(Command::CheckEngine(args)) => check_engine(args),
+        Some(Command::Deprecated(args)) => deprecated(args).await,
+        Some(Command::Migrate(args)) => migrate(args),
+        Some(Command::Webview(args)) => webview(args).await,
+        Some(Command::Protocol(args)) => protocol(args),
+    }
+}
 
-        module_map.push(vs_code_api_type::module_item(&comments, &result));
+fn diff(args: DiffArgs) -> anyhow::Result<()> {
+    let api_diff = gen::diff::diff(
+        &std::fs::read_to_string(&args.old)?,
+        &std::fs::read_to_string(&args.new)?,
+        &args.host.host_module(),
+    )?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&api_diff)?);
+    } else {
+        print!("{}", api_diff.to_markdown());
+    }
+    Ok(())
+}
 
-        module_map.push(type_decls::value_of_type());
+fn check_engine(args: CheckEngineArgs) -> anyhow::Result<()> {
+    let manifest =
+        serde_json::from_str::<serde_json::Value>(&std::fs::read_to_string(&args.manifest)?)?;
+    let Some(engine) = manifest["engines"]["vscode"].as_str() else {
+        anyhow::bail!("no engines.vscode in {}", args.manifest.display());
+    };
+    let host = args.host.host_module();
+    let history = gen::since::History::from_sources(&read_history(&args.history)?, &host)?;
+    if let Some(oldest) = history.versions.first() {
+        if gen::check_engine::minimum_version(engine)? < gen::since::parse_version(oldest)? {
+            eprintln!(
+                "engines.vscode is {} but the history starts at {}, whose API is taken as available",
+                engine, oldest
+            );
+        }
+    }
+    let mut sources = Vec::<(String, String)>::new();
+    for path in &args.sources {
+        read_sources(path, SOURCE_EXTENSIONS, &mut sources)?;
+    }
 
-        for module_item in result {
-            if let Some(new_module_item) =
-                type_decls::module_item_transform(&module_item, &comments)
-            {
-                module_map.push(new_module_item);
-            }
+    let incompatibilities = gen::check_engine::check(&history, engine, &sources, &host)?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&incompatibilities)?);
+    } else {
+        for incompatibility in &incompatibilities {
+            println!("{} (engines.vscode is {})", incompatibility, engine);
         }
+    }
+    if !incompatibilities.is_empty() {
+        anyhow::bail!(
+            "{} uses of the extension API are newer than engines.vscode {}",
+            incompatibilities.len(),
+            engine
+        );
+    }
+    Ok(())
+}
 
-        swc_ecma_ast::TsModuleBlock {
-            span: swc_common::Span::default(),
-            body: module_map,
+async fn deprecated(args: DeprecatedArgs) -> anyhow::Result<()> {
+    let source = read_index_d_ts(args.input.as_deref()).await?;
+    let mut sources = Vec::<(String, String)>::new();
+    for path in &args.sources {
+        read_sources(path, SOURCE_EXTENSIONS, &mut sources)?;
+    }
+
+    let deprecated_uses = gen::deprecated_uses::check(&source, &sources, &args.host.host_module())?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&deprecated_uses)?);
+    } else {
+        for deprecated_use in &deprecated_uses {
+            println!("{}", deprecated_use);
         }
-    });
+    }
+    if !deprecated_uses.is_empty() {
+        anyhow::bail!(
+            "{} uses of the extension API are deprecated",
+            deprecated_uses.len()
+        );
+    }
+    Ok(())
+}
 
-    let code = node_to_code_string(&result, &comments)?;
+fn migrate(args: MigrateArgs) -> anyhow::Result<()> {
+    let mut sources = Vec::<(String, String)>::new();
+    for path in &args.sources {
+        // the rewritten imports are TypeScript
+        read_sources(path, TYPESCRIPT_EXTENSIONS, &mut sources)?;
+    }
+    if let Some((file, _)) = sources.iter().find(|(file, _)| {
+        !TYPESCRIPT_EXTENSIONS.iter().any(|extension| {
+            std::path::Path::new(file)
+                .extension()
+                .is_some_and(|e| e == *extension)
+        })
+    }) {
+        anyhow::bail!("migrate rewrites TypeScript sources only, not {}", file);
+    }
 
-    let _ = std::fs::write("./out.ts", code)?;
+    let host = args.host.host_module();
+    let mut note_count = 0;
+    for (file, source) in &sources {
+        let migration = gen::migrate::migrate(file, source, &args.module, &host)?;
+        for note in &migration.notes {
+            println!("{}:{}:{}: {}", file, note.line, note.column, note.message);
+        }
+        note_count += migration.notes.len();
+        if migration.code != *source {
+            if args.dry_run {
+                println!("{}: would be rewritten", file);
+            } else {
+                std::fs::write(file, &migration.code)?;
+                println!("{}: rewritten", file);
+            }
+        }
+    }
+    if note_count != 0 {
+        anyhow::bail!("{} constructs have to be rewritten by hand", note_count);
+    }
+    Ok(())
+}
+
+fn protocol(args: ProtocolArgs) -> anyhow::Result<()> {
+    let source = std::fs::read_to_string(&args.file)?;
+    let options = gen::protocol::Options {
+        to_webview: args.to_webview,
+        to_extension: args.to_extension,
+        discriminant: args.discriminant,
+        types_module: args.types_module.unwrap_or_else(|| {
+            format!(
+                "./{}",
+                args.file
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            )
+        }),
+    };
+    let code = gen::protocol::generate(&args.file.to_string_lossy(), &source, &options)?;
+    std::fs::write(&args.output, code)?;
+    Ok(())
+}
 
+async fn webview(args: WebviewArgs) -> anyhow::Result<()> {
+    let source = match &args.input {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => reqwest::get(gen::webview::TYPES_URL).await?.text().await?,
+    };
+    std::fs::write(&args.output, gen::webview::generate(&source)?)?;
     Ok(())
 }
 
-fn node_to_code_string<Node: swc_ecma_codegen::Node>(
-    node: &Node,
-    comments: &dyn swc_common::comments::Comments,
-) -> anyhow::Result<String> {
-    let cm = swc_common::sync::Lrc::<swc_common::SourceMap>::default();
-    let mut buf = vec![];
-    let writer = swc_ecma_codegen::text_writer::JsWriter::new(cm.clone(), "\n", &mut buf, None);
+async fn generate(args: Args) -> anyhow::Result<()> {
+    let source = read_index_d_ts(args.input.as_deref()).await?;
 
-    let mut emitter = swc_ecma_codegen::Emitter {
-        cfg: Default::default(),
-        comments: Some(&comments),
-        cm: cm.clone(),
-        wr: writer,
+    let host = gen::HostModule {
+        doc_header: args
+            .doc_header
+            .clone()
+            .unwrap_or_else(|| gen::HostModule::default().doc_header),
+        ..args.host.host_module()
     };
+    let mut generator = gen::Generator::from_source(&source)
+        .strict_function_types(args.strict_function_types)
+        .any_to_unknown(args.any_to_unknown)
+        .promise_facade(args.promise_facade)
+        .trace(args.trace)
+        .strip_deprecated(args.strip_deprecated)
+        .emit_model(args.emit_json.is_some())
+        .rust_bindings(args.emit_rust.is_some())
+        .split(args.split.is_some())
+        .fake(args.emit_fake.is_some())
+        .host_module(host.clone());
+    generator = generator
+        .keep_any(args.keep_any.clone())
+        .default_keep_any(!args.no_default_keep_any);
+    if !args.loader.is_empty() {
+        generator = generator.loaders(args.loader.clone());
+    }
+    if let Some(path) = &args.overrides {
+        generator =
+            generator.overrides(gen::Overrides::from_toml(&std::fs::read_to_string(path)?)?);
+    }
+    if let Some(dir) = &args.history {
+        let history = gen::since::History::from_sources(&read_history(dir)?, &host)?;
+        if let Some(path) = &args.since_json {
+            std::fs::write(path, serde_json::to_string_pretty(&history)?)?;
+        }
+        generator = generator.history(history);
+    }
+    if args.npm.is_some() {
+        generator = generator.npm(gen::NpmPackage {
+            name: args.npm_name.clone(),
+            version: args.npm_version.clone(),
+        });
+    }
+    if let Some(path) = &args.filter {
+        generator = generator.filter(gen::Filter::from_toml(&std::fs::read_to_string(path)?)?);
+    }
+
+    let generated = generator.generate()?;
+    let mut any_to_unknown = 0;
+    for diagnostic in &generated.diagnostics {
+        if matches!(diagnostic, gen::Diagnostic::AnyToUnknown { .. }) {
+            any_to_unknown += 1;
+            if !args.verbose {
+                continue;
+            }
+        }
+        eprintln!("{}", diagnostic);
+    }
+    if any_to_unknown > 0 && !args.verbose {
+        eprintln!(
+            "any -> unknown: {} positions (--verbose lists them)",
+            any_to_unknown
+        );
+    }
+
+    match &args.split {
+        Some(dir) => {
+            for file in &generated.files {
+                let path = dir.join(&file.path);
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(path, &file.code)?;
+            }
+        }
+        None => std::fs::write("./out.ts", generated.code)?,
+    }
+
+    if let (Some(path), Some(model)) = (&args.emit_json, &generated.model) {
+        std::fs::write(path, serde_json::to_string_pretty(model)?)?;
+    }
+
+    if let (Some(path), Some(fake)) = (&args.emit_fake, &generated.fake) {
+        std::fs::write(path, fake)?;
+    }
+
+    if let Some(dir) = &args.npm {
+        std::fs::create_dir_all(dir)?;
+        for file in &generated.npm {
+            std::fs::write(dir.join(&file.path), &file.code)?;
+        }
+    }
 
-    swc_ecma_codegen::Node::emit_with(&node, &mut emitter)?;
+    if let (Some(path), Some(rust)) = (&args.emit_rust, &generated.rust) {
+        std::fs::write(path, rust)?;
+    }
 
-    let code = String::from_utf8(buf)?;
-    Ok(code)
+    Ok(())
+}
+
+/// Read index.d.ts from `input`, or download it from DefinitelyTyped
+async fn read_index_d_ts(input: Option<&std::path::Path>) -> anyhow::Result<String> {
+    Ok(match input {
+        Some(path) => std::fs::read_to_string(path)?,
+        None => {
+            reqwest::get(
+                "https://raw.githubusercontent.com/DefinitelyTyped/DefinitelyTyped/master/types/vscode/index.d.ts",
+            )
+            .await?
+            .text()
+            .await?
+        }
+    })
+}
+
+/// Read `<version>.d.ts` and `<version>/index.d.ts` in `dir` as (version, source)
+fn read_history(dir: &std::path::Path) -> anyhow::Result<Vec<(String, String)>> {
+    let mut sources = Vec::<(String, String)>::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if path.is_dir() && path.join("index.d.ts").is_file() {
+            sources.push((file_name, std::fs::read_to_string(path.join("index.d.ts"))?));
+        } else if let Some(version) = file_name.strip_suffix(".d.ts") {
+            sources.push((version.to_string(), std::fs::read_to_string(&path)?));
+        }
+    }
+    if sources.is_empty() {
+        anyhow::bail!("no index.d.ts in {}", dir.display());
+    }
+    Ok(sources)
 }
 
-#[derive(thiserror::Error, Debug)]
-pub enum Error {
-    #[error("parse module error")]
-    ParseModuleError,
+const TYPESCRIPT_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts"];
+
+const SOURCE_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Read the sources with one of `extensions` at `path`, recursively for a directory
+/// except `node_modules` and hidden directories, as (path, source)
+fn read_sources(
+    path: &std::path::Path,
+    extensions: &[&str],
+    sources: &mut Vec<(String, String)>,
+) -> anyhow::Result<()> {
+    if path.is_dir() {
+        let mut entries = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        entries.sort();
+        for entry in entries {
+            let name = entry
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if entry.is_dir() && (name == "node_modules" || name.starts_with('.')) {
+                continue;
+            }
+            if entry.is_dir()
+                || (extensions
+                    .iter()
+                    .any(|extension| entry.extension().is_some_and(|e| e == *extension))
+                    && !name.ends_with(".d.ts"))
+            {
+                read_sources(&entry, extensions, sources)?;
+            }
+        }
+    } else {
+        sources.push((path.display().to_string(), std::fs::read_to_string(path)?));
+    }
+    Ok(())
 }
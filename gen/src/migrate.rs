@@ -0,0 +1,726 @@
+//! Rewrite of an extension which imports the `vscode` module of Node.js
+//! to the type imports of this module and `importVsCodeApiOrThrow()`
+//!
+//! ```ts
+//! import * as vscode from "vscode";
+//! import { window } from "vscode";
+//! let editor: vscode.TextEditor | undefined = window.activeTextEditor;
+//! ```
+//!
+//! becomes
+//!
+//! ```ts
+//! import { type TextEditor, importVsCodeApiOrThrow } from "jsr:@narumincho/vscode";
+//!
+//! const vscode = importVsCodeApiOrThrow();
+//!
+//! let editor: TextEditor | undefined = vscode.window.activeTextEditor;
+//! ```
+//!
+//! The source is edited in place, so formatting and comments outside the rewritten parts are kept.
+//! Names which functions, blocks and type parameters declare again shadow the imports
+//! and are left as they are.
+
+/// Module specifier of this module in the rewritten imports
+pub const DEFAULT_MODULE_SPECIFIER: &str = "jsr:@narumincho/vscode";
+
+/// The rewritten source and what could not be rewritten
+pub struct Migration {
+    pub code: String,
+    pub notes: Vec<Note>,
+}
+
+/// A construct which has to be rewritten by hand
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Note {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    pub message: String,
+}
+
+/// Rewrite the imports of `"vscode"` in `source` to imports of `module_specifier`.
+/// `file` decides TSX by its extension.
+/// The module name, the type name and the import function are the ones of `host`
+pub fn migrate(
+    file: &str,
+    source: &str,
+    module_specifier: &str,
+    host: &crate::HostModule,
+) -> Result<Migration, crate::Error> {
+    let module = crate::usage::parse(file, source)?;
+
+    let mut imports = Vec::<swc_common::Span>::new();
+    let mut namespaces = Vec::<(String, bool)>::new();
+    let mut named = std::collections::HashMap::<String, String>::new();
+    for item in &module.body {
+        match item {
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::Import(import))
+                if *import.src.value == *host.module_name =>
+            {
+                imports.push(import.span);
+                for specifier in &import.specifiers {
+                    match specifier {
+                        swc_ecma_ast::ImportSpecifier::Namespace(namespace) => {
+                            namespaces.push((namespace.local.sym.to_string(), import.type_only))
+                        }
+                        // `import vscode from "vscode"` under esModuleInterop
+                        swc_ecma_ast::ImportSpecifier::Default(default) => {
+                            namespaces.push((default.local.sym.to_string(), import.type_only))
+                        }
+                        swc_ecma_ast::ImportSpecifier::Named(specifier) => {
+                            let imported = match &specifier.imported {
+                                Some(swc_ecma_ast::ModuleExportName::Ident(ident)) => {
+                                    ident.sym.to_string()
+                                }
+                                Some(swc_ecma_ast::ModuleExportName::Str(str)) => {
+                                    str.value.to_string()
+                                }
+                                None => specifier.local.sym.to_string(),
+                            };
+                            named.insert(specifier.local.sym.to_string(), imported);
+                        }
+                    }
+                }
+            }
+            // `import vscode = require("vscode")`
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::TsImportEquals(
+                import,
+            )) if matches!(
+                &import.module_ref,
+                swc_ecma_ast::TsModuleRef::TsExternalModuleRef(module_ref)
+                    if *module_ref.expr.value == *host.module_name
+            ) =>
+            {
+                imports.push(import.span);
+                namespaces.push((import.id.sym.to_string(), import.is_type_only));
+            }
+            _ => {}
+        }
+    }
+
+    let mut declared = DeclaredNames::default();
+    swc_ecma_visit::Visit::visit_module(&mut declared, &module);
+    // the imports of "vscode" go away
+    for name in namespaces.iter().map(|(name, _)| name).chain(named.keys()) {
+        declared.0.remove(name);
+    }
+    let api = match namespaces.iter().find(|(_, type_only)| !type_only) {
+        Some((name, _)) => name.clone(),
+        None => ["vscode", "vscodeApi"]
+            .into_iter()
+            .find(|name| !declared.0.contains(*name))
+            .unwrap_or("vscodeApi")
+            .to_string(),
+    };
+
+    let mut migrator = Migrator {
+        source,
+        host,
+        api,
+        namespaces: namespaces.iter().map(|(name, _)| name.clone()).collect(),
+        named,
+        declared: declared.0,
+        scopes: vec![],
+        edits: vec![],
+        type_imports: vec![],
+        uses_api: false,
+        notes: vec![],
+    };
+    swc_ecma_visit::Visit::visit_module(&mut migrator, &module);
+
+    let Some((first, rest)) = imports.split_first() else {
+        return Ok(Migration {
+            code: source.to_string(),
+            notes: migrator.notes,
+        });
+    };
+    let import = migrator.import(module_specifier);
+    let api_object = migrator.api_object(&namespaces);
+    let last_import = module
+        .body
+        .iter()
+        .rev()
+        .find_map(|item| match item {
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::Import(import)) => {
+                Some(import.span)
+            }
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::TsImportEquals(
+                import,
+            )) => Some(import.span),
+            _ => None,
+        })
+        .unwrap_or(*first);
+    let after_last_import = |text: String| match (text.is_empty(), api_object.is_empty()) {
+        (_, true) => text,
+        (true, false) => format!("\n{}", api_object),
+        (false, false) => format!("{}\n\n{}", text, api_object),
+    };
+    for span in std::iter::once(first).chain(rest) {
+        let text = if span == first {
+            import.clone()
+        } else {
+            String::new()
+        };
+        let text = if *span == last_import {
+            after_last_import(text)
+        } else {
+            text
+        };
+        migrator.edits.push((*span, text));
+    }
+    if !imports.contains(&last_import) && !api_object.is_empty() {
+        migrator.edits.push((
+            swc_common::Span::new(last_import.hi, last_import.hi, Default::default()),
+            format!("\n\n{}", api_object),
+        ));
+    }
+
+    let mut code = source.to_string();
+    let mut edits = migrator.edits;
+    edits.sort_by_key(|(span, _)| std::cmp::Reverse(span.lo));
+    for (span, text) in edits {
+        let range = offset(span.lo)..offset(span.hi);
+        // the line of a removed import goes with it
+        let range = if text.is_empty() && code[range.end..].starts_with('\n') {
+            range.start..range.end + 1
+        } else {
+            range
+        };
+        code.replace_range(range, &text);
+    }
+    let mut notes = migrator.notes;
+    notes.sort_by_key(|note| (note.line, note.column));
+    Ok(Migration { code, notes })
+}
+
+fn offset(pos: swc_common::BytePos) -> usize {
+    (pos.0 - crate::usage::START) as usize
+}
+
+/// Every name declared in a module, to avoid them for new names
+#[derive(Default)]
+struct DeclaredNames(std::collections::HashSet<String>);
+
+impl swc_ecma_visit::Visit for DeclaredNames {
+    fn visit_binding_ident(&mut self, ident: &swc_ecma_ast::BindingIdent) {
+        self.0.insert(ident.id.sym.to_string());
+    }
+
+    fn visit_fn_decl(&mut self, fn_decl: &swc_ecma_ast::FnDecl) {
+        self.0.insert(fn_decl.ident.sym.to_string());
+        swc_ecma_visit::VisitWith::visit_children_with(fn_decl, self);
+    }
+
+    fn visit_class_decl(&mut self, class_decl: &swc_ecma_ast::ClassDecl) {
+        self.0.insert(class_decl.ident.sym.to_string());
+        swc_ecma_visit::VisitWith::visit_children_with(class_decl, self);
+    }
+
+    fn visit_ts_interface_decl(&mut self, interface: &swc_ecma_ast::TsInterfaceDecl) {
+        self.0.insert(interface.id.sym.to_string());
+        swc_ecma_visit::VisitWith::visit_children_with(interface, self);
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, alias: &swc_ecma_ast::TsTypeAliasDecl) {
+        self.0.insert(alias.id.sym.to_string());
+        swc_ecma_visit::VisitWith::visit_children_with(alias, self);
+    }
+
+    fn visit_ts_enum_decl(&mut self, enum_decl: &swc_ecma_ast::TsEnumDecl) {
+        self.0.insert(enum_decl.id.sym.to_string());
+    }
+
+    fn visit_ts_type_param(&mut self, type_param: &swc_ecma_ast::TsTypeParam) {
+        self.0.insert(type_param.name.sym.to_string());
+    }
+
+    fn visit_import_specifier(&mut self, specifier: &swc_ecma_ast::ImportSpecifier) {
+        let local = match specifier {
+            swc_ecma_ast::ImportSpecifier::Named(named) => &named.local,
+            swc_ecma_ast::ImportSpecifier::Default(default) => &default.local,
+            swc_ecma_ast::ImportSpecifier::Namespace(namespace) => &namespace.local,
+        };
+        self.0.insert(local.sym.to_string());
+    }
+}
+
+struct Migrator<'a> {
+    source: &'a str,
+    host: &'a crate::HostModule,
+    /// Name of the API object, the namespace import when there is one
+    api: String,
+    /// Local names of the namespace imports of `"vscode"`
+    namespaces: std::collections::HashSet<String>,
+    /// Local names of the named imports of `"vscode"`, with the imported names
+    named: std::collections::HashMap<String, String>,
+    declared: std::collections::HashSet<String>,
+    /// Names declared by the enclosing functions, blocks and declarations, innermost last
+    scopes: Vec<std::collections::HashSet<String>>,
+    edits: Vec<(swc_common::Span, String)>,
+    /// (exported name, local name) of the types to import
+    type_imports: Vec<(String, String)>,
+    uses_api: bool,
+    notes: Vec<Note>,
+}
+
+impl Migrator<'_> {
+    /// The imported name of `name` when it refers to a named import of `"vscode"`
+    fn imported(&self, name: &str) -> Option<String> {
+        if self.is_shadowed(name) {
+            return None;
+        }
+        self.named.get(name).cloned()
+    }
+
+    /// Whether `name` refers to a namespace import of `"vscode"`
+    fn is_namespace(&self, name: &str) -> bool {
+        !self.is_shadowed(name) && self.namespaces.contains(name)
+    }
+
+    fn is_shadowed(&self, name: &str) -> bool {
+        self.scopes.iter().any(|scope| scope.contains(name))
+    }
+
+    fn with_scope(&mut self, names: std::collections::HashSet<String>, f: impl FnOnce(&mut Self)) {
+        self.scopes.push(names);
+        f(self);
+        self.scopes.pop();
+    }
+
+    fn note(&mut self, span: swc_common::Span, message: String) {
+        let (line, column) = crate::usage::line_column(self.source, span.lo);
+        self.notes.push(Note {
+            line,
+            column,
+            message,
+        });
+    }
+
+    /// Local name for the exported type `name`, imported on the first use
+    fn import_type(&mut self, name: &str) -> String {
+        if let Some((_, local)) = self
+            .type_imports
+            .iter()
+            .find(|(exported, _)| exported == name)
+        {
+            return local.clone();
+        }
+        let local = if self.declared.contains(name) {
+            format!("VSCode{}", name)
+        } else {
+            name.to_string()
+        };
+        self.type_imports.push((name.to_string(), local.clone()));
+        local
+    }
+
+    /// The import of this module, in place of the first import of `"vscode"`
+    fn import(&self, module_specifier: &str) -> String {
+        let mut specifiers = self
+            .type_imports
+            .iter()
+            .map(|(exported, local)| {
+                if exported == local {
+                    format!("type {}", exported)
+                } else {
+                    format!("type {} as {}", exported, local)
+                }
+            })
+            .collect::<Vec<_>>();
+        // an extension which is partly migrated already imports it
+        let import_function = self.import_function();
+        if self.uses_api && !self.declared.contains(&import_function) {
+            specifiers.push(import_function);
+        }
+        if specifiers.is_empty() {
+            return String::new();
+        }
+        format!(
+            "import {{ {} }} from \"{}\";",
+            specifiers.join(", "),
+            module_specifier
+        )
+    }
+
+    /// The API object and the other namespace imports as aliases of it, after the last import
+    fn api_object(&self, namespaces: &[(String, bool)]) -> String {
+        if !self.uses_api {
+            return String::new();
+        }
+        let mut code = format!("const {} = {}();", self.api, self.import_function());
+        for (name, _) in namespaces {
+            if *name != self.api {
+                code.push_str(&format!("\nconst {} = {};", name, self.api));
+            }
+        }
+        code
+    }
+
+    /// Rewrite a type name like `vscode.TextEditor` or `TextEditor` of a named import
+    fn type_name(&mut self, name: &swc_ecma_ast::TsEntityName) {
+        match name {
+            swc_ecma_ast::TsEntityName::Ident(ident) => {
+                let Some(imported) = self.imported(&ident.sym) else {
+                    return;
+                };
+                // the local name of the named import is kept for the type
+                match self
+                    .type_imports
+                    .iter()
+                    .find(|(exported, _)| *exported == imported)
+                {
+                    Some((_, local)) if *local != *ident.sym => {
+                        self.edits.push((ident.span, local.clone()))
+                    }
+                    Some(_) => {}
+                    None => self.type_imports.push((imported, ident.sym.to_string())),
+                }
+            }
+            swc_ecma_ast::TsEntityName::TsQualifiedName(qualified) => {
+                let swc_ecma_ast::TsEntityName::Ident(left) = &qualified.left else {
+                    if self.is_api_root(name) {
+                        self.note(
+                            qualified.right.span,
+                            "types in the namespaces of the API are not exported by name"
+                                .to_string(),
+                        );
+                    }
+                    return;
+                };
+                if self.is_namespace(&left.sym) {
+                    let local = self.import_type(&qualified.right.sym);
+                    self.edits.push((swc_common::Spanned::span(name), local));
+                } else if self.imported(&left.sym).is_some() {
+                    self.note(
+                        left.span,
+                        "types in the namespaces of the API are not exported by name".to_string(),
+                    );
+                }
+            }
+        }
+    }
+
+    fn is_api_root(&self, name: &swc_ecma_ast::TsEntityName) -> bool {
+        match name {
+            swc_ecma_ast::TsEntityName::Ident(ident) => {
+                self.is_namespace(&ident.sym) || self.imported(&ident.sym).is_some()
+            }
+            swc_ecma_ast::TsEntityName::TsQualifiedName(qualified) => {
+                self.is_api_root(&qualified.left)
+            }
+        }
+    }
+
+    /// `VSCodeAPI["window"]["activeTextEditor"]` of `vscode.window.activeTextEditor`
+    fn indexed_api_type(&mut self, name: &swc_ecma_ast::TsEntityName) -> String {
+        match name {
+            swc_ecma_ast::TsEntityName::Ident(ident) => match self.imported(&ident.sym) {
+                Some(imported) => {
+                    format!(
+                        "{}[\"{}\"]",
+                        self.import_type(&self.host.type_name),
+                        imported
+                    )
+                }
+                None => self.import_type(&self.host.type_name),
+            },
+            swc_ecma_ast::TsEntityName::TsQualifiedName(qualified) => format!(
+                "{}[\"{}\"]",
+                self.indexed_api_type(&qualified.left),
+                qualified.right.sym
+            ),
+        }
+    }
+
+    /// `expr` of `implements` or `extends` of an interface, which is a type
+    fn heritage(&mut self, heritage: &swc_ecma_ast::TsExprWithTypeArgs) {
+        match &*heritage.expr {
+            swc_ecma_ast::Expr::Ident(ident) => {
+                self.type_name(&swc_ecma_ast::TsEntityName::Ident(ident.clone()))
+            }
+            swc_ecma_ast::Expr::Member(member) => match (&*member.obj, &member.prop) {
+                (swc_ecma_ast::Expr::Ident(object), swc_ecma_ast::MemberProp::Ident(prop))
+                    if self.is_namespace(&object.sym) =>
+                {
+                    let local = self.import_type(&prop.sym);
+                    self.edits.push((member.span, local));
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+        if let Some(type_args) = &heritage.type_args {
+            swc_ecma_visit::Visit::visit_ts_type_param_instantiation(self, type_args);
+        }
+    }
+
+    fn is_vscode_call(&self, call: &swc_ecma_ast::CallExpr) -> bool {
+        matches!(
+            call.args.first().map(|arg| &*arg.expr),
+            Some(swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str)))
+                if *str.value == *self.host.module_name
+        )
+    }
+
+    /// `importVsCodeApiOrThrow`
+    fn import_function(&self) -> String {
+        format!("{}OrThrow", self.host.import_function)
+    }
+}
+
+impl swc_ecma_visit::Visit for Migrator<'_> {
+    fn visit_import_decl(&mut self, _: &swc_ecma_ast::ImportDecl) {}
+
+    fn visit_ts_import_equals_decl(&mut self, _: &swc_ecma_ast::TsImportEqualsDecl) {}
+
+    fn visit_expr(&mut self, expr: &swc_ecma_ast::Expr) {
+        match expr {
+            swc_ecma_ast::Expr::Ident(ident) => {
+                if let Some(imported) = self.imported(&ident.sym) {
+                    self.edits
+                        .push((ident.span, format!("{}.{}", self.api, imported)));
+                    self.uses_api = true;
+                } else if self.is_namespace(&ident.sym) {
+                    self.uses_api = true;
+                }
+            }
+            swc_ecma_ast::Expr::Call(call) if self.is_vscode_call(call) => {
+                match &call.callee {
+                    swc_ecma_ast::Callee::Import(_) => self.note(
+                        call.span,
+                        format!(
+                            "dynamic import of \"{}\": use {}()",
+                            self.host.module_name,
+                            self.import_function()
+                        ),
+                    ),
+                    swc_ecma_ast::Callee::Expr(callee)
+                        if matches!(
+                            &**callee,
+                            swc_ecma_ast::Expr::Ident(ident)
+                                if &*ident.sym == "require" && !self.is_shadowed("require")
+                        ) =>
+                    {
+                        self.note(
+                            call.span,
+                            format!(
+                                "require(\"{}\"): use {}()",
+                                self.host.module_name,
+                                self.import_function()
+                            ),
+                        )
+                    }
+                    _ => {}
+                }
+                swc_ecma_visit::VisitWith::visit_children_with(expr, self);
+            }
+            _ => swc_ecma_visit::VisitWith::visit_children_with(expr, self),
+        }
+    }
+
+    fn visit_prop(&mut self, prop: &swc_ecma_ast::Prop) {
+        match prop {
+            swc_ecma_ast::Prop::Shorthand(ident) => {
+                if let Some(imported) = self.imported(&ident.sym) {
+                    self.edits.push((
+                        ident.span,
+                        format!("{}: {}.{}", ident.sym, self.api, imported),
+                    ));
+                    self.uses_api = true;
+                } else if self.is_namespace(&ident.sym) {
+                    self.uses_api = true;
+                }
+            }
+            _ => swc_ecma_visit::VisitWith::visit_children_with(prop, self),
+        }
+    }
+
+    fn visit_ts_type_ref(&mut self, type_ref: &swc_ecma_ast::TsTypeRef) {
+        self.type_name(&type_ref.type_name);
+        if let Some(type_params) = &type_ref.type_params {
+            swc_ecma_visit::Visit::visit_ts_type_param_instantiation(self, type_params);
+        }
+    }
+
+    fn visit_ts_type_query(&mut self, query: &swc_ecma_ast::TsTypeQuery) {
+        match &query.expr_name {
+            swc_ecma_ast::TsTypeQueryExpr::TsEntityName(name) if self.is_api_root(name) => {
+                let text = self.indexed_api_type(name);
+                let span = swc_common::Span::new(
+                    query.span.lo,
+                    swc_common::Spanned::span(name).hi,
+                    Default::default(),
+                );
+                self.edits.push((span, text));
+            }
+            swc_ecma_ast::TsTypeQueryExpr::Import(import)
+                if *import.arg.value == *self.host.module_name =>
+            {
+                self.note(
+                    query.span,
+                    format!(
+                        "typeof import(\"{}\"): use {}",
+                        self.host.module_name, self.host.type_name
+                    ),
+                );
+            }
+            _ => {}
+        }
+        if let Some(type_args) = &query.type_args {
+            swc_ecma_visit::Visit::visit_ts_type_param_instantiation(self, type_args);
+        }
+    }
+
+    fn visit_ts_import_type(&mut self, import: &swc_ecma_ast::TsImportType) {
+        if *import.arg.value == *self.host.module_name {
+            self.note(
+                import.span,
+                format!(
+                    "import(\"{}\") type: import the type from the module",
+                    self.host.module_name
+                ),
+            );
+        }
+    }
+
+    fn visit_class(&mut self, class: &swc_ecma_ast::Class) {
+        if let Some(super_class) = &class.super_class {
+            swc_ecma_visit::Visit::visit_expr(self, super_class);
+        }
+        swc_ecma_visit::Visit::visit_decorators(self, &class.decorators);
+        self.with_scope(crate::scope::type_param_names(&class.type_params), |v| {
+            for heritage in &class.implements {
+                v.heritage(heritage);
+            }
+            if let Some(super_type_params) = &class.super_type_params {
+                swc_ecma_visit::Visit::visit_ts_type_param_instantiation(v, super_type_params);
+            }
+            swc_ecma_visit::Visit::visit_opt_ts_type_param_decl(v, class.type_params.as_ref());
+            swc_ecma_visit::Visit::visit_class_members(v, &class.body);
+        });
+    }
+
+    fn visit_ts_interface_decl(&mut self, interface: &swc_ecma_ast::TsInterfaceDecl) {
+        self.with_scope(
+            crate::scope::type_param_names(&interface.type_params),
+            |v| {
+                for heritage in &interface.extends {
+                    v.heritage(heritage);
+                }
+                swc_ecma_visit::Visit::visit_opt_ts_type_param_decl(
+                    v,
+                    interface.type_params.as_ref(),
+                );
+                swc_ecma_visit::Visit::visit_ts_interface_body(v, &interface.body);
+            },
+        );
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, alias: &swc_ecma_ast::TsTypeAliasDecl) {
+        self.with_scope(crate::scope::type_param_names(&alias.type_params), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(alias, v)
+        });
+    }
+
+    fn visit_function(&mut self, function: &swc_ecma_ast::Function) {
+        self.with_scope(crate::scope::function_names(function), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(function, v)
+        });
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &swc_ecma_ast::ArrowExpr) {
+        self.with_scope(crate::scope::arrow_names(arrow), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(arrow, v)
+        });
+    }
+
+    fn visit_constructor(&mut self, constructor: &swc_ecma_ast::Constructor) {
+        self.with_scope(crate::scope::constructor_names(constructor), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(constructor, v)
+        });
+    }
+
+    fn visit_block_stmt(&mut self, block: &swc_ecma_ast::BlockStmt) {
+        self.with_scope(crate::scope::lexical_names(&block.stmts), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(block, v)
+        });
+    }
+
+    fn visit_catch_clause(&mut self, clause: &swc_ecma_ast::CatchClause) {
+        self.with_scope(crate::scope::catch_names(clause), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(clause, v)
+        });
+    }
+
+    fn visit_for_stmt(&mut self, stmt: &swc_ecma_ast::ForStmt) {
+        self.with_scope(crate::scope::for_names(stmt), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(stmt, v)
+        });
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &swc_ecma_ast::ForInStmt) {
+        self.with_scope(crate::scope::for_head_names(&stmt.left), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(stmt, v)
+        });
+    }
+
+    fn visit_for_of_stmt(&mut self, stmt: &swc_ecma_ast::ForOfStmt) {
+        self.with_scope(crate::scope::for_head_names(&stmt.left), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(stmt, v)
+        });
+    }
+
+    fn visit_ts_module_decl(&mut self, module: &swc_ecma_ast::TsModuleDecl) {
+        if matches!(&module.id, swc_ecma_ast::TsModuleName::Str(str) if *str.value == *self.host.module_name)
+        {
+            self.note(
+                module.span,
+                format!("augmentation of the \"{}\" module", self.host.module_name),
+            );
+            return;
+        }
+        swc_ecma_visit::VisitWith::visit_children_with(module, self);
+    }
+
+    fn visit_named_export(&mut self, export: &swc_ecma_ast::NamedExport) {
+        if export
+            .src
+            .as_ref()
+            .is_some_and(|src| *src.value == *self.host.module_name)
+        {
+            self.note(
+                export.span,
+                format!("re-export of \"{}\"", self.host.module_name),
+            );
+            return;
+        }
+        for specifier in &export.specifiers {
+            let swc_ecma_ast::ExportSpecifier::Named(specifier) = specifier else {
+                continue;
+            };
+            if let swc_ecma_ast::ModuleExportName::Ident(ident) = &specifier.orig {
+                if self.imported(&ident.sym).is_some() || self.is_namespace(&ident.sym) {
+                    self.note(
+                        ident.span,
+                        format!(
+                            "export of {} imported from \"{}\"",
+                            ident.sym, self.host.module_name
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn visit_export_all(&mut self, export: &swc_ecma_ast::ExportAll) {
+        if *export.src.value == *self.host.module_name {
+            self.note(
+                export.span,
+                format!("re-export of \"{}\"", self.host.module_name),
+            );
+        }
+    }
+}
@@ -0,0 +1,1025 @@
+//! Serializable model of the declarations in the generated module, for tools other than TypeScript.
+//!
+//! Field names are camelCase and every enum carries its variant in `kind`.
+//! Removing or changing the meaning of a field bumps `VERSION`, adding one does not.
+
+/// Version of the model written in `Model::version`
+pub const VERSION: u32 = 1;
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Model {
+    pub version: u32,
+    pub declarations: Vec<Declaration>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Declaration {
+    Namespace {
+        name: String,
+        doc: Option<Doc>,
+        declarations: Vec<Declaration>,
+    },
+    Class {
+        name: String,
+        doc: Option<Doc>,
+        is_abstract: bool,
+        type_params: Vec<TypeParam>,
+        extends: Option<Type>,
+        implements: Vec<Type>,
+        members: Vec<Member>,
+    },
+    Interface {
+        name: String,
+        doc: Option<Doc>,
+        type_params: Vec<TypeParam>,
+        extends: Vec<Type>,
+        members: Vec<Member>,
+    },
+    Enum {
+        name: String,
+        doc: Option<Doc>,
+        members: Vec<EnumMember>,
+    },
+    /// One per overload
+    Function {
+        name: String,
+        doc: Option<Doc>,
+        type_params: Vec<TypeParam>,
+        params: Vec<Param>,
+        return_type: Option<Type>,
+    },
+    Variable {
+        name: String,
+        doc: Option<Doc>,
+        /// declared with `const`
+        is_const: bool,
+        #[serde(rename = "type")]
+        type_: Option<Type>,
+    },
+    TypeAlias {
+        name: String,
+        doc: Option<Doc>,
+        type_params: Vec<TypeParam>,
+        #[serde(rename = "type")]
+        type_: Type,
+    },
+}
+
+/// A member of a class, interface or type literal. Overloads are separate members.
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Member {
+    Constructor {
+        doc: Option<Doc>,
+        params: Vec<Param>,
+    },
+    Method {
+        name: String,
+        doc: Option<Doc>,
+        is_static: bool,
+        is_optional: bool,
+        type_params: Vec<TypeParam>,
+        params: Vec<Param>,
+        return_type: Option<Type>,
+    },
+    Property {
+        name: String,
+        doc: Option<Doc>,
+        is_static: bool,
+        is_optional: bool,
+        is_readonly: bool,
+        #[serde(rename = "type")]
+        type_: Option<Type>,
+    },
+    Getter {
+        name: String,
+        doc: Option<Doc>,
+        is_static: bool,
+        #[serde(rename = "type")]
+        type_: Option<Type>,
+    },
+    Setter {
+        name: String,
+        doc: Option<Doc>,
+        is_static: bool,
+        param: Param,
+    },
+    CallSignature {
+        doc: Option<Doc>,
+        type_params: Vec<TypeParam>,
+        params: Vec<Param>,
+        return_type: Option<Type>,
+    },
+    ConstructSignature {
+        doc: Option<Doc>,
+        type_params: Vec<TypeParam>,
+        params: Vec<Param>,
+        return_type: Option<Type>,
+    },
+    IndexSignature {
+        doc: Option<Doc>,
+        is_static: bool,
+        is_readonly: bool,
+        params: Vec<Param>,
+        #[serde(rename = "type")]
+        type_: Option<Type>,
+    },
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumMember {
+    pub name: String,
+    pub doc: Option<Doc>,
+    /// number or string, `null` when it is not a literal
+    pub value: serde_json::Value,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Param {
+    pub name: String,
+    pub is_optional: bool,
+    pub is_rest: bool,
+    #[serde(rename = "type")]
+    pub type_: Option<Type>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeParam {
+    pub name: String,
+    pub constraint: Option<Type>,
+    pub default: Option<Type>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum Type {
+    /// `string`, `any`, `void`, ...
+    Keyword {
+        name: String,
+    },
+    This,
+    /// `Name<TypeArgs>`, with dotted `name` for qualified names
+    Reference {
+        name: String,
+        type_args: Vec<Type>,
+    },
+    /// `typeof name`
+    Query {
+        name: String,
+        type_args: Vec<Type>,
+    },
+    Literal {
+        value: serde_json::Value,
+    },
+    Array {
+        element: Box<Type>,
+    },
+    Tuple {
+        elements: Vec<TupleElement>,
+    },
+    Union {
+        types: Vec<Type>,
+    },
+    Intersection {
+        types: Vec<Type>,
+    },
+    Function {
+        type_params: Vec<TypeParam>,
+        params: Vec<Param>,
+        return_type: Box<Type>,
+    },
+    Constructor {
+        is_abstract: bool,
+        type_params: Vec<TypeParam>,
+        params: Vec<Param>,
+        return_type: Box<Type>,
+    },
+    Object {
+        members: Vec<Member>,
+    },
+    /// `keyof`, `unique` or `readonly` applied to `type`
+    Operator {
+        operator: String,
+        #[serde(rename = "type")]
+        type_: Box<Type>,
+    },
+    IndexedAccess {
+        object: Box<Type>,
+        index: Box<Type>,
+    },
+    Conditional {
+        check: Box<Type>,
+        extends: Box<Type>,
+        true_type: Box<Type>,
+        false_type: Box<Type>,
+    },
+    Infer {
+        name: String,
+    },
+    /// `{ [param in constraint as nameType]: type }`
+    Mapped {
+        param: String,
+        constraint: Option<Box<Type>>,
+        name_type: Option<Box<Type>>,
+        /// `+`, `-` or `true` for a modifier without a sign
+        readonly: Option<String>,
+        optional: Option<String>,
+        #[serde(rename = "type")]
+        type_: Option<Box<Type>>,
+    },
+    /// `param is type` or `asserts param`
+    Predicate {
+        asserts: bool,
+        param: String,
+        #[serde(rename = "type")]
+        type_: Option<Box<Type>>,
+    },
+    /// A type the model has no structure for, as TypeScript code
+    Other {
+        text: String,
+    },
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TupleElement {
+    pub label: Option<String>,
+    pub is_optional: bool,
+    pub is_rest: bool,
+    #[serde(rename = "type")]
+    pub type_: Type,
+}
+
+/// A JSDoc comment
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Doc {
+    /// The text before the first tag
+    pub description: String,
+    pub tags: Vec<DocTag>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DocTag {
+    /// Tag name without `@`, like `param` or `deprecated`
+    pub tag: String,
+    /// The parameter name of `@param` and `@typeParam`
+    pub name: Option<String>,
+    pub text: String,
+}
+
+/// Build the model of `result_vec`, reading the doc comments of members from `comments`
+pub fn build(
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    comments: &dyn swc_common::comments::Comments,
+) -> Model {
+    Model {
+        version: VERSION,
+        declarations: declarations(result_vec, comments),
+    }
+}
+
+fn declarations(
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    comments: &dyn swc_common::comments::Comments,
+) -> Vec<Declaration> {
+    result_vec
+        .iter()
+        .flat_map(|result| {
+            let doc = result.comments.as_deref().and_then(parse_doc);
+            match &result.decl {
+                crate::pickup::ResultDecl::SubModule(sub_module) => {
+                    vec![Declaration::Namespace {
+                        name: match &sub_module.name {
+                            swc_ecma_ast::TsModuleName::Ident(ident) => ident.sym.to_string(),
+                            swc_ecma_ast::TsModuleName::Str(str) => str.value.to_string(),
+                        },
+                        doc,
+                        declarations: declarations(&sub_module.decl_vec, comments),
+                    }]
+                }
+                crate::pickup::ResultDecl::Class(class) => {
+                    vec![Declaration::Class {
+                        name: class.ident.sym.to_string(),
+                        doc,
+                        is_abstract: class.class.is_abstract,
+                        type_params: type_params(&class.class.type_params, comments),
+                        extends: class.class.super_class.as_ref().map(|super_class| {
+                            Type::Reference {
+                                name: expr_to_name(super_class),
+                                type_args: type_args(&class.class.super_type_params, comments),
+                            }
+                        }),
+                        implements: class
+                            .class
+                            .implements
+                            .iter()
+                            .map(|expr| expr_with_type_args(expr, comments))
+                            .collect(),
+                        members: class
+                            .class
+                            .body
+                            .iter()
+                            .filter_map(|member| class_member(member, comments))
+                            .collect(),
+                    }]
+                }
+                crate::pickup::ResultDecl::TsInterface(interface) => vec![Declaration::Interface {
+                    name: interface.id.sym.to_string(),
+                    doc,
+                    type_params: type_params(&interface.type_params, comments),
+                    extends: interface
+                        .extends
+                        .iter()
+                        .map(|expr| expr_with_type_args(expr, comments))
+                        .collect(),
+                    members: type_elements(&interface.body.body, comments),
+                }],
+                crate::pickup::ResultDecl::TsEnum(enum_decl) => vec![Declaration::Enum {
+                    name: enum_decl.id.sym.to_string(),
+                    doc,
+                    members: enum_decl
+                        .members
+                        .iter()
+                        .map(|member| EnumMember {
+                            name: match &member.id {
+                                swc_ecma_ast::TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
+                                swc_ecma_ast::TsEnumMemberId::Str(str) => str.value.to_string(),
+                            },
+                            doc: leading_doc(comments, member.span.lo),
+                            value: member
+                                .init
+                                .as_deref()
+                                .map(expr_to_value)
+                                .unwrap_or_default(),
+                        })
+                        .collect(),
+                }],
+                crate::pickup::ResultDecl::Fn(fn_decl) => vec![Declaration::Function {
+                    name: fn_decl.ident.sym.to_string(),
+                    doc,
+                    type_params: type_params(&fn_decl.function.type_params, comments),
+                    params: fn_decl
+                        .function
+                        .params
+                        .iter()
+                        .map(|param| pat_to_param(&param.pat, comments))
+                        .collect(),
+                    return_type: fn_decl
+                        .function
+                        .return_type
+                        .as_ref()
+                        .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+                }],
+                crate::pickup::ResultDecl::Var(var_decl) => var_decl
+                    .decls
+                    .iter()
+                    .map(|declarator| {
+                        let param = pat_to_param(&declarator.name, comments);
+                        Declaration::Variable {
+                            name: param.name,
+                            doc: doc.clone(),
+                            is_const: var_decl.kind == swc_ecma_ast::VarDeclKind::Const,
+                            type_: param.type_,
+                        }
+                    })
+                    .collect(),
+                crate::pickup::ResultDecl::TsTypeAlias(alias) => vec![Declaration::TypeAlias {
+                    name: alias.id.sym.to_string(),
+                    doc,
+                    type_params: type_params(&alias.type_params, comments),
+                    type_: ts_type(&alias.type_ann, comments),
+                }],
+            }
+        })
+        .collect()
+}
+
+fn class_member(
+    member: &swc_ecma_ast::ClassMember,
+    comments: &dyn swc_common::comments::Comments,
+) -> Option<Member> {
+    let doc = leading_doc(comments, swc_common::Spanned::span(member).lo);
+    let name = crate::decl_path::class_member_name(member)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| "[computed]".to_string());
+    match member {
+        swc_ecma_ast::ClassMember::Constructor(constructor) => Some(Member::Constructor {
+            doc,
+            params: constructor
+                .params
+                .iter()
+                .map(|param| match param {
+                    swc_ecma_ast::ParamOrTsParamProp::Param(param) => {
+                        pat_to_param(&param.pat, comments)
+                    }
+                    swc_ecma_ast::ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+                        swc_ecma_ast::TsParamPropParam::Ident(ident) => {
+                            binding_ident_to_param(ident, comments)
+                        }
+                        swc_ecma_ast::TsParamPropParam::Assign(assign) => Param {
+                            is_optional: true,
+                            ..pat_to_param(&assign.left, comments)
+                        },
+                    },
+                })
+                .collect(),
+        }),
+        swc_ecma_ast::ClassMember::Method(method) => {
+            let params = method
+                .function
+                .params
+                .iter()
+                .map(|param| pat_to_param(&param.pat, comments))
+                .collect::<Vec<_>>();
+            let return_type = method
+                .function
+                .return_type
+                .as_ref()
+                .map(|type_ann| ts_type(&type_ann.type_ann, comments));
+            Some(match method.kind {
+                swc_ecma_ast::MethodKind::Method => Member::Method {
+                    name,
+                    doc,
+                    is_static: method.is_static,
+                    is_optional: method.is_optional,
+                    type_params: type_params(&method.function.type_params, comments),
+                    params,
+                    return_type,
+                },
+                swc_ecma_ast::MethodKind::Getter => Member::Getter {
+                    name,
+                    doc,
+                    is_static: method.is_static,
+                    type_: return_type,
+                },
+                swc_ecma_ast::MethodKind::Setter => Member::Setter {
+                    name,
+                    doc,
+                    is_static: method.is_static,
+                    param: params.into_iter().next()?,
+                },
+            })
+        }
+        swc_ecma_ast::ClassMember::ClassProp(prop) => Some(Member::Property {
+            name,
+            doc,
+            is_static: prop.is_static,
+            is_optional: prop.is_optional,
+            is_readonly: prop.readonly,
+            type_: prop
+                .type_ann
+                .as_ref()
+                .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+        }),
+        swc_ecma_ast::ClassMember::TsIndexSignature(signature) => {
+            Some(index_signature(signature, doc, comments))
+        }
+        _ => None,
+    }
+}
+
+fn type_elements(
+    elements: &[swc_ecma_ast::TsTypeElement],
+    comments: &dyn swc_common::comments::Comments,
+) -> Vec<Member> {
+    elements
+        .iter()
+        .map(|element| {
+            let doc = leading_doc(comments, swc_common::Spanned::span(element).lo);
+            let name = crate::decl_path::type_element_name(element)
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| "[computed]".to_string());
+            match element {
+                swc_ecma_ast::TsTypeElement::TsCallSignatureDecl(signature) => {
+                    Member::CallSignature {
+                        doc,
+                        type_params: type_params(&signature.type_params, comments),
+                        params: signature
+                            .params
+                            .iter()
+                            .map(|param| fn_param(param, comments))
+                            .collect(),
+                        return_type: signature
+                            .type_ann
+                            .as_ref()
+                            .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+                    }
+                }
+                swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(signature) => {
+                    Member::ConstructSignature {
+                        doc,
+                        type_params: type_params(&signature.type_params, comments),
+                        params: signature
+                            .params
+                            .iter()
+                            .map(|param| fn_param(param, comments))
+                            .collect(),
+                        return_type: signature
+                            .type_ann
+                            .as_ref()
+                            .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+                    }
+                }
+                swc_ecma_ast::TsTypeElement::TsPropertySignature(signature) => Member::Property {
+                    name,
+                    doc,
+                    is_static: false,
+                    is_optional: signature.optional,
+                    is_readonly: signature.readonly,
+                    type_: signature
+                        .type_ann
+                        .as_ref()
+                        .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+                },
+                swc_ecma_ast::TsTypeElement::TsGetterSignature(signature) => Member::Getter {
+                    name,
+                    doc,
+                    is_static: false,
+                    type_: signature
+                        .type_ann
+                        .as_ref()
+                        .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+                },
+                swc_ecma_ast::TsTypeElement::TsSetterSignature(signature) => Member::Setter {
+                    name,
+                    doc,
+                    is_static: false,
+                    param: fn_param(&signature.param, comments),
+                },
+                swc_ecma_ast::TsTypeElement::TsMethodSignature(signature) => Member::Method {
+                    name,
+                    doc,
+                    is_static: false,
+                    is_optional: signature.optional,
+                    type_params: type_params(&signature.type_params, comments),
+                    params: signature
+                        .params
+                        .iter()
+                        .map(|param| fn_param(param, comments))
+                        .collect(),
+                    return_type: signature
+                        .type_ann
+                        .as_ref()
+                        .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+                },
+                swc_ecma_ast::TsTypeElement::TsIndexSignature(signature) => {
+                    index_signature(signature, doc, comments)
+                }
+            }
+        })
+        .collect()
+}
+
+fn index_signature(
+    signature: &swc_ecma_ast::TsIndexSignature,
+    doc: Option<Doc>,
+    comments: &dyn swc_common::comments::Comments,
+) -> Member {
+    Member::IndexSignature {
+        doc,
+        is_static: signature.is_static,
+        is_readonly: signature.readonly,
+        params: signature
+            .params
+            .iter()
+            .map(|param| fn_param(param, comments))
+            .collect(),
+        type_: signature
+            .type_ann
+            .as_ref()
+            .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+    }
+}
+
+fn type_params(
+    type_params: &Option<Box<swc_ecma_ast::TsTypeParamDecl>>,
+    comments: &dyn swc_common::comments::Comments,
+) -> Vec<TypeParam> {
+    type_params
+        .iter()
+        .flat_map(|decl| &decl.params)
+        .map(|param| TypeParam {
+            name: param.name.sym.to_string(),
+            constraint: param.constraint.as_deref().map(|ty| ts_type(ty, comments)),
+            default: param.default.as_deref().map(|ty| ts_type(ty, comments)),
+        })
+        .collect()
+}
+
+fn type_args(
+    type_args: &Option<Box<swc_ecma_ast::TsTypeParamInstantiation>>,
+    comments: &dyn swc_common::comments::Comments,
+) -> Vec<Type> {
+    type_args
+        .iter()
+        .flat_map(|instantiation| &instantiation.params)
+        .map(|param| ts_type(param, comments))
+        .collect()
+}
+
+fn expr_with_type_args(
+    expr: &swc_ecma_ast::TsExprWithTypeArgs,
+    comments: &dyn swc_common::comments::Comments,
+) -> Type {
+    Type::Reference {
+        name: expr_to_name(&expr.expr),
+        type_args: type_args(&expr.type_args, comments),
+    }
+}
+
+fn fn_param(
+    param: &swc_ecma_ast::TsFnParam,
+    comments: &dyn swc_common::comments::Comments,
+) -> Param {
+    match param {
+        swc_ecma_ast::TsFnParam::Ident(ident) => binding_ident_to_param(ident, comments),
+        swc_ecma_ast::TsFnParam::Rest(rest) => {
+            pat_to_param(&swc_ecma_ast::Pat::Rest(rest.clone()), comments)
+        }
+        swc_ecma_ast::TsFnParam::Array(array) => Param {
+            name: "_".to_string(),
+            is_optional: array.optional,
+            is_rest: false,
+            type_: array
+                .type_ann
+                .as_ref()
+                .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+        },
+        swc_ecma_ast::TsFnParam::Object(object) => Param {
+            name: "_".to_string(),
+            is_optional: object.optional,
+            is_rest: false,
+            type_: object
+                .type_ann
+                .as_ref()
+                .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+        },
+    }
+}
+
+fn binding_ident_to_param(
+    ident: &swc_ecma_ast::BindingIdent,
+    comments: &dyn swc_common::comments::Comments,
+) -> Param {
+    Param {
+        name: ident.id.sym.to_string(),
+        is_optional: ident.id.optional,
+        is_rest: false,
+        type_: ident
+            .type_ann
+            .as_ref()
+            .map(|type_ann| ts_type(&type_ann.type_ann, comments)),
+    }
+}
+
+fn pat_to_param(pat: &swc_ecma_ast::Pat, comments: &dyn swc_common::comments::Comments) -> Param {
+    match pat {
+        swc_ecma_ast::Pat::Ident(ident) => binding_ident_to_param(ident, comments),
+        swc_ecma_ast::Pat::Rest(rest) => {
+            let param = pat_to_param(&rest.arg, comments);
+            Param {
+                is_rest: true,
+                type_: rest
+                    .type_ann
+                    .as_ref()
+                    .map(|type_ann| ts_type(&type_ann.type_ann, comments))
+                    .or(param.type_),
+                ..param
+            }
+        }
+        swc_ecma_ast::Pat::Assign(assign) => Param {
+            is_optional: true,
+            ..pat_to_param(&assign.left, comments)
+        },
+        _ => Param {
+            name: "_".to_string(),
+            is_optional: false,
+            is_rest: false,
+            type_: None,
+        },
+    }
+}
+
+fn ts_type(ts_type: &swc_ecma_ast::TsType, comments: &dyn swc_common::comments::Comments) -> Type {
+    match ts_type {
+        swc_ecma_ast::TsType::TsKeywordType(keyword) => Type::Keyword {
+            name: keyword_name(keyword.kind).to_string(),
+        },
+        swc_ecma_ast::TsType::TsThisType(_) => Type::This,
+        swc_ecma_ast::TsType::TsFnOrConstructorType(
+            swc_ecma_ast::TsFnOrConstructorType::TsFnType(fn_type),
+        ) => Type::Function {
+            type_params: type_params(&fn_type.type_params, comments),
+            params: fn_type
+                .params
+                .iter()
+                .map(|param| fn_param(param, comments))
+                .collect(),
+            return_type: Box::new(self::ts_type(&fn_type.type_ann.type_ann, comments)),
+        },
+        swc_ecma_ast::TsType::TsFnOrConstructorType(
+            swc_ecma_ast::TsFnOrConstructorType::TsConstructorType(constructor_type),
+        ) => Type::Constructor {
+            is_abstract: constructor_type.is_abstract,
+            type_params: type_params(&constructor_type.type_params, comments),
+            params: constructor_type
+                .params
+                .iter()
+                .map(|param| fn_param(param, comments))
+                .collect(),
+            return_type: Box::new(self::ts_type(&constructor_type.type_ann.type_ann, comments)),
+        },
+        swc_ecma_ast::TsType::TsTypeRef(type_ref) => Type::Reference {
+            name: entity_name(&type_ref.type_name),
+            type_args: type_args(&type_ref.type_params, comments),
+        },
+        swc_ecma_ast::TsType::TsTypeQuery(query) => match &query.expr_name {
+            swc_ecma_ast::TsTypeQueryExpr::TsEntityName(name) => Type::Query {
+                name: entity_name(name),
+                type_args: type_args(&query.type_args, comments),
+            },
+            swc_ecma_ast::TsTypeQueryExpr::Import(_) => other(ts_type),
+        },
+        swc_ecma_ast::TsType::TsTypeLit(type_lit) => Type::Object {
+            members: type_elements(&type_lit.members, comments),
+        },
+        swc_ecma_ast::TsType::TsArrayType(array) => Type::Array {
+            element: Box::new(self::ts_type(&array.elem_type, comments)),
+        },
+        swc_ecma_ast::TsType::TsTupleType(tuple) => Type::Tuple {
+            elements: tuple
+                .elem_types
+                .iter()
+                .map(|element| {
+                    let (is_optional, is_rest, ty) = match &*element.ty {
+                        swc_ecma_ast::TsType::TsOptionalType(optional) => {
+                            (true, false, &optional.type_ann)
+                        }
+                        swc_ecma_ast::TsType::TsRestType(rest) => (false, true, &rest.type_ann),
+                        _ => (false, false, &element.ty),
+                    };
+                    // `name?: T` and `...name: T` put the modifier on the label
+                    let label = element
+                        .label
+                        .as_ref()
+                        .map(|label| pat_to_param(label, comments));
+                    TupleElement {
+                        is_optional: is_optional
+                            || label.as_ref().is_some_and(|label| label.is_optional),
+                        is_rest: is_rest || label.as_ref().is_some_and(|label| label.is_rest),
+                        label: label.map(|label| label.name),
+                        type_: self::ts_type(ty, comments),
+                    }
+                })
+                .collect(),
+        },
+        swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(union),
+        ) => Type::Union {
+            types: union
+                .types
+                .iter()
+                .map(|ty| self::ts_type(ty, comments))
+                .collect(),
+        },
+        swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(intersection),
+        ) => Type::Intersection {
+            types: intersection
+                .types
+                .iter()
+                .map(|ty| self::ts_type(ty, comments))
+                .collect(),
+        },
+        swc_ecma_ast::TsType::TsConditionalType(conditional) => Type::Conditional {
+            check: Box::new(self::ts_type(&conditional.check_type, comments)),
+            extends: Box::new(self::ts_type(&conditional.extends_type, comments)),
+            true_type: Box::new(self::ts_type(&conditional.true_type, comments)),
+            false_type: Box::new(self::ts_type(&conditional.false_type, comments)),
+        },
+        swc_ecma_ast::TsType::TsInferType(infer) => Type::Infer {
+            name: infer.type_param.name.sym.to_string(),
+        },
+        swc_ecma_ast::TsType::TsParenthesizedType(parenthesized) => {
+            self::ts_type(&parenthesized.type_ann, comments)
+        }
+        swc_ecma_ast::TsType::TsTypeOperator(operator) => Type::Operator {
+            operator: match operator.op {
+                swc_ecma_ast::TsTypeOperatorOp::KeyOf => "keyof",
+                swc_ecma_ast::TsTypeOperatorOp::Unique => "unique",
+                swc_ecma_ast::TsTypeOperatorOp::ReadOnly => "readonly",
+            }
+            .to_string(),
+            type_: Box::new(self::ts_type(&operator.type_ann, comments)),
+        },
+        swc_ecma_ast::TsType::TsIndexedAccessType(indexed_access) => Type::IndexedAccess {
+            object: Box::new(self::ts_type(&indexed_access.obj_type, comments)),
+            index: Box::new(self::ts_type(&indexed_access.index_type, comments)),
+        },
+        swc_ecma_ast::TsType::TsMappedType(mapped) => Type::Mapped {
+            param: mapped.type_param.name.sym.to_string(),
+            constraint: mapped
+                .type_param
+                .constraint
+                .as_deref()
+                .map(|ty| Box::new(self::ts_type(ty, comments))),
+            name_type: mapped
+                .name_type
+                .as_deref()
+                .map(|ty| Box::new(self::ts_type(ty, comments))),
+            readonly: mapped.readonly.map(true_plus_minus),
+            optional: mapped.optional.map(true_plus_minus),
+            type_: mapped
+                .type_ann
+                .as_deref()
+                .map(|ty| Box::new(self::ts_type(ty, comments))),
+        },
+        swc_ecma_ast::TsType::TsLitType(lit) => match &lit.lit {
+            swc_ecma_ast::TsLit::Number(number) => Type::Literal {
+                value: number_value(number.value),
+            },
+            swc_ecma_ast::TsLit::Str(str) => Type::Literal {
+                value: serde_json::Value::String(str.value.to_string()),
+            },
+            swc_ecma_ast::TsLit::Bool(bool) => Type::Literal {
+                value: serde_json::Value::Bool(bool.value),
+            },
+            swc_ecma_ast::TsLit::BigInt(_) | swc_ecma_ast::TsLit::Tpl(_) => other(ts_type),
+        },
+        swc_ecma_ast::TsType::TsTypePredicate(predicate) => Type::Predicate {
+            asserts: predicate.asserts,
+            param: match &predicate.param_name {
+                swc_ecma_ast::TsThisTypeOrIdent::TsThisType(_) => "this".to_string(),
+                swc_ecma_ast::TsThisTypeOrIdent::Ident(ident) => ident.sym.to_string(),
+            },
+            type_: predicate
+                .type_ann
+                .as_ref()
+                .map(|type_ann| Box::new(self::ts_type(&type_ann.type_ann, comments))),
+        },
+        swc_ecma_ast::TsType::TsOptionalType(_)
+        | swc_ecma_ast::TsType::TsRestType(_)
+        | swc_ecma_ast::TsType::TsImportType(_) => other(ts_type),
+    }
+}
+
+fn other(ts_type: &swc_ecma_ast::TsType) -> Type {
+    Type::Other {
+        text: crate::node_to_code_string(
+            ts_type,
+            &swc_common::comments::SingleThreadedComments::default(),
+        )
+        .unwrap_or_default(),
+    }
+}
+
+fn keyword_name(kind: swc_ecma_ast::TsKeywordTypeKind) -> &'static str {
+    match kind {
+        swc_ecma_ast::TsKeywordTypeKind::TsAnyKeyword => "any",
+        swc_ecma_ast::TsKeywordTypeKind::TsUnknownKeyword => "unknown",
+        swc_ecma_ast::TsKeywordTypeKind::TsNumberKeyword => "number",
+        swc_ecma_ast::TsKeywordTypeKind::TsObjectKeyword => "object",
+        swc_ecma_ast::TsKeywordTypeKind::TsBooleanKeyword => "boolean",
+        swc_ecma_ast::TsKeywordTypeKind::TsBigIntKeyword => "bigint",
+        swc_ecma_ast::TsKeywordTypeKind::TsStringKeyword => "string",
+        swc_ecma_ast::TsKeywordTypeKind::TsSymbolKeyword => "symbol",
+        swc_ecma_ast::TsKeywordTypeKind::TsVoidKeyword => "void",
+        swc_ecma_ast::TsKeywordTypeKind::TsUndefinedKeyword => "undefined",
+        swc_ecma_ast::TsKeywordTypeKind::TsNullKeyword => "null",
+        swc_ecma_ast::TsKeywordTypeKind::TsNeverKeyword => "never",
+        swc_ecma_ast::TsKeywordTypeKind::TsIntrinsicKeyword => "intrinsic",
+    }
+}
+
+fn true_plus_minus(value: swc_ecma_ast::TruePlusMinus) -> String {
+    match value {
+        swc_ecma_ast::TruePlusMinus::True => "true",
+        swc_ecma_ast::TruePlusMinus::Plus => "+",
+        swc_ecma_ast::TruePlusMinus::Minus => "-",
+    }
+    .to_string()
+}
+
+fn entity_name(name: &swc_ecma_ast::TsEntityName) -> String {
+    match name {
+        swc_ecma_ast::TsEntityName::Ident(ident) => ident.sym.to_string(),
+        swc_ecma_ast::TsEntityName::TsQualifiedName(qualified) => {
+            format!("{}.{}", entity_name(&qualified.left), qualified.right.sym)
+        }
+    }
+}
+
+fn expr_to_name(expr: &swc_ecma_ast::Expr) -> String {
+    match expr {
+        swc_ecma_ast::Expr::Ident(ident) => ident.sym.to_string(),
+        swc_ecma_ast::Expr::Member(member) => match &member.prop {
+            swc_ecma_ast::MemberProp::Ident(ident) => {
+                format!("{}.{}", expr_to_name(&member.obj), ident.sym)
+            }
+            _ => expr_to_name(&member.obj),
+        },
+        _ => "[computed]".to_string(),
+    }
+}
+
+fn expr_to_value(expr: &swc_ecma_ast::Expr) -> serde_json::Value {
+    match expr {
+        swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Num(number)) => number_value(number.value),
+        swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str)) => {
+            serde_json::Value::String(str.value.to_string())
+        }
+        swc_ecma_ast::Expr::Unary(unary) if unary.op == swc_ecma_ast::UnaryOp::Minus => {
+            match &*unary.arg {
+                swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Num(number)) => {
+                    number_value(-number.value)
+                }
+                _ => serde_json::Value::Null,
+            }
+        }
+        _ => serde_json::Value::Null,
+    }
+}
+
+/// Integral numbers are written without a fraction
+fn number_value(value: f64) -> serde_json::Value {
+    if value.fract() == 0.0 && value.abs() < (1u64 << 53) as f64 {
+        serde_json::Value::from(value as i64)
+    } else {
+        serde_json::Number::from_f64(value)
+            .map(serde_json::Value::Number)
+            .unwrap_or_default()
+    }
+}
+
+fn leading_doc(
+    comments: &dyn swc_common::comments::Comments,
+    pos: swc_common::BytePos,
+) -> Option<Doc> {
+    parse_doc(&comments.get_leading(pos)?)
+}
+
+/// Parse the last JSDoc (`/** ... */`) comment in `comments`
+pub fn parse_doc(comments: &[swc_common::comments::Comment]) -> Option<Doc> {
+    let comment = comments.iter().rev().find(|comment| {
+        comment.kind == swc_common::comments::CommentKind::Block && comment.text.starts_with('*')
+    })?;
+    let lines = comment.text[1..].lines().map(|line| {
+        let line = line.trim();
+        let line = line.strip_prefix('*').unwrap_or(line);
+        line.strip_prefix(' ').unwrap_or(line).trim_end()
+    });
+
+    let mut description = Vec::<&str>::new();
+    let mut tags = Vec::<(String, Vec<&str>)>::new();
+    for line in lines {
+        match line.strip_prefix('@') {
+            Some(rest) => {
+                let (tag, text) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                tags.push((tag.to_string(), vec![text.trim_start()]));
+            }
+            None => match tags.last_mut() {
+                Some((_, text)) => text.push(line),
+                None => description.push(line),
+            },
+        }
+    }
+
+    Some(Doc {
+        description: description.join("\n").trim().to_string(),
+        tags: tags
+            .into_iter()
+            .map(|(tag, text)| {
+                let text = text.join("\n").trim().to_string();
+                let (name, text) = match tag.as_str() {
+                    "param" | "typeParam" | "template" => {
+                        let (name, text) = text
+                            .split_once(char::is_whitespace)
+                            .unwrap_or((text.as_str(), ""));
+                        (Some(name.to_string()), text.trim_start().to_string())
+                    }
+                    _ => (None, text),
+                };
+                DocTag { tag, name, text }
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,311 @@
+//! The module as an npm package for Node tooling
+//!
+//! ```text
+//! package.json   `exports` with the `types`, `import` and `require` conditions
+//! index.d.ts     the types, and the exported functions without their bodies
+//! index.cjs      the functions with the types stripped, assigned to `exports`
+//! index.mjs      the exports of index.cjs
+//! ```
+//!
+//! Both are emitted from the module block of mod.ts, so they have the same fixes.
+//! index.mjs does not copy the functions, since ES modules have no `require("vscode")`,
+//! and `import` and `require` of the package share one `setVsCodeApi`.
+
+/// `name` and `version` of package.json
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NpmPackage {
+    pub name: String,
+    pub version: String,
+}
+
+impl Default for NpmPackage {
+    fn default() -> Self {
+        NpmPackage {
+            name: "@narumincho/vscode".to_string(),
+            version: "0.0.0".to_string(),
+        }
+    }
+}
+
+/// The files of the package, from `module_block` of mod.ts.
+/// Call this before emitting the module itself, which takes the comments
+pub fn files(
+    module_block: &swc_ecma_ast::TsModuleBlock,
+    comments: &swc_common::comments::SingleThreadedComments,
+    host: &crate::HostModule,
+    package: &NpmPackage,
+) -> Result<Vec<crate::GeneratedFile>, crate::Error> {
+    let declarations = swc_ecma_ast::TsModuleBlock {
+        span: swc_common::Span::default(),
+        body: module_block.body.iter().filter_map(declaration).collect(),
+    };
+
+    let mut runtime = module_block.clone();
+    swc_ecma_visit::VisitMutWith::visit_mut_with(&mut runtime, &mut StripTypes);
+
+    let mut exported = Vec::<String>::new();
+    let common_js = swc_ecma_ast::TsModuleBlock {
+        span: swc_common::Span::default(),
+        body: runtime
+            .body
+            .into_iter()
+            .map(|item| match item {
+                swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
+                    export_decl,
+                )) => {
+                    exported.extend(declared_names(&export_decl.decl));
+                    swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(export_decl.decl))
+                }
+                item => item,
+            })
+            .collect(),
+    };
+
+    Ok(vec![
+        crate::GeneratedFile {
+            path: "package.json".to_string(),
+            code: package_json(host, package),
+        },
+        crate::GeneratedFile {
+            path: "index.d.ts".to_string(),
+            code: crate::node_to_code_string(&declarations, &crate::copy_comments(comments))?,
+        },
+        crate::GeneratedFile {
+            path: "index.mjs".to_string(),
+            code: format!(
+                "import commonJs from \"./index.cjs\";\nexport const {{ {} }} = commonJs;\n",
+                exported.join(", ")
+            ),
+        },
+        crate::GeneratedFile {
+            path: "index.cjs".to_string(),
+            code: format!(
+                "\"use strict\";\n{}{}",
+                crate::node_to_code_string(&common_js, &crate::copy_comments(comments))?,
+                exported
+                    .iter()
+                    .map(|name| format!("exports.{} = {};\n", name, name))
+                    .collect::<String>()
+            ),
+        },
+    ])
+}
+
+fn package_json(host: &crate::HostModule, package: &NpmPackage) -> String {
+    #[derive(serde::Serialize)]
+    struct PackageJson<'a> {
+        name: &'a str,
+        version: &'a str,
+        description: String,
+        main: &'static str,
+        module: &'static str,
+        types: &'static str,
+        exports: Exports,
+        files: [&'static str; 3],
+    }
+
+    #[derive(serde::Serialize)]
+    struct Exports {
+        #[serde(rename = ".")]
+        root: Conditions,
+    }
+
+    /// `types` has to be first, as the first matching condition is taken
+    #[derive(serde::Serialize)]
+    struct Conditions {
+        types: &'static str,
+        import: &'static str,
+        require: &'static str,
+    }
+
+    let json = serde_json::to_string_pretty(&PackageJson {
+        name: &package.name,
+        version: &package.version,
+        description: format!(
+            "{} of the {} module for Node",
+            host.import_function, host.module_name
+        ),
+        main: "./index.cjs",
+        module: "./index.mjs",
+        types: "./index.d.ts",
+        exports: Exports {
+            root: Conditions {
+                types: "./index.d.ts",
+                import: "./index.mjs",
+                require: "./index.cjs",
+            },
+        },
+        files: ["index.d.ts", "index.mjs", "index.cjs"],
+    })
+    .expect("strings only");
+    json + "\n"
+}
+
+/// The item as it is in index.d.ts, `None` if it only exists at runtime
+fn declaration(item: &swc_ecma_ast::ModuleItem) -> Option<swc_ecma_ast::ModuleItem> {
+    match item {
+        swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(export_decl)) => {
+            let decl = match &export_decl.decl {
+                swc_ecma_ast::Decl::Fn(fn_decl) => {
+                    let mut fn_decl = fn_decl.clone();
+                    fn_decl.declare = true;
+                    fn_decl.function.body = None;
+                    swc_ecma_ast::Decl::Fn(fn_decl)
+                }
+                swc_ecma_ast::Decl::Var(var_decl) => {
+                    let mut var_decl = var_decl.clone();
+                    var_decl.declare = true;
+                    for declarator in &mut var_decl.decls {
+                        declarator.init = None;
+                    }
+                    swc_ecma_ast::Decl::Var(var_decl)
+                }
+                swc_ecma_ast::Decl::TsTypeAlias(_) | swc_ecma_ast::Decl::TsInterface(_) => {
+                    export_decl.decl.clone()
+                }
+                _ => return None,
+            };
+            Some(swc_ecma_ast::ModuleItem::ModuleDecl(
+                swc_ecma_ast::ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
+                    span: export_decl.span,
+                    decl,
+                }),
+            ))
+        }
+        // helpers of the exported types like `ValueOf`
+        swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(
+            swc_ecma_ast::Decl::TsTypeAlias(_) | swc_ecma_ast::Decl::TsInterface(_),
+        )) => Some(item.clone()),
+        _ => None,
+    }
+}
+
+fn declared_names(decl: &swc_ecma_ast::Decl) -> Vec<String> {
+    match decl {
+        swc_ecma_ast::Decl::Fn(fn_decl) => vec![fn_decl.ident.sym.to_string()],
+        swc_ecma_ast::Decl::Var(var_decl) => var_decl
+            .decls
+            .iter()
+            .filter_map(|declarator| match &declarator.name {
+                swc_ecma_ast::Pat::Ident(ident) => Some(ident.id.sym.to_string()),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Whether the declaration has no JavaScript, like types and `declare const require`
+fn is_type_only(decl: &swc_ecma_ast::Decl) -> bool {
+    match decl {
+        swc_ecma_ast::Decl::TsTypeAlias(_)
+        | swc_ecma_ast::Decl::TsInterface(_)
+        | swc_ecma_ast::Decl::TsModule(_) => true,
+        swc_ecma_ast::Decl::Var(var_decl) => var_decl.declare,
+        swc_ecma_ast::Decl::Fn(fn_decl) => fn_decl.declare || fn_decl.function.body.is_none(),
+        _ => false,
+    }
+}
+
+/// Remove the TypeScript syntax which the runtime code of the module uses
+struct StripTypes;
+
+impl swc_ecma_visit::VisitMut for StripTypes {
+    fn visit_mut_module_items(&mut self, items: &mut Vec<swc_ecma_ast::ModuleItem>) {
+        items.retain(|item| match item {
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
+                export_decl,
+            )) => !is_type_only(&export_decl.decl),
+            swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(decl)) => !is_type_only(decl),
+            _ => true,
+        });
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(items, self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<swc_ecma_ast::Stmt>) {
+        stmts.retain(|stmt| match stmt {
+            swc_ecma_ast::Stmt::Decl(decl) => !is_type_only(decl),
+            _ => true,
+        });
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(stmts, self);
+    }
+
+    fn visit_mut_expr(&mut self, expr: &mut swc_ecma_ast::Expr) {
+        loop {
+            let inner = match expr {
+                swc_ecma_ast::Expr::TsAs(ts_as) => &mut ts_as.expr,
+                swc_ecma_ast::Expr::TsTypeAssertion(assertion) => &mut assertion.expr,
+                swc_ecma_ast::Expr::TsConstAssertion(assertion) => &mut assertion.expr,
+                swc_ecma_ast::Expr::TsNonNull(non_null) => &mut non_null.expr,
+                swc_ecma_ast::Expr::TsSatisfies(satisfies) => &mut satisfies.expr,
+                swc_ecma_ast::Expr::TsInstantiation(instantiation) => &mut instantiation.expr,
+                _ => break,
+            };
+            *expr = *std::mem::replace(
+                inner,
+                Box::new(swc_ecma_ast::Expr::Invalid(swc_ecma_ast::Invalid {
+                    span: swc_common::Span::default(),
+                })),
+            );
+        }
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(expr, self);
+    }
+
+    fn visit_mut_function(&mut self, function: &mut swc_ecma_ast::Function) {
+        function.type_params = None;
+        function.return_type = None;
+        // `this: unknown` only tells the type of `this`
+        function.params.retain(|param| {
+            !matches!(&param.pat, swc_ecma_ast::Pat::Ident(ident) if &*ident.id.sym == "this")
+        });
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(function, self);
+    }
+
+    fn visit_mut_arrow_expr(&mut self, arrow: &mut swc_ecma_ast::ArrowExpr) {
+        arrow.type_params = None;
+        arrow.return_type = None;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(arrow, self);
+    }
+
+    fn visit_mut_binding_ident(&mut self, ident: &mut swc_ecma_ast::BindingIdent) {
+        ident.type_ann = None;
+        ident.id.optional = false;
+    }
+
+    fn visit_mut_array_pat(&mut self, pat: &mut swc_ecma_ast::ArrayPat) {
+        pat.type_ann = None;
+        pat.optional = false;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(pat, self);
+    }
+
+    fn visit_mut_object_pat(&mut self, pat: &mut swc_ecma_ast::ObjectPat) {
+        pat.type_ann = None;
+        pat.optional = false;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(pat, self);
+    }
+
+    fn visit_mut_rest_pat(&mut self, pat: &mut swc_ecma_ast::RestPat) {
+        pat.type_ann = None;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(pat, self);
+    }
+
+    fn visit_mut_var_declarator(&mut self, declarator: &mut swc_ecma_ast::VarDeclarator) {
+        declarator.definite = false;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(declarator, self);
+    }
+
+    fn visit_mut_call_expr(&mut self, call: &mut swc_ecma_ast::CallExpr) {
+        call.type_args = None;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(call, self);
+    }
+
+    fn visit_mut_new_expr(&mut self, new: &mut swc_ecma_ast::NewExpr) {
+        new.type_args = None;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(new, self);
+    }
+
+    fn visit_mut_opt_call(&mut self, call: &mut swc_ecma_ast::OptCall) {
+        call.type_args = None;
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(call, self);
+    }
+}
@@ -0,0 +1,262 @@
+/// Patches to the upstream declarations, read from a TOML file
+///
+/// ```toml
+/// [[override]]
+/// path = "Memento.get"
+/// replace = """
+/// get<T = unknown>(key: string): T | undefined;
+/// get<T>(key: string, defaultValue: T): T;
+/// """
+///
+/// [[override]]
+/// path = "window.showQuickPick"
+/// remove = true
+///
+/// [[override]]
+/// path = "TextEditor"
+/// add = "readonly id: string;"
+/// ```
+///
+/// `replace` and `remove` target the declarations or members named by `path`, every overload of them.
+/// `add` targets a namespace, class or interface (or the module with `path = ""`) and appends to it.
+/// Snippets are written as they would be in index.d.ts at that place.
+/// A path which does not exist upstream is skipped with a diagnostic, so stale overrides are noticed.
+/// The types they reference must be declared by the generated module,
+/// which is checked with the rest of the module after generation.
+#[derive(serde::Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Overrides {
+    #[serde(rename = "override", default)]
+    pub entries: Vec<Override>,
+}
+
+#[derive(serde::Deserialize)]
+pub struct Override {
+    /// Dotted path like `window.showQuickPick` or `TextEditor.options`
+    pub path: String,
+    #[serde(flatten)]
+    pub action: Action,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    /// TypeScript code replacing the target
+    Replace(String),
+    /// Remove the target, which has to be `true`
+    Remove(bool),
+    /// TypeScript code appended to the target
+    Add(String),
+}
+
+impl Overrides {
+    pub fn from_toml(source: &str) -> Result<Self, crate::Error> {
+        let overrides = toml::from_str::<Overrides>(source)
+            .map_err(|error| crate::Error::InvalidOverrides(error.to_string()))?;
+        if let Some(entry) = overrides
+            .entries
+            .iter()
+            .find(|entry| matches!(entry.action, Action::Remove(false)))
+        {
+            return Err(crate::Error::InvalidOverrides(format!(
+                "remove = false of {} does nothing, drop the override instead",
+                entry.path
+            )));
+        }
+        Ok(overrides)
+    }
+}
+
+impl crate::Transform for Overrides {
+    fn transform(
+        &self,
+        mut decls: Vec<crate::pickup::ResultDeclWithComments>,
+        context: &mut crate::TransformContext,
+    ) -> Result<Vec<crate::pickup::ResultDeclWithComments>, crate::Error> {
+        for entry in &self.entries {
+            let segments = if entry.path.is_empty() {
+                vec![]
+            } else {
+                entry.path.split('.').collect::<Vec<_>>()
+            };
+            let is_applied = match &entry.action {
+                Action::Replace(code) => match segments.split_last() {
+                    Some((name, parent)) => match find_container(&mut decls, parent) {
+                        Some(container) => container.replace(name, Some(code), context.comments)?,
+                        None => false,
+                    },
+                    None => false,
+                },
+                Action::Remove(_) => match segments.split_last() {
+                    Some((name, parent)) => match find_container(&mut decls, parent) {
+                        Some(container) => container.replace(name, None, context.comments)?,
+                        None => false,
+                    },
+                    None => false,
+                },
+                Action::Add(code) => match find_container(&mut decls, &segments) {
+                    Some(container) => {
+                        container.add(code, context.comments)?;
+                        true
+                    }
+                    None => false,
+                },
+            };
+            if !is_applied {
+                context
+                    .diagnostics
+                    .push(crate::Diagnostic::OverrideTargetNotFound {
+                        path: entry.path.clone(),
+                    });
+            }
+        }
+        Ok(decls)
+    }
+}
+
+/// Where the members of an override go
+enum Container<'a> {
+    Decls(&'a mut Vec<crate::pickup::ResultDeclWithComments>),
+    Class(&'a mut swc_ecma_ast::Class),
+    Interface(&'a mut swc_ecma_ast::TsInterfaceBody),
+}
+
+fn find_container<'a>(
+    decls: &'a mut Vec<crate::pickup::ResultDeclWithComments>,
+    path: &[&str],
+) -> Option<Container<'a>> {
+    let Some((first, rest)) = path.split_first() else {
+        return Some(Container::Decls(decls));
+    };
+    let result = decls
+        .iter_mut()
+        .find(|result| result.decl.name().is_some_and(|name| &*name == *first))?;
+    match (&mut result.decl, rest.is_empty()) {
+        (crate::pickup::ResultDecl::SubModule(sub_module), _) => {
+            find_container(&mut sub_module.decl_vec, rest)
+        }
+        (crate::pickup::ResultDecl::Class(class), true) => Some(Container::Class(&mut class.class)),
+        (crate::pickup::ResultDecl::TsInterface(interface), true) => {
+            Some(Container::Interface(&mut interface.body))
+        }
+        _ => None,
+    }
+}
+
+impl Container<'_> {
+    /// Replace every member named `name` with the members in `code`, or remove them without `code`.
+    ///
+    /// Returns whether a member named `name` exists.
+    fn replace(
+        self,
+        name: &str,
+        code: Option<&str>,
+        comments: &dyn swc_common::comments::Comments,
+    ) -> Result<bool, crate::Error> {
+        let is_target = |n: Option<swc_atoms::JsWord>| n.is_some_and(|n| &*n == name);
+        match self {
+            Container::Decls(decls) => {
+                let Some(index) = decls
+                    .iter()
+                    .position(|result| is_target(result.decl.name()))
+                else {
+                    return Ok(false);
+                };
+                decls.retain(|result| !is_target(result.decl.name()));
+                if let Some(code) = code {
+                    decls.splice(index..index, parse_decls(code, comments)?);
+                }
+            }
+            Container::Class(class) => {
+                let Some(index) = class
+                    .body
+                    .iter()
+                    .position(|member| is_target(crate::decl_path::class_member_name(member)))
+                else {
+                    return Ok(false);
+                };
+                class
+                    .body
+                    .retain(|member| !is_target(crate::decl_path::class_member_name(member)));
+                if let Some(code) = code {
+                    class
+                        .body
+                        .splice(index..index, parse_class_members(code, comments)?);
+                }
+            }
+            Container::Interface(body) => {
+                let Some(index) = body
+                    .body
+                    .iter()
+                    .position(|element| is_target(crate::decl_path::type_element_name(element)))
+                else {
+                    return Ok(false);
+                };
+                body.body
+                    .retain(|element| !is_target(crate::decl_path::type_element_name(element)));
+                if let Some(code) = code {
+                    body.body
+                        .splice(index..index, parse_type_elements(code, comments)?);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn add(
+        self,
+        code: &str,
+        comments: &dyn swc_common::comments::Comments,
+    ) -> Result<(), crate::Error> {
+        match self {
+            Container::Decls(decls) => decls.extend(parse_decls(code, comments)?),
+            Container::Class(class) => class.body.extend(parse_class_members(code, comments)?),
+            Container::Interface(body) => body.body.extend(parse_type_elements(code, comments)?),
+        }
+        Ok(())
+    }
+}
+
+fn parse_decls(
+    code: &str,
+    comments: &dyn swc_common::comments::Comments,
+) -> Result<Vec<crate::pickup::ResultDeclWithComments>, crate::Error> {
+    let wrapped = format!("declare namespace Override {{\n{}\n}}", code);
+    match crate::snippet::module_items(&wrapped, comments)?.as_slice() {
+        [swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(swc_ecma_ast::Decl::TsModule(
+            module,
+        )))] => match &module.body {
+            Some(swc_ecma_ast::TsNamespaceBody::TsModuleBlock(block)) => {
+                Ok(crate::pickup::pick_module_item(&block.body, comments))
+            }
+            _ => Err(crate::Error::ParseSnippetError(code.to_string())),
+        },
+        _ => Err(crate::Error::ParseSnippetError(code.to_string())),
+    }
+}
+
+fn parse_class_members(
+    code: &str,
+    comments: &dyn swc_common::comments::Comments,
+) -> Result<Vec<swc_ecma_ast::ClassMember>, crate::Error> {
+    let wrapped = format!("declare class Override {{\n{}\n}}", code);
+    match crate::snippet::module_items(&wrapped, comments)?.as_slice() {
+        [swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(swc_ecma_ast::Decl::Class(
+            class,
+        )))] => Ok(class.class.body.clone()),
+        _ => Err(crate::Error::ParseSnippetError(code.to_string())),
+    }
+}
+
+fn parse_type_elements(
+    code: &str,
+    comments: &dyn swc_common::comments::Comments,
+) -> Result<Vec<swc_ecma_ast::TsTypeElement>, crate::Error> {
+    let wrapped = format!("interface Override {{\n{}\n}}", code);
+    match crate::snippet::module_items(&wrapped, comments)?.as_slice() {
+        [swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(
+            swc_ecma_ast::Decl::TsInterface(interface),
+        ))] => Ok(interface.body.body.clone()),
+        _ => Err(crate::Error::ParseSnippetError(code.to_string())),
+    }
+}
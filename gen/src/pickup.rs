@@ -1,5 +1,5 @@
 pub fn pick_module_item(
-    module_items: &Vec<swc_ecma_ast::ModuleItem>,
+    module_items: &[swc_ecma_ast::ModuleItem],
     comments: &dyn swc_common::comments::Comments,
 ) -> Vec<ResultDeclWithComments> {
     module_items
@@ -8,6 +8,38 @@ pub fn pick_module_item(
         .collect()
 }
 
+/// The declarations of `declare module "<module_name>"` in `module_items`,
+/// with the global declarations like `interface Thenable<T>` which it refers to.
+/// Other `declare module` blocks are left out
+pub fn pick_ambient_module(
+    module_items: &[swc_ecma_ast::ModuleItem],
+    module_name: &str,
+    comments: &dyn swc_common::comments::Comments,
+) -> Result<Vec<ResultDeclWithComments>, crate::Error> {
+    let mut found = false;
+    let mut result = Vec::<ResultDeclWithComments>::new();
+    for item in module_items {
+        match item {
+            swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(
+                swc_ecma_ast::Decl::TsModule(module_decl),
+            )) if matches!(module_decl.id, swc_ecma_ast::TsModuleName::Str(_)) => {
+                if let swc_ecma_ast::TsModuleName::Str(name) = &module_decl.id {
+                    if &*name.value == module_name {
+                        found = true;
+                        result.extend(ts_module_decl_to_result_decl_vec(module_decl, comments));
+                    }
+                }
+            }
+            item => result.extend(module_item_to_result_decl_vec(item, comments)),
+        }
+    }
+    if found {
+        Ok(result)
+    } else {
+        Err(crate::Error::AmbientModuleNotFound(module_name.to_string()))
+    }
+}
+
 fn module_item_to_result_decl_vec(
     item: &swc_ecma_ast::ModuleItem,
     comments: &dyn swc_common::comments::Comments,
@@ -159,3 +191,32 @@ pub struct SubModule {
     pub name: swc_ecma_ast::TsModuleName,
     pub decl_vec: Vec<ResultDeclWithComments>,
 }
+
+impl ResultDecl {
+    /// The name used as a segment of declaration paths like `window.showInformationMessage`
+    pub fn name(&self) -> Option<swc_atoms::JsWord> {
+        match self {
+            ResultDecl::Class(class) => Some(class.ident.sym.clone()),
+            ResultDecl::Fn(fn_decl) => Some(fn_decl.ident.sym.clone()),
+            ResultDecl::Var(var_decl) => var_decl.decls.first().and_then(|decl| match &decl.name {
+                swc_ecma_ast::Pat::Ident(ident) => Some(ident.id.sym.clone()),
+                _ => None,
+            }),
+            ResultDecl::TsInterface(interface) => Some(interface.id.sym.clone()),
+            ResultDecl::TsTypeAlias(alias) => Some(alias.id.sym.clone()),
+            ResultDecl::TsEnum(enum_decl) => Some(enum_decl.id.sym.clone()),
+            ResultDecl::SubModule(sub_module) => match &sub_module.name {
+                swc_ecma_ast::TsModuleName::Ident(ident) => Some(ident.sym.clone()),
+                swc_ecma_ast::TsModuleName::Str(str) => Some(str.value.clone()),
+            },
+        }
+    }
+
+    /// Whether this declaration only exists in type space
+    pub fn is_type_only(&self) -> bool {
+        matches!(
+            self,
+            ResultDecl::TsInterface(_) | ResultDecl::TsTypeAlias(_)
+        )
+    }
+}
@@ -0,0 +1,697 @@
+/// ```ts
+/// export type VSCodeAPIPromises = Omit<VSCodeAPI, "window"> & {
+///   readonly window: Omit<VSCodeAPI["window"], "showInformationMessage" | "activeTextEditor"> & {
+///     showInformationMessage(message: string): Promise<string | undefined>;
+///     activeTextEditor: TextEditorPromises | undefined;
+///   };
+/// };
+/// export type TextEditorPromises = Omit<TextEditor, "edit"> & {
+///   edit(callback: (editBuilder: TextEditorEdit) => void): Promise<boolean>;
+/// };
+/// export function importVsCodeApiPromises(): VSCodeAPIPromises | undefined { ... }
+/// ```
+///
+/// The names follow `host`, like `AzdataPromises` and `importAzdataPromises`.
+/// A class or interface gets a `Promises` type when one of its methods returns Thenable,
+/// or one of its members gives an object of such a type, like `TextEditor.document`.
+/// The namespaces and those types give the `Promises` types out, but still take the
+/// original ones, which the `Promises` types are assignable to.
+///
+/// At runtime every object the API gives out is wrapped in a Proxy which converts
+/// the Thenable results of calls, and callbacks get their arguments wrapped too.
+/// Objects the API calls back, like providers, get the original objects.
+/// Classes are kept as is, because `Omit` would drop their construct signatures.
+pub fn module_items(
+    comments: &swc_common::comments::SingleThreadedComments,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    host: &crate::HostModule,
+) -> Result<Vec<swc_ecma_ast::ModuleItem>, crate::Error> {
+    let vs_code_api_type = swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+        span: swc_common::Span::default(),
+        type_name: swc_ecma_ast::TsEntityName::Ident(host.type_ident()),
+        type_params: None,
+    });
+    let promised_types = promised_types(result_vec);
+    let promised_names = promised_types
+        .iter()
+        .map(|promised| promised.name.clone())
+        .collect::<Vec<_>>();
+
+    let mut module_items = vec![exported_type_alias(
+        comments,
+        &format!(
+            "{} whose functions return Promise instead of Thenable",
+            host.type_name
+        ),
+        swc_ecma_ast::TsTypeAliasDecl {
+            span: swc_common::Span::default(),
+            declare: false,
+            id: swc_ecma_ast::Ident::new(
+                string_cache::Atom::from(format!("{}Promises", host.type_name)),
+                swc_common::Span::default(),
+            ),
+            type_params: None,
+            type_ann: Box::new(
+                namespace_type(
+                    vs_code_api_type.clone(),
+                    result_vec,
+                    &promised_names,
+                    comments,
+                )
+                .unwrap_or(vs_code_api_type),
+            ),
+        },
+    )];
+    for promised in &promised_types {
+        module_items.push(exported_type_alias(
+            comments,
+            &format!(
+                "{{@link {}}} whose methods return Promise instead of Thenable",
+                promised.name
+            ),
+            promised.type_alias(&promised_names, comments),
+        ));
+    }
+
+    module_items.extend(crate::snippet::module_items(
+        &format!(
+            "const promisesProxies = new WeakMap<object, object>();
+const promisesTargets = new WeakMap<object, object>();
+const promisesCallbacks = new WeakMap<object, object>();
+
+/**
+ * A Proxy of `value` whose calls return Promise instead of Thenable,
+ * and whose properties and results are wrapped in the same way
+ */
+function toPromises(value: unknown): unknown {{
+  if ((typeof value !== \"object\" && typeof value !== \"function\") || value === null) {{
+    return value;
+  }}
+  if (promisesTargets.has(value)) {{
+    return value;
+  }}
+  const cached = promisesProxies.get(value);
+  if (cached !== undefined) {{
+    return cached;
+  }}
+  const target = value;
+  // the API object is frozen, and a trap of a frozen target has to give its own properties
+  const shadow = typeof target === \"function\"
+    ? function () {{}}
+    : Array.isArray(target)
+    ? []
+    : Object.create(Object.getPrototypeOf(target));
+  const proxy = new Proxy(shadow, {{
+    get: (_, name) => {{
+      const member = Reflect.get(target, name, target);
+      return name === \"prototype\" || name === \"constructor\"
+        ? member
+        : toPromises(member);
+    }},
+    set: (_, name, newValue) =>
+      Reflect.set(target, name, fromPromises(newValue), target),
+    has: (_, name) => Reflect.has(target, name),
+    apply: (_, thisArg, args) =>
+      resultToPromises(
+        Reflect.apply(
+          target as (...args: unknown[]) => unknown,
+          fromPromises(thisArg),
+          args.map(fromPromises),
+        ),
+      ),
+    construct: (_, args) =>
+      toPromises(
+        Reflect.construct(
+          target as new (...args: unknown[]) => object,
+          args.map(fromPromises),
+        ),
+      ) as object,
+  }});
+  promisesProxies.set(target, proxy);
+  promisesTargets.set(proxy, target);
+  return proxy;
+}}
+
+function resultToPromises(result: unknown): unknown {{
+  return typeof (result as PromiseLike<unknown> | null | undefined)?.then === \"function\"
+    ? Promise.resolve(result).then(toPromises)
+    : toPromises(result);
+}}
+
+/**
+ * The original of a value wrapped by {{@link toPromises}}.
+ * A callback, like a listener of an event, gets its arguments wrapped
+ */
+function fromPromises(value: unknown): unknown {{
+  if ((typeof value !== \"object\" && typeof value !== \"function\") || value === null) {{
+    return value;
+  }}
+  const target = promisesTargets.get(value);
+  if (target !== undefined) {{
+    return target;
+  }}
+  if (typeof value !== \"function\") {{
+    return value;
+  }}
+  const cached = promisesCallbacks.get(value);
+  if (cached !== undefined) {{
+    return cached;
+  }}
+  const callback = function (this: unknown, ...args: unknown[]): unknown {{
+    return fromPromises(Reflect.apply(value, this, args.map(toPromises)));
+  }};
+  promisesCallbacks.set(value, callback);
+  return callback;
+}}
+
+/**
+ * {{@link {import_function}}} whose functions return Promise instead of Thenable
+ */
+export function {import_function}Promises(): {type_name}Promises | undefined {{
+  const api = {import_function}();
+  return api === undefined ? undefined : toPromises(api) as {type_name}Promises;
+}}
+",
+            import_function = host.import_function,
+            type_name = host.type_name,
+        ),
+        comments,
+    )?);
+
+    Ok(module_items)
+}
+
+fn exported_type_alias(
+    comments: &dyn swc_common::comments::Comments,
+    doc: &str,
+    alias: swc_ecma_ast::TsTypeAliasDecl,
+) -> swc_ecma_ast::ModuleItem {
+    swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
+        swc_ecma_ast::ExportDecl {
+            span: {
+                let span = swc_common::Span::dummy_with_cmt();
+                comments.add_leading(
+                    span.lo,
+                    swc_common::comments::Comment {
+                        span: swc_common::DUMMY_SP,
+                        kind: swc_common::comments::CommentKind::Block,
+                        text: swc_atoms::Atom::from(format!("*\n * {}\n ", doc)),
+                    },
+                );
+                span
+            },
+            decl: swc_ecma_ast::Decl::TsTypeAlias(Box::new(alias)),
+        },
+    ))
+}
+
+/// A class or interface which gets a `Promises` type
+struct PromisedType {
+    name: swc_atoms::JsWord,
+    type_params: Option<Box<swc_ecma_ast::TsTypeParamDecl>>,
+    /// the extended types, with their type arguments
+    bases: Vec<(
+        swc_atoms::JsWord,
+        Option<Box<swc_ecma_ast::TsTypeParamInstantiation>>,
+    )>,
+    /// the members of the instance type
+    elements: Vec<swc_ecma_ast::TsTypeElement>,
+}
+
+impl PromisedType {
+    /// `Omit<TextEditor, "edit" | keyof Base> & BasePromises & { edit(...): Promise<boolean> }`
+    fn type_alias(
+        &self,
+        promised_names: &[swc_atoms::JsWord],
+        comments: &dyn swc_common::comments::Comments,
+    ) -> swc_ecma_ast::TsTypeAliasDecl {
+        let mut omitted = Vec::<swc_ecma_ast::TsType>::new();
+        let mut members = Vec::<swc_ecma_ast::TsTypeElement>::new();
+        let names = promised_member_names(&self.elements, promised_names);
+        for element in &self.elements {
+            let Some(name) = crate::decl_path::type_element_name(element) else {
+                continue;
+            };
+            if !names.contains(&name) {
+                continue;
+            }
+            if !omitted.contains(&string_lit_type(name.clone())) {
+                omitted.push(string_lit_type(name));
+            }
+            members.push(promised_element(element, promised_names, comments));
+        }
+        let mut types = vec![];
+        for (base, type_args) in &self.bases {
+            if !promised_names.contains(base) {
+                continue;
+            }
+            omitted.push(swc_ecma_ast::TsType::TsTypeOperator(
+                swc_ecma_ast::TsTypeOperator {
+                    span: swc_common::Span::default(),
+                    op: swc_ecma_ast::TsTypeOperatorOp::KeyOf,
+                    type_ann: Box::new(type_ref(base.clone(), type_args.clone())),
+                },
+            ));
+            types.push(Box::new(type_ref(
+                swc_atoms::JsWord::from(format!("{}Promises", base)),
+                type_args.clone(),
+            )));
+        }
+        let instantiation = self.type_params.as_ref().map(|type_params| {
+            Box::new(swc_ecma_ast::TsTypeParamInstantiation {
+                span: swc_common::Span::default(),
+                params: type_params
+                    .params
+                    .iter()
+                    .map(|param| Box::new(type_ref(param.name.sym.clone(), None)))
+                    .collect(),
+            })
+        });
+        types.insert(
+            0,
+            Box::new(omit(type_ref(self.name.clone(), instantiation), omitted)),
+        );
+        if !members.is_empty() {
+            types.push(Box::new(swc_ecma_ast::TsType::TsTypeLit(
+                swc_ecma_ast::TsTypeLit {
+                    span: swc_common::Span::default(),
+                    members,
+                },
+            )));
+        }
+        swc_ecma_ast::TsTypeAliasDecl {
+            span: swc_common::Span::default(),
+            declare: false,
+            id: swc_ecma_ast::Ident::new(
+                swc_atoms::JsWord::from(format!("{}Promises", self.name)),
+                swc_common::Span::default(),
+            ),
+            type_params: self.type_params.clone(),
+            type_ann: Box::new(swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+                swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(
+                    swc_ecma_ast::TsIntersectionType {
+                        span: swc_common::Span::default(),
+                        types,
+                    },
+                ),
+            )),
+        }
+    }
+}
+
+/// The classes and interfaces with a method returning Thenable, and the ones with a member
+/// giving out one of those, until no more are found
+fn promised_types(result_vec: &[crate::pickup::ResultDeclWithComments]) -> Vec<PromisedType> {
+    let mut candidates = Vec::<PromisedType>::new();
+    for result in result_vec {
+        match &result.decl {
+            crate::pickup::ResultDecl::Class(class_decl) => candidates.push(PromisedType {
+                name: class_decl.ident.sym.clone(),
+                type_params: class_decl.class.type_params.clone(),
+                bases: match class_decl.class.super_class.as_deref() {
+                    Some(swc_ecma_ast::Expr::Ident(ident)) => {
+                        vec![(
+                            ident.sym.clone(),
+                            class_decl.class.super_type_params.clone(),
+                        )]
+                    }
+                    _ => vec![],
+                },
+                elements: class_decl
+                    .class
+                    .body
+                    .iter()
+                    .filter_map(crate::type_decls::class_member_to_ts_type_element)
+                    .collect(),
+            }),
+            crate::pickup::ResultDecl::TsInterface(interface) => candidates.push(PromisedType {
+                name: interface.id.sym.clone(),
+                type_params: interface.type_params.clone(),
+                bases: interface
+                    .extends
+                    .iter()
+                    .filter_map(|extends| match &*extends.expr {
+                        swc_ecma_ast::Expr::Ident(ident) => {
+                            Some((ident.sym.clone(), extends.type_args.clone()))
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+                elements: interface.body.body.clone(),
+            }),
+            _ => {}
+        }
+    }
+
+    let mut promised = Vec::<PromisedType>::new();
+    loop {
+        let promised_names = promised
+            .iter()
+            .map(|promised| promised.name.clone())
+            .collect::<Vec<_>>();
+        let (found, rest): (Vec<_>, Vec<_>) = candidates.into_iter().partition(|candidate| {
+            !promised_member_names(&candidate.elements, &promised_names).is_empty()
+                || candidate
+                    .bases
+                    .iter()
+                    .any(|(base, _)| promised_names.contains(base))
+        });
+        candidates = rest;
+        if found.is_empty() {
+            return promised;
+        }
+        promised.extend(found);
+    }
+}
+
+/// Names of the members of which at least one overload gives out Thenable or a promised type
+fn promised_member_names(
+    elements: &[swc_ecma_ast::TsTypeElement],
+    promised_names: &[swc_atoms::JsWord],
+) -> Vec<swc_atoms::JsWord> {
+    let mut names = Vec::<swc_atoms::JsWord>::new();
+    for element in elements {
+        let output = match element {
+            swc_ecma_ast::TsTypeElement::TsMethodSignature(signature) => &signature.type_ann,
+            swc_ecma_ast::TsTypeElement::TsPropertySignature(signature) => &signature.type_ann,
+            swc_ecma_ast::TsTypeElement::TsGetterSignature(signature) => &signature.type_ann,
+            _ => continue,
+        };
+        let Some(name) = crate::decl_path::type_element_name(element) else {
+            continue;
+        };
+        if output
+            .as_ref()
+            .is_some_and(|type_ann| references_promised(type_ann, promised_names))
+            && !names.contains(&name)
+        {
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// The member with `Promise` and the `Promises` types in what it gives out
+fn promised_element(
+    element: &swc_ecma_ast::TsTypeElement,
+    promised_names: &[swc_atoms::JsWord],
+    comments: &dyn swc_common::comments::Comments,
+) -> swc_ecma_ast::TsTypeElement {
+    let mut element = element.clone();
+    // spans of the original, like the one of the key, would take its comments
+    let comment_vec = comments.get_leading(swc_common::Spanned::span(&element).lo);
+    swc_ecma_visit::VisitMutWith::visit_mut_with(&mut element, &mut ResetSpans);
+    let (span, output) = match &mut element {
+        swc_ecma_ast::TsTypeElement::TsMethodSignature(signature) => {
+            (&mut signature.span, &mut signature.type_ann)
+        }
+        swc_ecma_ast::TsTypeElement::TsPropertySignature(signature) => {
+            (&mut signature.span, &mut signature.type_ann)
+        }
+        swc_ecma_ast::TsTypeElement::TsGetterSignature(signature) => {
+            (&mut signature.span, &mut signature.type_ann)
+        }
+        _ => return element,
+    };
+    *span = swc_common::Span::dummy_with_cmt();
+    if let Some(comment_vec) = comment_vec {
+        comments.add_leading_comments(span.lo, comment_vec);
+    }
+    if let Some(type_ann) = output {
+        swc_ecma_visit::VisitMutWith::visit_mut_with(type_ann, &mut ToPromises { promised_names });
+    }
+    element
+}
+
+/// `None` when no member of the namespace gives out Thenable or a promised type
+fn namespace_type(
+    base: swc_ecma_ast::TsType,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    promised_names: &[swc_atoms::JsWord],
+    comments: &dyn swc_common::comments::Comments,
+) -> Option<swc_ecma_ast::TsType> {
+    let promised_fn_names = promised_fn_names(result_vec, promised_names);
+    let mut omitted = Vec::<swc_atoms::JsWord>::new();
+    let mut members = Vec::<swc_ecma_ast::TsTypeElement>::new();
+    let span_with_comments = |result: &crate::pickup::ResultDeclWithComments| {
+        let span = swc_common::Span::dummy_with_cmt();
+        if let Some(comment_vec) = &result.comments {
+            comments.add_leading_comments(span.lo, comment_vec.clone());
+        }
+        span
+    };
+    for result in result_vec {
+        match &result.decl {
+            crate::pickup::ResultDecl::Fn(fn_decl)
+                if promised_fn_names.contains(&fn_decl.ident.sym) =>
+            {
+                if !omitted.contains(&fn_decl.ident.sym) {
+                    omitted.push(fn_decl.ident.sym.clone());
+                }
+                members.push(swc_ecma_ast::TsTypeElement::TsMethodSignature(
+                    swc_ecma_ast::TsMethodSignature {
+                        span: span_with_comments(result),
+                        readonly: false,
+                        computed: false,
+                        optional: false,
+                        key: Box::new(swc_ecma_ast::Expr::Ident(fn_decl.ident.clone())),
+                        params: fn_decl
+                            .function
+                            .params
+                            .iter()
+                            .map(|param| crate::fn_to_type::pat_to_ts_fn_param(&param.pat))
+                            .collect(),
+                        type_ann: fn_decl.function.return_type.clone().map(|mut type_ann| {
+                            swc_ecma_visit::VisitMutWith::visit_mut_with(
+                                &mut type_ann,
+                                &mut ToPromises { promised_names },
+                            );
+                            type_ann
+                        }),
+                        type_params: fn_decl.function.type_params.clone(),
+                    },
+                ));
+            }
+            crate::pickup::ResultDecl::Var(var_decl) => {
+                for declarator in &var_decl.decls {
+                    let swc_ecma_ast::Pat::Ident(binding_ident) = &declarator.name else {
+                        continue;
+                    };
+                    let Some(type_ann) = &binding_ident.type_ann else {
+                        continue;
+                    };
+                    if !references_promised(type_ann, promised_names) {
+                        continue;
+                    }
+                    let mut type_ann = type_ann.clone();
+                    swc_ecma_visit::VisitMutWith::visit_mut_with(
+                        &mut type_ann,
+                        &mut ToPromises { promised_names },
+                    );
+                    omitted.push(binding_ident.id.sym.clone());
+                    members.push(swc_ecma_ast::TsTypeElement::TsPropertySignature(
+                        swc_ecma_ast::TsPropertySignature {
+                            span: span_with_comments(result),
+                            readonly: var_decl.kind == swc_ecma_ast::VarDeclKind::Const,
+                            key: Box::new(swc_ecma_ast::Expr::Ident(binding_ident.id.clone())),
+                            computed: false,
+                            optional: false,
+                            init: None,
+                            params: vec![],
+                            type_ann: Some(type_ann),
+                            type_params: None,
+                        },
+                    ));
+                }
+            }
+            crate::pickup::ResultDecl::SubModule(sub_module) => {
+                let (name, key) = match &sub_module.name {
+                    swc_ecma_ast::TsModuleName::Ident(ident) => {
+                        (ident.sym.clone(), swc_ecma_ast::Expr::Ident(ident.clone()))
+                    }
+                    swc_ecma_ast::TsModuleName::Str(str) => (
+                        str.value.clone(),
+                        swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str.clone())),
+                    ),
+                };
+                if let Some(sub_module_type) = namespace_type(
+                    swc_ecma_ast::TsType::TsIndexedAccessType(swc_ecma_ast::TsIndexedAccessType {
+                        span: swc_common::Span::default(),
+                        readonly: false,
+                        obj_type: Box::new(base.clone()),
+                        index_type: Box::new(string_lit_type(name.clone())),
+                    }),
+                    &sub_module.decl_vec,
+                    promised_names,
+                    comments,
+                ) {
+                    omitted.push(name);
+                    members.push(swc_ecma_ast::TsTypeElement::TsPropertySignature(
+                        swc_ecma_ast::TsPropertySignature {
+                            span: span_with_comments(result),
+                            readonly: true,
+                            key: Box::new(key),
+                            computed: false,
+                            optional: false,
+                            init: None,
+                            params: vec![],
+                            type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
+                                span: swc_common::Span::default(),
+                                type_ann: Box::new(sub_module_type),
+                            })),
+                            type_params: None,
+                        },
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+    if omitted.is_empty() {
+        return None;
+    }
+    Some(swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+        swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(
+            swc_ecma_ast::TsIntersectionType {
+                span: swc_common::Span::default(),
+                types: vec![
+                    Box::new(omit(
+                        base,
+                        omitted.into_iter().map(string_lit_type).collect(),
+                    )),
+                    Box::new(swc_ecma_ast::TsType::TsTypeLit(swc_ecma_ast::TsTypeLit {
+                        span: swc_common::Span::default(),
+                        members,
+                    })),
+                ],
+            },
+        ),
+    ))
+}
+
+/// names of the functions of which at least one overload gives out Thenable or a promised type
+fn promised_fn_names(
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    promised_names: &[swc_atoms::JsWord],
+) -> Vec<swc_atoms::JsWord> {
+    let mut names = Vec::<swc_atoms::JsWord>::new();
+    for result in result_vec {
+        if let crate::pickup::ResultDecl::Fn(fn_decl) = &result.decl {
+            if let Some(return_type) = &fn_decl.function.return_type {
+                if references_promised(return_type, promised_names)
+                    && !names.contains(&fn_decl.ident.sym)
+                {
+                    names.push(fn_decl.ident.sym.clone());
+                }
+            }
+        }
+    }
+    names
+}
+
+/// `Omit<base, omitted>`
+fn omit(
+    base: swc_ecma_ast::TsType,
+    mut omitted: Vec<swc_ecma_ast::TsType>,
+) -> swc_ecma_ast::TsType {
+    let keys = if omitted.len() == 1 {
+        omitted.remove(0)
+    } else {
+        swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(swc_ecma_ast::TsUnionType {
+                span: swc_common::Span::default(),
+                types: omitted.into_iter().map(Box::new).collect(),
+            }),
+        )
+    };
+    swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+        span: swc_common::Span::default(),
+        type_name: swc_ecma_ast::TsEntityName::Ident(swc_ecma_ast::Ident::new(
+            string_cache::Atom::from("Omit"),
+            swc_common::Span::default(),
+        )),
+        type_params: Some(Box::new(swc_ecma_ast::TsTypeParamInstantiation {
+            span: swc_common::Span::default(),
+            params: vec![Box::new(base), Box::new(keys)],
+        })),
+    })
+}
+
+fn type_ref(
+    name: swc_atoms::JsWord,
+    type_params: Option<Box<swc_ecma_ast::TsTypeParamInstantiation>>,
+) -> swc_ecma_ast::TsType {
+    swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+        span: swc_common::Span::default(),
+        type_name: swc_ecma_ast::TsEntityName::Ident(swc_ecma_ast::Ident::new(
+            name,
+            swc_common::Span::default(),
+        )),
+        type_params,
+    })
+}
+
+fn references_promised(
+    type_ann: &swc_ecma_ast::TsTypeAnn,
+    promised_names: &[swc_atoms::JsWord],
+) -> bool {
+    let mut finder = PromisedFinder {
+        promised_names,
+        found: false,
+    };
+    swc_ecma_visit::VisitWith::visit_with(type_ann, &mut finder);
+    finder.found
+}
+
+struct PromisedFinder<'a> {
+    promised_names: &'a [swc_atoms::JsWord],
+    found: bool,
+}
+
+impl swc_ecma_visit::Visit for PromisedFinder<'_> {
+    fn visit_ts_type_ref(&mut self, type_ref: &swc_ecma_ast::TsTypeRef) {
+        if let swc_ecma_ast::TsEntityName::Ident(ident) = &type_ref.type_name {
+            if &*ident.sym == "Thenable" || self.promised_names.contains(&ident.sym) {
+                self.found = true;
+            }
+        }
+        swc_ecma_visit::VisitWith::visit_children_with(type_ref, self);
+    }
+}
+
+/// `Thenable` to `Promise`, and the promised types to their `Promises` types
+struct ToPromises<'a> {
+    promised_names: &'a [swc_atoms::JsWord],
+}
+
+impl swc_ecma_visit::VisitMut for ToPromises<'_> {
+    fn visit_mut_ts_type_ref(&mut self, type_ref: &mut swc_ecma_ast::TsTypeRef) {
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(type_ref, self);
+        if let swc_ecma_ast::TsEntityName::Ident(ident) = &mut type_ref.type_name {
+            if &*ident.sym == "Thenable" {
+                ident.sym = string_cache::Atom::from("Promise");
+            } else if self.promised_names.contains(&ident.sym) {
+                ident.sym = string_cache::Atom::from(format!("{}Promises", ident.sym));
+            }
+        }
+    }
+}
+
+struct ResetSpans;
+
+impl swc_ecma_visit::VisitMut for ResetSpans {
+    fn visit_mut_span(&mut self, span: &mut swc_common::Span) {
+        *span = swc_common::DUMMY_SP;
+    }
+}
+
+fn string_lit_type(value: swc_atoms::JsWord) -> swc_ecma_ast::TsType {
+    swc_ecma_ast::TsType::TsLitType(swc_ecma_ast::TsLitType {
+        span: swc_common::Span::default(),
+        lit: swc_ecma_ast::TsLit::Str(swc_ecma_ast::Str {
+            span: swc_common::Span::default(),
+            value,
+            raw: None,
+        }),
+    })
+}
@@ -0,0 +1,327 @@
+//! Typed messages between the extension and its webview.
+//!
+//! The messages are declared as two discriminated unions in a TypeScript file
+//!
+//! ```ts
+//! export type ExtensionToWebview = { type: "update"; text: string } | { type: "reset" };
+//! export type WebviewToExtension = { type: "ready" } | { type: "edit"; text: string };
+//! ```
+//!
+//! and the generated module wraps `Webview.postMessage` and `onDidReceiveMessage` of the extension
+//! and `postMessage` and the `message` event of the webview,
+//! passing the received messages whose discriminant is not in the union to `onInvalid` instead.
+
+/// Names in the file of the messages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Union of the messages the extension sends to the webview
+    pub to_webview: String,
+    /// Union of the messages the webview sends to the extension
+    pub to_extension: String,
+    /// Property which tells the messages apart
+    pub discriminant: String,
+    /// Specifier the generated module imports the unions from, like `./messages.ts`
+    pub types_module: String,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            to_webview: "ExtensionToWebview".to_string(),
+            to_extension: "WebviewToExtension".to_string(),
+            discriminant: "type".to_string(),
+            types_module: "./messages.ts".to_string(),
+        }
+    }
+}
+
+/// Generate the module from `source` of `file`, which declares the unions of `options`
+pub fn generate(file: &str, source: &str, options: &Options) -> Result<String, crate::Error> {
+    let module = crate::usage::parse(file, source)?;
+    let declarations = Declarations::new(&module);
+    let to_webview = declarations.discriminants(file, source, &options.to_webview, options)?;
+    let to_extension = declarations.discriminants(file, source, &options.to_extension, options)?;
+
+    let code = format!(
+        r#"import type {{ {to_webview_type}, {to_extension_type} }} from {types_module};
+
+/** The discriminants of {{@link {to_webview_type}}} */
+const {to_webview_type_camel}Discriminants: readonly unknown[] = {to_webview};
+
+/** The discriminants of {{@link {to_extension_type}}} */
+const {to_extension_type_camel}Discriminants: readonly unknown[] = {to_extension};
+
+/**
+ * Whether `message` has a `{discriminant}` of {{@link {to_webview_type}}}.
+ * The other properties are not checked
+ */
+export function is{to_webview_type}(message: unknown): message is {to_webview_type} {{
+  return typeof message === "object" && message !== null &&
+    {to_webview_type_camel}Discriminants.includes((message as {{ readonly {discriminant_key}?: unknown }}){discriminant_access});
+}}
+
+/**
+ * Whether `message` has a `{discriminant}` of {{@link {to_extension_type}}}.
+ * The other properties are not checked
+ */
+export function is{to_extension_type}(message: unknown): message is {to_extension_type} {{
+  return typeof message === "object" && message !== null &&
+    {to_extension_type_camel}Discriminants.includes((message as {{ readonly {discriminant_key}?: unknown }}){discriminant_access});
+}}
+
+/**
+ * The side of the extension, around the `webview` of a `WebviewPanel` or `WebviewView`
+ *
+ * ```ts
+ * const messenger = createExtensionMessenger(panel.webview);
+ * messenger.onMessage((message) => {{ ... }});
+ * ```
+ */
+export function createExtensionMessenger(webview: {{
+  readonly postMessage: (message: unknown) => PromiseLike<boolean>;
+  readonly onDidReceiveMessage: (listener: (message: unknown) => unknown) => {{ dispose(): unknown }};
+}}): {{
+  readonly post: (message: {to_webview_type}) => PromiseLike<boolean>;
+  readonly onMessage: (
+    listener: (message: {to_extension_type}) => void,
+    onInvalid?: (message: unknown) => void,
+  ) => {{ dispose(): unknown }};
+}} {{
+  return {{
+    post: (message) => webview.postMessage(message),
+    onMessage: (listener, onInvalid) =>
+      webview.onDidReceiveMessage((message) => {{
+        if (is{to_extension_type}(message)) {{
+          listener(message);
+        }} else {{
+          onInvalid?.(message);
+        }}
+      }}),
+  }};
+}}
+
+/**
+ * The side of the webview, around the API of `getWebviewApi()` or `acquireVsCodeApi()`
+ *
+ * ```ts
+ * const messenger = createWebviewMessenger(getWebviewApi()!);
+ * messenger.post({{ ... }});
+ * ```
+ */
+export function createWebviewMessenger(api: {{
+  readonly postMessage: (message: unknown) => void;
+}}): {{
+  readonly post: (message: {to_extension_type}) => void;
+  readonly onMessage: (
+    listener: (message: {to_webview_type}) => void,
+    onInvalid?: (message: unknown) => void,
+  ) => {{ dispose(): void }};
+}} {{
+  return {{
+    post: (message) => api.postMessage(message),
+    onMessage: (listener, onInvalid) => {{
+      const handler = (event: Event): void => {{
+        const message = (event as MessageEvent).data;
+        if (is{to_webview_type}(message)) {{
+          listener(message);
+        }} else {{
+          onInvalid?.(message);
+        }}
+      }};
+      globalThis.addEventListener("message", handler);
+      return {{ dispose: () => globalThis.removeEventListener("message", handler) }};
+    }},
+  }};
+}}
+"#,
+        to_webview_type = options.to_webview,
+        to_extension_type = options.to_extension,
+        to_webview_type_camel = crate::decl_path::lower_first(&options.to_webview),
+        to_extension_type_camel = crate::decl_path::lower_first(&options.to_extension),
+        types_module = crate::snippet::string_literal(&options.types_module),
+        to_webview = to_webview,
+        to_extension = to_extension,
+        discriminant = options.discriminant,
+        discriminant_key = crate::snippet::property_key(&options.discriminant),
+        discriminant_access =
+            if crate::snippet::property_key(&options.discriminant) == options.discriminant {
+                format!(".{}", options.discriminant)
+            } else {
+                format!(
+                    "[{}]",
+                    crate::snippet::string_literal(&options.discriminant)
+                )
+            },
+    );
+    // the text is kept as written, parsing only checks it
+    crate::snippet::module_items(&code, &swc_common::comments::NoopComments)?;
+    Ok(code)
+}
+
+/// Type aliases and interfaces of the file by name
+struct Declarations<'a> {
+    aliases: std::collections::HashMap<&'a str, &'a swc_ecma_ast::TsType>,
+    interfaces: std::collections::HashMap<&'a str, &'a swc_ecma_ast::TsInterfaceDecl>,
+}
+
+impl<'a> Declarations<'a> {
+    fn new(module: &'a swc_ecma_ast::Module) -> Self {
+        let mut declarations = Declarations {
+            aliases: std::collections::HashMap::new(),
+            interfaces: std::collections::HashMap::new(),
+        };
+        for item in &module.body {
+            let decl = match item {
+                swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
+                    export_decl,
+                )) => &export_decl.decl,
+                swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(decl)) => decl,
+                _ => continue,
+            };
+            match decl {
+                swc_ecma_ast::Decl::TsTypeAlias(alias) => {
+                    declarations.aliases.insert(&alias.id.sym, &alias.type_ann);
+                }
+                swc_ecma_ast::Decl::TsInterface(interface) => {
+                    declarations.interfaces.insert(&interface.id.sym, interface);
+                }
+                _ => {}
+            }
+        }
+        declarations
+    }
+
+    /// Array literal of the discriminants of the members of the union `name`
+    fn discriminants(
+        &self,
+        file: &str,
+        source: &str,
+        name: &str,
+        options: &Options,
+    ) -> Result<String, crate::Error> {
+        let Some(union) = self.aliases.get(name) else {
+            return Err(crate::Error::InvalidProtocol(format!(
+                "{}: no type alias {}",
+                file, name
+            )));
+        };
+        let mut values = Vec::<String>::new();
+        for member in union_members(union) {
+            let value = self
+                .discriminant(member, &options.discriminant, 0)
+                .ok_or_else(|| {
+                    let (line, column) =
+                        crate::usage::line_column(source, swc_common::Spanned::span(member).lo);
+                    crate::Error::InvalidProtocol(format!(
+                        "{}:{}:{}: a member of {} without a literal `{}`",
+                        file, line, column, name, options.discriminant
+                    ))
+                })?;
+            if values.contains(&value) {
+                let (line, column) =
+                    crate::usage::line_column(source, swc_common::Spanned::span(member).lo);
+                return Err(crate::Error::InvalidProtocol(format!(
+                    "{}:{}:{}: {} has `{}` {} twice",
+                    file, line, column, name, options.discriminant, value
+                )));
+            }
+            values.push(value);
+        }
+        Ok(format!("[{}]", values.join(", ")))
+    }
+
+    /// The discriminant of a member, which is an object type or a name of one in the file
+    fn discriminant(
+        &self,
+        member: &swc_ecma_ast::TsType,
+        discriminant: &str,
+        depth: usize,
+    ) -> Option<String> {
+        // aliases referring to each other
+        if depth > 16 {
+            return None;
+        }
+        match member {
+            swc_ecma_ast::TsType::TsTypeLit(type_lit) => {
+                literal_property(&type_lit.members, discriminant)
+            }
+            swc_ecma_ast::TsType::TsParenthesizedType(paren) => {
+                self.discriminant(&paren.type_ann, discriminant, depth + 1)
+            }
+            swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+                swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(intersection),
+            ) => intersection
+                .types
+                .iter()
+                .find_map(|ts_type| self.discriminant(ts_type, discriminant, depth + 1)),
+            swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+                type_name: swc_ecma_ast::TsEntityName::Ident(ident),
+                ..
+            }) => {
+                if let Some(alias) = self.aliases.get(&*ident.sym) {
+                    return self.discriminant(alias, discriminant, depth + 1);
+                }
+                let interface = self.interfaces.get(&*ident.sym)?;
+                literal_property(&interface.body.body, discriminant).or_else(|| {
+                    interface
+                        .extends
+                        .iter()
+                        .find_map(|parent| match &*parent.expr {
+                            swc_ecma_ast::Expr::Ident(parent) => self.discriminant(
+                                &swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+                                    span: parent.span,
+                                    type_name: swc_ecma_ast::TsEntityName::Ident(parent.clone()),
+                                    type_params: None,
+                                }),
+                                discriminant,
+                                depth + 1,
+                            ),
+                            _ => None,
+                        })
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A union is flattened, and any other type is a union of itself
+fn union_members(ts_type: &swc_ecma_ast::TsType) -> Vec<&swc_ecma_ast::TsType> {
+    match ts_type {
+        swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(union),
+        ) => union
+            .types
+            .iter()
+            .flat_map(|ts_type| union_members(ts_type))
+            .collect(),
+        swc_ecma_ast::TsType::TsParenthesizedType(paren) => union_members(&paren.type_ann),
+        ts_type => vec![ts_type],
+    }
+}
+
+/// The literal type of the property `name` as JavaScript, like `"update"` or `1`
+fn literal_property(members: &[swc_ecma_ast::TsTypeElement], name: &str) -> Option<String> {
+    members.iter().find_map(|member| {
+        let swc_ecma_ast::TsTypeElement::TsPropertySignature(property) = member else {
+            return None;
+        };
+        let key = match &*property.key {
+            swc_ecma_ast::Expr::Ident(ident) => ident.sym.to_string(),
+            swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str)) => str.value.to_string(),
+            _ => return None,
+        };
+        if key != name || property.optional {
+            return None;
+        }
+        match &*property.type_ann.as_ref()?.type_ann {
+            swc_ecma_ast::TsType::TsLitType(lit_type) => match &lit_type.lit {
+                swc_ecma_ast::TsLit::Str(str) => Some(crate::snippet::string_literal(&str.value)),
+                swc_ecma_ast::TsLit::Number(number) => Some(number.value.to_string()),
+                swc_ecma_ast::TsLit::Bool(bool) => Some(bool.value.to_string()),
+                _ => None,
+            },
+            _ => None,
+        }
+    })
+}
@@ -1,258 +1,205 @@
-pub fn module_items(
-    comments: &swc_common::comments::SingleThreadedComments,
-) -> Vec<swc_ecma_ast::ModuleItem> {
-    vec![
-        swc_ecma_ast::ModuleItem::Stmt(declare_const_require()),
-        swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
-            swc_ecma_ast::ExportDecl {
-                span: {
-                    let span = swc_common::Span::dummy_with_cmt();
-                    swc_common::comments::Comments::add_leading(
-                        comments,
-                        span.lo,
-                        swc_common::comments::Comment {
-                            span: swc_common::DUMMY_SP,
-                            kind: swc_common::comments::CommentKind::Block,
-                            text: swc_atoms::Atom::from(
-                                "* import VS Code API
-```ts
-require(\"vscode\")
-```
+/// A way for `importVsCodeApi` to get the VS Code API at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Loader {
+    /// `require("vscode")` of CommonJS
+    Require,
+    /// `globalThis.require("vscode")`, for bundles which rename or shadow `require`
+    GlobalThisRequire,
+    /// `module.createRequire(...)("vscode")` of Node.js, for ES modules
+    CreateRequire,
+    /// The module given to `setVsCodeApi(api)`, for tests
+    Inject,
+}
 
-Returns VSCodeApi only within the vscode extension.
-",
-                            ),
-                        },
-                    );
+/// What `importVsCodeApi` does without any loader given
+pub const DEFAULT_LOADERS: &[Loader] = &[Loader::Require];
 
-                    span
-                },
-                decl: swc_ecma_ast::Decl::Fn(swc_ecma_ast::FnDecl {
-                    ident: swc_ecma_ast::Ident::new(
-                        string_cache::Atom::from("importVsCodeApi"),
-                        swc_common::Span::default(),
-                    ),
-                    declare: false,
-                    function: Box::new(func()),
-                }),
-            },
-        )),
-    ]
-}
+impl Loader {
+    pub const ALL: &'static [Loader] = &[
+        Loader::Require,
+        Loader::GlobalThisRequire,
+        Loader::CreateRequire,
+        Loader::Inject,
+    ];
+
+    /// Name in the command line, like `global-this-require`
+    pub fn name(self) -> &'static str {
+        match self {
+            Loader::Require => "require",
+            Loader::GlobalThisRequire => "global-this-require",
+            Loader::CreateRequire => "create-require",
+            Loader::Inject => "inject",
+        }
+    }
+
+    /// Shown in the error of `importVsCodeApiOrThrow`, like `require("vscode")`
+    fn description(self, host: &crate::HostModule) -> String {
+        let module_name = crate::snippet::string_literal(&host.module_name);
+        match self {
+            Loader::Require => format!("require({})", module_name),
+            Loader::GlobalThisRequire => format!("globalThis.require({})", module_name),
+            Loader::CreateRequire => format!("module.createRequire(...)({})", module_name),
+            Loader::Inject => format!("{}(api)", host.inject_function()),
+        }
+    }
 
-fn func() -> swc_ecma_ast::Function {
-    swc_ecma_ast::Function {
-        params: vec![],
-        decorators: vec![],
-        span: swc_common::Span::default(),
-        body: Some(func_body()),
-        is_async: false,
-        is_generator: false,
-        type_params: None,
-        return_type: Some(Box::new(swc_ecma_ast::TsTypeAnn {
-            span: swc_common::Span::default(),
-            type_ann: Box::new(swc_ecma_ast::TsType::TsUnionOrIntersectionType(
-                swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(swc_ecma_ast::TsUnionType {
-                    span: swc_common::Span::default(),
-                    types: vec![
-                        Box::new(swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
-                            span: swc_common::Span::default(),
-                            type_name: swc_ecma_ast::TsEntityName::Ident(
-                                (*crate::ident::VS_CODE_API_IDENT).clone(),
-                            ),
-                            type_params: None,
-                        })),
-                        Box::new(swc_ecma_ast::TsType::TsKeywordType(
-                            swc_ecma_ast::TsKeywordType {
-                                span: swc_common::Span::default(),
-                                kind: swc_ecma_ast::TsKeywordTypeKind::TsUndefinedKeyword,
-                            },
-                        )),
-                    ],
-                }),
-            )),
-        })),
+    /// Body of the arrow function which returns the module or `undefined`
+    fn load(self, host: &crate::HostModule) -> String {
+        let module_name = crate::snippet::string_literal(&host.module_name);
+        match self {
+            Loader::Require => {
+                format!(r#"typeof require === "function" ? require({module_name}) : undefined"#)
+            }
+            Loader::GlobalThisRequire => format!(
+                r#"{{
+      const requireFunc = (globalThis as {{ readonly require?: unknown }}).require;
+      return typeof requireFunc === "function" ? requireFunc({module_name}) : undefined;
+    }}"#
+            ),
+            // hosts like the extension host resolve their module whatever the base path is
+            Loader::CreateRequire => format!(
+                r#"{{
+      const process = (globalThis as {{
+        readonly process?: {{
+          readonly cwd: () => string;
+          readonly getBuiltinModule?: (id: string) => unknown;
+        }};
+      }}).process;
+      const nodeModule = process?.getBuiltinModule?.("node:module") as {{
+        readonly createRequire?: (path: string) => (id: string) => unknown;
+      }} | undefined;
+      return typeof nodeModule?.createRequire === "function"
+        ? nodeModule.createRequire(`${{process!.cwd()}}/`)({module_name}) as {type_name}
+        : undefined;
+    }}"#,
+                type_name = host.type_name
+            ),
+            Loader::Inject => "injectedModule".to_string(),
+        }
     }
 }
 
-fn func_body() -> swc_ecma_ast::BlockStmt {
-    swc_ecma_ast::BlockStmt {
-        span: swc_common::Span::default(),
-        stmts: vec![
-            swc_ecma_ast::Stmt::Decl(swc_ecma_ast::Decl::Var(Box::new(swc_ecma_ast::VarDecl {
-                span: swc_common::Span::default(),
-                declare: false,
-                kind: swc_ecma_ast::VarDeclKind::Const,
-                decls: vec![swc_ecma_ast::VarDeclarator {
-                    span: swc_common::Span::default(),
-                    definite: true,
-                    name: swc_ecma_ast::Pat::Ident(swc_ecma_ast::BindingIdent {
-                        id: require_func_ident(),
-                        type_ann: None,
-                    }),
-                    init: Some(Box::new(swc_ecma_ast::Expr::Cond(swc_ecma_ast::CondExpr {
-                        span: swc_common::Span::default(),
-                        test: Box::new(swc_ecma_ast::Expr::Bin(swc_ecma_ast::BinExpr {
-                            span: swc_common::Span::default(),
-                            op: swc_ecma_ast::BinaryOp::EqEqEq,
-                            left: Box::new(swc_ecma_ast::Expr::Unary(swc_ecma_ast::UnaryExpr {
-                                span: swc_common::Span::default(),
-                                op: swc_ecma_ast::UnaryOp::TypeOf,
-                                arg: Box::new(swc_ecma_ast::Expr::Ident(swc_ecma_ast::Ident::new(
-                                    string_cache::Atom::from("require"),
-                                    swc_common::Span::default(),
-                                ))),
-                            })),
-                            right: Box::new(swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(
-                                swc_ecma_ast::Str {
-                                    span: swc_common::Span::default(),
-                                    value: string_cache::Atom::from("function"),
-                                    raw: None,
-                                },
-                            ))),
-                        })),
-                        cons: Box::new(swc_ecma_ast::Expr::Ident(swc_ecma_ast::Ident::new(
-                            string_cache::Atom::from("require"),
-                            swc_common::Span::default(),
-                        ))),
-                        alt: Box::new(undefined_expr()),
-                    }))),
-                }],
-            }))),
-            swc_ecma_ast::Stmt::Return(swc_ecma_ast::ReturnStmt {
-                span: swc_common::Span::default(),
-                arg: Some(Box::new(swc_ecma_ast::Expr::Cond(swc_ecma_ast::CondExpr {
-                    span: swc_common::Span::default(),
-                    test: Box::new(swc_ecma_ast::Expr::Bin(swc_ecma_ast::BinExpr {
-                        span: swc_common::Span::default(),
-                        op: swc_ecma_ast::BinaryOp::EqEqEq,
-                        left: Box::new(swc_ecma_ast::Expr::Ident(require_func_ident())),
-                        right: Box::new(undefined_expr()),
-                    })),
-                    cons: Box::new(undefined_expr()),
-                    alt: Box::new(swc_ecma_ast::Expr::Call(swc_ecma_ast::CallExpr {
-                        span: swc_common::Span::default(),
-                        callee: swc_ecma_ast::Callee::Expr(Box::new(swc_ecma_ast::Expr::Ident(
-                            require_func_ident(),
-                        ))),
-                        args: vec![swc_ecma_ast::ExprOrSpread {
-                            spread: None,
-                            expr: Box::new(swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(
-                                swc_ecma_ast::Str {
-                                    span: swc_common::Span::default(),
-                                    value: string_cache::Atom::from("vscode"),
-                                    raw: None,
-                                },
-                            ))),
-                        }],
-                        type_args: None,
-                    })),
-                }))),
-            }),
-        ],
+impl std::str::FromStr for Loader {
+    type Err = crate::Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Loader::ALL
+            .iter()
+            .copied()
+            .find(|loader| loader.name() == name)
+            .ok_or_else(|| crate::Error::InvalidLoader(name.to_string()))
     }
 }
 
 /// ```ts
-/// undefined | ((path: "vscode") => VSCodeApi)
+/// export function importVsCodeApi(): VSCodeAPI | undefined { ... }
+/// export function importVsCodeApiOrThrow(): VSCodeAPI { ... }
 /// ```
-fn require_function_type() -> swc_ecma_ast::TsType {
-    swc_ecma_ast::TsType::TsUnionOrIntersectionType(
-        swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(swc_ecma_ast::TsUnionType {
-            span: swc_common::Span::default(),
-            types: vec![
-                Box::new(swc_ecma_ast::TsType::TsKeywordType(
-                    swc_ecma_ast::TsKeywordType {
-                        span: swc_common::Span::default(),
-                        kind: swc_ecma_ast::TsKeywordTypeKind::TsUndefinedKeyword,
-                    },
-                )),
-                Box::new(swc_ecma_ast::TsType::TsParenthesizedType(
-                    swc_ecma_ast::TsParenthesizedType {
-                        span: swc_common::Span::default(),
-                        type_ann: Box::new(swc_ecma_ast::TsType::TsFnOrConstructorType(
-                            swc_ecma_ast::TsFnOrConstructorType::TsFnType(swc_ecma_ast::TsFnType {
-                                span: swc_common::Span::default(),
-                                params: vec![swc_ecma_ast::TsFnParam::Ident(
-                                    swc_ecma_ast::BindingIdent {
-                                        id: swc_ecma_ast::Ident::new(
-                                            string_cache::Atom::from("path"),
-                                            swc_common::Span::default(),
-                                        ),
-                                        type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
-                                            span: swc_common::Span::default(),
-                                            type_ann: Box::new(swc_ecma_ast::TsType::TsLitType(
-                                                swc_ecma_ast::TsLitType {
-                                                    span: swc_common::Span::default(),
-                                                    lit: swc_ecma_ast::TsLit::Str(
-                                                        swc_ecma_ast::Str {
-                                                            span: swc_common::Span::default(),
-                                                            value: string_cache::Atom::from(
-                                                                "vscode",
-                                                            ),
-                                                            raw: None,
-                                                        },
-                                                    ),
-                                                },
-                                            )),
-                                        })),
-                                    },
-                                )],
-                                type_params: None,
-                                type_ann: Box::new(swc_ecma_ast::TsTypeAnn {
-                                    span: swc_common::Span::default(),
-                                    type_ann: Box::new(swc_ecma_ast::TsType::TsTypeRef(
-                                        swc_ecma_ast::TsTypeRef {
-                                            span: swc_common::Span::default(),
-                                            type_name: swc_ecma_ast::TsEntityName::Ident(
-                                                (*crate::ident::VS_CODE_API_IDENT).clone(),
-                                            ),
-                                            type_params: None,
-                                        },
-                                    )),
-                                }),
-                            }),
-                        )),
-                    },
-                )),
-            ],
-        }),
-    )
-}
+///
+/// The names and the module follow `host`.
+/// `loaders` are tried in order. The first one which returns the module wins
+pub fn module_items(
+    comments: &dyn swc_common::comments::Comments,
+    loaders: &[Loader],
+    host: &crate::HostModule,
+) -> Result<Vec<swc_ecma_ast::ModuleItem>, crate::Error> {
+    let type_name = &host.type_name;
+    let import_function = &host.import_function;
+    let mut code = String::new();
+    if loaders.contains(&Loader::Require) {
+        code.push_str(&format!(
+            "declare const require: undefined | ((path: {}) => {});\n",
+            crate::snippet::string_literal(&host.module_name),
+            type_name
+        ));
+    }
+    if loaders.contains(&Loader::Inject) {
+        code.push_str(&format!(
+            r#"let injectedModule: {type_name} | undefined = undefined;
 
-fn require_func_ident() -> swc_ecma_ast::Ident {
-    swc_ecma_ast::Ident::new(
-        string_cache::Atom::from("requireFunc"),
-        swc_common::Span::default(),
-    )
-}
+/**
+ * Make {{@link {import_function}}} return `api`, like a fake in unit tests.
+ * `undefined` takes it back
+ */
+export function {inject_function}(api: {type_name} | undefined): void {{
+  injectedModule = api;
+}}
+"#,
+            inject_function = host.inject_function(),
+        ));
+    }
+    let loader_entries = loaders
+        .iter()
+        .map(|loader| {
+            format!(
+                "  {{\n    name: {},\n    load: (): {} | undefined => {},\n  }},\n",
+                crate::snippet::string_literal(&loader.description(host)),
+                type_name,
+                loader.load(host)
+            )
+        })
+        .collect::<String>();
+    code.push_str(&format!(
+        r#"const moduleLoaders: ReadonlyArray<{{
+  readonly name: string;
+  readonly load: () => {type_name} | undefined;
+}}> = [
+{loader_entries}];
 
-fn undefined_expr() -> swc_ecma_ast::Expr {
-    swc_ecma_ast::Expr::Ident(swc_ecma_ast::Ident::new(
-        string_cache::Atom::from("undefined"),
-        swc_common::Span::default(),
-    ))
-}
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */
+function loadModule(failures: string[]): {type_name} | undefined {{
+  for (const loader of moduleLoaders) {{
+    try {{
+      const api = loader.load();
+      if (api !== undefined) {{
+        return api;
+      }}
+      failures.push(`${{loader.name}}: not available`);
+    }} catch (error) {{
+      failures.push(`${{loader.name}}: ${{error instanceof Error ? error.message : String(error)}}`);
+    }}
+  }}
+  return undefined;
+}}
+
+/**
+ * import {module_name} API
+ *
+ * ```ts
+ * {docs}
+ * ```
+ *
+ * Returns {type_name} only within the host which provides {module_name}.
+ */
+export function {import_function}(): {type_name} | undefined {{
+  return loadModule([]);
+}}
 
-fn declare_const_require() -> swc_ecma_ast::Stmt {
-    swc_ecma_ast::Stmt::Decl(swc_ecma_ast::Decl::Var(Box::new(swc_ecma_ast::VarDecl {
-        span: swc_common::Span::default(),
-        declare: true,
-        kind: swc_ecma_ast::VarDeclKind::Const,
-        decls: vec![swc_ecma_ast::VarDeclarator {
-            span: swc_common::Span::default(),
-            definite: true,
-            name: swc_ecma_ast::Pat::Ident(swc_ecma_ast::BindingIdent {
-                id: swc_ecma_ast::Ident::new(
-                    string_cache::Atom::from("require"),
-                    swc_common::Span::default(),
-                ),
-                type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
-                    span: swc_common::Span::default(),
-                    type_ann: Box::new(require_function_type()),
-                })),
-            }),
-            init: None,
-        }],
-    })))
+/**
+ * Like {{@link {import_function}}}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */
+export function {import_function}OrThrow(): {type_name} {{
+  const failures: string[] = [];
+  const api = loadModule(failures);
+  if (api === undefined) {{
+    throw new Error(
+      `Could not import {module_name}, which is only available within the host. Tried:\n${{
+        failures.map((failure) => `- ${{failure}}`).join("\n")
+      }}`,
+    );
+  }}
+  return api;
+}}
+"#,
+        module_name = host.module_name,
+        docs = loaders
+            .iter()
+            .map(|loader| loader.description(host))
+            .collect::<Vec<_>>()
+            .join("\n * "),
+    ));
+    crate::snippet::module_items(&code, comments)
 }
@@ -0,0 +1,540 @@
+/// Global types and namespaces of the TypeScript standard library, lib.es5.d.ts up to
+/// lib.es2023.d.ts, which the generated module may refer to
+const LIB_TYPE_NAMES: &[&str] = &[
+    // lib.es5.d.ts
+    "Array",
+    "ArrayBuffer",
+    "ArrayBufferLike",
+    "ArrayBufferTypes",
+    "ArrayBufferView",
+    "ArrayLike",
+    "Awaited",
+    "Boolean",
+    "CallableFunction",
+    "Capitalize",
+    "ClassDecorator",
+    "ConcatArray",
+    "ConstructorParameters",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "Exclude",
+    "Extract",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "IArguments",
+    "InstanceType",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Intl",
+    "JSON",
+    "Lowercase",
+    "Math",
+    "MethodDecorator",
+    "NewableFunction",
+    "NoInfer",
+    "NonNullable",
+    "Number",
+    "Object",
+    "Omit",
+    "OmitThisParameter",
+    "ParameterDecorator",
+    "Parameters",
+    "Partial",
+    "Pick",
+    "Promise",
+    "PromiseLike",
+    "PropertyDecorator",
+    "PropertyDescriptor",
+    "PropertyDescriptorMap",
+    "PropertyKey",
+    "RangeError",
+    "Readonly",
+    "ReadonlyArray",
+    "Record",
+    "ReferenceError",
+    "RegExp",
+    "RegExpExecArray",
+    "RegExpMatchArray",
+    "Required",
+    "ReturnType",
+    "String",
+    "SyntaxError",
+    "TemplateStringsArray",
+    "ThisParameterType",
+    "ThisType",
+    "TypeError",
+    "TypedPropertyDescriptor",
+    "URIError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "Uncapitalize",
+    "Uppercase",
+    // lib.es2015.*.d.ts
+    "Generator",
+    "GeneratorFunction",
+    "Iterable",
+    "IterableIterator",
+    "Iterator",
+    "IteratorResult",
+    "IteratorReturnResult",
+    "IteratorYieldResult",
+    "Map",
+    "Proxy",
+    "ProxyHandler",
+    "ReadonlyMap",
+    "ReadonlySet",
+    "Reflect",
+    "Set",
+    "Symbol",
+    "WeakMap",
+    "WeakSet",
+    // lib.es2017.*.d.ts
+    "Atomics",
+    "SharedArrayBuffer",
+    // lib.es2018.*.d.ts
+    "AsyncGenerator",
+    "AsyncGeneratorFunction",
+    "AsyncIterable",
+    "AsyncIterableIterator",
+    "AsyncIterator",
+    // lib.es2020.*.d.ts
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "PromiseFulfilledResult",
+    "PromiseRejectedResult",
+    "PromiseSettledResult",
+    // lib.es2021.*.d.ts
+    "AggregateError",
+    "FinalizationRegistry",
+    "WeakRef",
+    // lib.es2023.*.d.ts
+    "WeakKey",
+    "WeakKeyTypes",
+];
+
+/// Check that every type reference, `typeof` query and `extends` clause
+/// in the generated module points at a declaration of the module,
+/// a type parameter in scope or a type of the standard library.
+/// Every segment of a qualified name like `ns.Name` has to be a member of the one before it.
+pub fn check(
+    module_block: &swc_ecma_ast::TsModuleBlock,
+    type_name: &str,
+) -> Result<(), crate::Error> {
+    let mut resolver = Resolver {
+        type_name: swc_atoms::JsWord::from(type_name),
+        declared: declared_names(&module_block.body),
+        namespaces: vec![],
+        type_params: vec![],
+        path: vec![],
+        dangling: vec![],
+    };
+    swc_ecma_visit::VisitWith::visit_with(module_block, &mut resolver);
+
+    if resolver.dangling.is_empty() {
+        return Ok(());
+    }
+    Err(crate::Error::DanglingTypeReferences(
+        resolver
+            .dangling
+            .iter()
+            .map(|(name, referrers)| format!("{} (referenced by {})", name, referrers.join(", ")))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ))
+}
+
+/// What a declared name gives to the segments after it in a qualified name
+#[derive(Default)]
+enum Declared {
+    /// A type or value without members in type space
+    #[default]
+    Leaf,
+    Namespace(std::collections::HashMap<swc_atoms::JsWord, Declared>),
+    Enum(Vec<swc_atoms::JsWord>),
+}
+
+impl Declared {
+    /// Declarations of the same name merge, like an interface and a namespace
+    fn merge(&mut self, other: Declared) {
+        match (self, other) {
+            (Declared::Namespace(members), Declared::Namespace(other_members)) => {
+                for (name, declared) in other_members {
+                    members.entry(name).or_default().merge(declared);
+                }
+            }
+            (Declared::Enum(members), Declared::Enum(other_members)) => {
+                members.extend(other_members)
+            }
+            (this @ Declared::Leaf, other) => *this = other,
+            (_, _) => {}
+        }
+    }
+
+    fn member(&self, name: &swc_atoms::JsWord) -> Option<&Declared> {
+        static LEAF: Declared = Declared::Leaf;
+        match self {
+            Declared::Leaf => None,
+            Declared::Namespace(members) => members.get(name),
+            Declared::Enum(members) => members.contains(name).then_some(&LEAF),
+        }
+    }
+}
+
+fn declared_names(
+    module_items: &[swc_ecma_ast::ModuleItem],
+) -> std::collections::HashMap<swc_atoms::JsWord, Declared> {
+    let mut declared = std::collections::HashMap::<swc_atoms::JsWord, Declared>::new();
+    for (name, names) in module_items.iter().flat_map(module_item_declared_names) {
+        declared.entry(name).or_default().merge(names);
+    }
+    declared
+}
+
+fn module_item_declared_names(
+    module_item: &swc_ecma_ast::ModuleItem,
+) -> Vec<(swc_atoms::JsWord, Declared)> {
+    let decl = match module_item {
+        swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(export_decl)) => {
+            &export_decl.decl
+        }
+        swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(decl)) => decl,
+        _ => return vec![],
+    };
+    match decl {
+        swc_ecma_ast::Decl::Class(class) => vec![(class.ident.sym.clone(), Declared::Leaf)],
+        swc_ecma_ast::Decl::Fn(fn_decl) => vec![(fn_decl.ident.sym.clone(), Declared::Leaf)],
+        swc_ecma_ast::Decl::Var(var_decl) => var_decl
+            .decls
+            .iter()
+            .filter_map(|decl| match &decl.name {
+                swc_ecma_ast::Pat::Ident(ident) => Some((ident.id.sym.clone(), Declared::Leaf)),
+                _ => None,
+            })
+            .collect(),
+        swc_ecma_ast::Decl::TsInterface(interface) => {
+            vec![(interface.id.sym.clone(), Declared::Leaf)]
+        }
+        swc_ecma_ast::Decl::TsTypeAlias(alias) => vec![(alias.id.sym.clone(), Declared::Leaf)],
+        swc_ecma_ast::Decl::TsEnum(enum_decl) => vec![(
+            enum_decl.id.sym.clone(),
+            Declared::Enum(
+                enum_decl
+                    .members
+                    .iter()
+                    .map(|member| match &member.id {
+                        swc_ecma_ast::TsEnumMemberId::Ident(ident) => ident.sym.clone(),
+                        swc_ecma_ast::TsEnumMemberId::Str(str) => str.value.clone(),
+                    })
+                    .collect(),
+            ),
+        )],
+        swc_ecma_ast::Decl::TsModule(module) => match &module.id {
+            swc_ecma_ast::TsModuleName::Ident(ident) => vec![(
+                ident.sym.clone(),
+                module
+                    .body
+                    .as_ref()
+                    .map(namespace_body_declared)
+                    .unwrap_or_else(|| Declared::Namespace(Default::default())),
+            )],
+            swc_ecma_ast::TsModuleName::Str(_) => vec![],
+        },
+        swc_ecma_ast::Decl::Using(_) => vec![],
+    }
+}
+
+fn namespace_body_declared(body: &swc_ecma_ast::TsNamespaceBody) -> Declared {
+    match body {
+        swc_ecma_ast::TsNamespaceBody::TsModuleBlock(block) => {
+            Declared::Namespace(declared_names(&block.body))
+        }
+        // `namespace a.b { }`
+        swc_ecma_ast::TsNamespaceBody::TsNamespaceDecl(namespace) => Declared::Namespace(
+            [(
+                namespace.id.sym.clone(),
+                namespace_body_declared(&namespace.body),
+            )]
+            .into_iter()
+            .collect(),
+        ),
+    }
+}
+
+struct Resolver {
+    /// Root type like `VSCodeAPI`
+    type_name: swc_atoms::JsWord,
+    declared: std::collections::HashMap<swc_atoms::JsWord, Declared>,
+    /// names of the enclosing namespaces, innermost last
+    namespaces: Vec<swc_atoms::JsWord>,
+    /// type parameters of the enclosing declarations, innermost last
+    type_params: Vec<Vec<swc_atoms::JsWord>>,
+    path: Vec<String>,
+    /// dangling name and the paths of the declarations referencing it
+    dangling: Vec<(swc_atoms::JsWord, Vec<String>)>,
+}
+
+impl Resolver {
+    fn enter(&mut self, segment: String, f: impl FnOnce(&mut Self)) {
+        self.path.push(segment);
+        f(self);
+        self.path.pop();
+    }
+
+    fn with_type_params(
+        &mut self,
+        type_params: &Option<Box<swc_ecma_ast::TsTypeParamDecl>>,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.type_params.push(match type_params {
+            Some(type_params) => type_params
+                .params
+                .iter()
+                .map(|param| param.name.sym.clone())
+                .collect(),
+            None => vec![],
+        });
+        f(self);
+        self.type_params.pop();
+    }
+
+    /// The declaration of `name` seen from the innermost enclosing namespace
+    fn lookup(&self, name: &swc_atoms::JsWord) -> Option<&Declared> {
+        (0..=self.namespaces.len()).rev().find_map(|depth| {
+            let Some((first, rest)) = self.namespaces[..depth].split_first() else {
+                return self.declared.get(name);
+            };
+            rest.iter()
+                .try_fold(self.declared.get(first)?, |scope, namespace| {
+                    scope.member(namespace)
+                })?
+                .member(name)
+        })
+    }
+
+    /// Resolve a name like `ns.Name` segment by segment
+    fn resolve(&mut self, segments: &[&swc_atoms::JsWord]) {
+        let Some((first, rest)) = segments.split_first() else {
+            return;
+        };
+        if LIB_TYPE_NAMES.contains(&&***first)
+            || self.type_params.iter().any(|scope| scope.contains(first))
+        {
+            return;
+        }
+        let mut declared = self.lookup(first);
+        for segment in rest {
+            declared = declared.and_then(|declared| declared.member(segment));
+        }
+        if declared.is_some() {
+            return;
+        }
+        let name = swc_atoms::JsWord::from(
+            segments
+                .iter()
+                .map(|segment| segment.to_string())
+                .collect::<Vec<_>>()
+                .join("."),
+        );
+        let referrer = if self.path.is_empty() {
+            "(module)".to_string()
+        } else {
+            self.path.join(".")
+        };
+        match self.dangling.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, referrers)) => {
+                if !referrers.contains(&referrer) {
+                    referrers.push(referrer)
+                }
+            }
+            None => self.dangling.push((name, vec![referrer])),
+        }
+    }
+}
+
+/// `ns.Name` as `["ns", "Name"]`
+fn entity_name_segments(name: &swc_ecma_ast::TsEntityName) -> Vec<&swc_atoms::JsWord> {
+    match name {
+        swc_ecma_ast::TsEntityName::Ident(ident) => vec![&ident.sym],
+        swc_ecma_ast::TsEntityName::TsQualifiedName(qualified) => {
+            let mut segments = entity_name_segments(&qualified.left);
+            segments.push(&qualified.right.sym);
+            segments
+        }
+    }
+}
+
+/// `ns.Base` in an `extends` clause as `["ns", "Base"]`, `None` for other expressions
+fn expr_segments(expr: &swc_ecma_ast::Expr) -> Option<Vec<&swc_atoms::JsWord>> {
+    match expr {
+        swc_ecma_ast::Expr::Ident(ident) => Some(vec![&ident.sym]),
+        swc_ecma_ast::Expr::Member(member) => {
+            let swc_ecma_ast::MemberProp::Ident(prop) = &member.prop else {
+                return None;
+            };
+            let mut segments = expr_segments(&member.obj)?;
+            segments.push(&prop.sym);
+            Some(segments)
+        }
+        _ => None,
+    }
+}
+
+impl swc_ecma_visit::Visit for Resolver {
+    fn visit_ts_type_alias_decl(&mut self, decl: &swc_ecma_ast::TsTypeAliasDecl) {
+        // members of the root type are addressed as `window.showInformationMessage`
+        if decl.id.sym == self.type_name {
+            swc_ecma_visit::VisitWith::visit_children_with(decl, self);
+            return;
+        }
+        self.enter(decl.id.sym.to_string(), |v| {
+            v.with_type_params(&decl.type_params, |v| {
+                swc_ecma_visit::VisitWith::visit_children_with(decl, v)
+            })
+        });
+    }
+
+    fn visit_ts_interface_decl(&mut self, decl: &swc_ecma_ast::TsInterfaceDecl) {
+        self.enter(decl.id.sym.to_string(), |v| {
+            v.with_type_params(&decl.type_params, |v| {
+                swc_ecma_visit::VisitWith::visit_children_with(decl, v)
+            })
+        });
+    }
+
+    fn visit_fn_decl(&mut self, decl: &swc_ecma_ast::FnDecl) {
+        self.enter(decl.ident.sym.to_string(), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(decl, v)
+        });
+    }
+
+    fn visit_function(&mut self, function: &swc_ecma_ast::Function) {
+        self.with_type_params(&function.type_params, |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(function, v)
+        });
+    }
+
+    fn visit_ts_property_signature(&mut self, signature: &swc_ecma_ast::TsPropertySignature) {
+        self.enter(crate::decl_path::key_to_segment(&signature.key), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(signature, v)
+        });
+    }
+
+    fn visit_ts_method_signature(&mut self, signature: &swc_ecma_ast::TsMethodSignature) {
+        self.enter(crate::decl_path::key_to_segment(&signature.key), |v| {
+            v.with_type_params(&signature.type_params, |v| {
+                swc_ecma_visit::VisitWith::visit_children_with(signature, v)
+            })
+        });
+    }
+
+    fn visit_ts_call_signature_decl(&mut self, signature: &swc_ecma_ast::TsCallSignatureDecl) {
+        self.with_type_params(&signature.type_params, |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(signature, v)
+        });
+    }
+
+    fn visit_ts_construct_signature_decl(
+        &mut self,
+        signature: &swc_ecma_ast::TsConstructSignatureDecl,
+    ) {
+        self.with_type_params(&signature.type_params, |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(signature, v)
+        });
+    }
+
+    fn visit_ts_fn_type(&mut self, fn_type: &swc_ecma_ast::TsFnType) {
+        self.with_type_params(&fn_type.type_params, |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(fn_type, v)
+        });
+    }
+
+    fn visit_ts_constructor_type(&mut self, constructor_type: &swc_ecma_ast::TsConstructorType) {
+        self.with_type_params(&constructor_type.type_params, |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(constructor_type, v)
+        });
+    }
+
+    fn visit_ts_mapped_type(&mut self, mapped_type: &swc_ecma_ast::TsMappedType) {
+        self.type_params
+            .push(vec![mapped_type.type_param.name.sym.clone()]);
+        swc_ecma_visit::VisitWith::visit_children_with(mapped_type, self);
+        self.type_params.pop();
+    }
+
+    fn visit_ts_conditional_type(&mut self, conditional_type: &swc_ecma_ast::TsConditionalType) {
+        swc_ecma_visit::Visit::visit_ts_type(self, &conditional_type.check_type);
+        // `infer U` declares `U` for the rest of the extends type and the true branch only
+        let mut infer_collector = InferCollector { names: vec![] };
+        swc_ecma_visit::VisitWith::visit_with(
+            &*conditional_type.extends_type,
+            &mut infer_collector,
+        );
+        self.type_params.push(infer_collector.names);
+        swc_ecma_visit::Visit::visit_ts_type(self, &conditional_type.extends_type);
+        swc_ecma_visit::Visit::visit_ts_type(self, &conditional_type.true_type);
+        self.type_params.pop();
+        swc_ecma_visit::Visit::visit_ts_type(self, &conditional_type.false_type);
+    }
+
+    fn visit_ts_module_decl(&mut self, decl: &swc_ecma_ast::TsModuleDecl) {
+        let swc_ecma_ast::TsModuleName::Ident(ident) = &decl.id else {
+            return;
+        };
+        self.namespaces.push(ident.sym.clone());
+        self.enter(ident.sym.to_string(), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(&decl.body, v)
+        });
+        self.namespaces.pop();
+    }
+
+    fn visit_ts_entity_name(&mut self, name: &swc_ecma_ast::TsEntityName) {
+        self.resolve(&entity_name_segments(name));
+    }
+
+    /// `typeof api.window` reads properties of a value, which only the first segment declares
+    fn visit_ts_type_query(&mut self, query: &swc_ecma_ast::TsTypeQuery) {
+        match &query.expr_name {
+            swc_ecma_ast::TsTypeQueryExpr::TsEntityName(name) => {
+                self.resolve(&entity_name_segments(name)[..1])
+            }
+            swc_ecma_ast::TsTypeQueryExpr::Import(_) => {}
+        }
+        swc_ecma_visit::VisitWith::visit_children_with(&query.type_args, self);
+    }
+
+    fn visit_ts_expr_with_type_args(&mut self, expr: &swc_ecma_ast::TsExprWithTypeArgs) {
+        match expr_segments(&expr.expr) {
+            Some(segments) => self.resolve(&segments),
+            None => swc_ecma_visit::VisitWith::visit_with(&*expr.expr, self),
+        }
+        swc_ecma_visit::VisitWith::visit_children_with(&expr.type_args, self);
+    }
+
+    fn visit_class(&mut self, class: &swc_ecma_ast::Class) {
+        if let Some(segments) = class.super_class.as_deref().and_then(expr_segments) {
+            self.resolve(&segments);
+        }
+        self.with_type_params(&class.type_params, |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(&class.super_type_params, v);
+            swc_ecma_visit::VisitWith::visit_children_with(&class.implements, v);
+            swc_ecma_visit::VisitWith::visit_children_with(&class.body, v);
+        });
+    }
+}
+
+struct InferCollector {
+    names: Vec<swc_atoms::JsWord>,
+}
+
+impl swc_ecma_visit::Visit for InferCollector {
+    fn visit_ts_infer_type(&mut self, infer_type: &swc_ecma_ast::TsInferType) {
+        self.names.push(infer_type.type_param.name.sym.clone());
+    }
+}
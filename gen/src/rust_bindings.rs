@@ -0,0 +1,873 @@
+//! Rust bindings of the host module for wasm-bindgen, emitted from the `model::Model`
+//! of the same declarations as the TypeScript module.
+//!
+//! ```rust,ignore
+//! #[wasm_bindgen(module = "vscode")]
+//! extern "C" {
+//!     #[derive(Debug, Clone, PartialEq)]
+//!     pub type Disposable;
+//!     #[wasm_bindgen(constructor)]
+//!     pub fn new(call_on_dispose: &js_sys::Function) -> Disposable;
+//! }
+//!
+//! pub mod window {
+//!     #[wasm_bindgen(module = "vscode")]
+//!     extern "C" {
+//!         #[wasm_bindgen(js_namespace = window, js_name = showInformationMessage)]
+//!         pub fn show_information_message(message: &str) -> js_sys::Promise;
+//!     }
+//! }
+//! ```
+//!
+//! Namespaces are Rust modules. Their variables are static getters of `Namespace`,
+//! so they are read on every call. Overloads get `_2`, `_3`, ... after the first,
+//! and the types wasm-bindgen can not tell apart are `JsValue`.
+//! Thenables are taken as `js_sys::Promise`, which they are at runtime.
+//! The golden output is compiled against wasm-bindgen and js-sys by tests/rust_bindings,
+//! with `cargo test -- --ignored`.
+
+/// Source of the Rust module
+pub fn module(model: &crate::model::Model, host: &crate::HostModule) -> String {
+    let mut types = std::collections::HashMap::new();
+    collect(&model.declarations, &[], &mut types);
+    let emitter = Emitter { host, types };
+    let mut out = String::new();
+    for line in host.doc_header.lines() {
+        out.push_str(format!("//! {}", line).trim_end());
+        out.push('\n');
+    }
+    out.push_str(&format!(
+        "//!\n//! Bindings of the `{}` module for wasm-bindgen\n\n",
+        host.module_name
+    ));
+    out.push_str("use wasm_bindgen::prelude::*;\n");
+    emitter.namespace(&mut out, &[], &model.declarations);
+    out
+}
+
+/// What a declared name is, for the types of parameters and results
+enum Kind {
+    /// A class or an interface, bound as an extern type
+    Type,
+    /// An interface with call signatures like `Event<T>`
+    Callable,
+    /// An enum with numeric or string members
+    Enum {
+        is_string: bool,
+    },
+    Alias(crate::model::Type),
+}
+
+/// Types by name, with the path of the Rust module they are in
+type Types = std::collections::HashMap<String, (Vec<String>, Kind)>;
+
+fn collect(declarations: &[crate::model::Declaration], namespace: &[String], types: &mut Types) {
+    for declaration in declarations {
+        let (name, kind) = match declaration {
+            crate::model::Declaration::Namespace {
+                name, declarations, ..
+            } => {
+                let mut inner = namespace.to_vec();
+                inner.push(name.clone());
+                collect(declarations, &inner, types);
+                continue;
+            }
+            crate::model::Declaration::Class { name, .. } => (name, Kind::Type),
+            crate::model::Declaration::Interface { name, members, .. } => (
+                name,
+                if members
+                    .iter()
+                    .any(|member| matches!(member, crate::model::Member::CallSignature { .. }))
+                {
+                    Kind::Callable
+                } else {
+                    Kind::Type
+                },
+            ),
+            crate::model::Declaration::Enum { name, members, .. } => (
+                name,
+                Kind::Enum {
+                    is_string: members.iter().any(|member| member.value.is_string()),
+                },
+            ),
+            crate::model::Declaration::TypeAlias { name, type_, .. } => {
+                (name, Kind::Alias(type_.clone()))
+            }
+            crate::model::Declaration::Function { .. }
+            | crate::model::Declaration::Variable { .. } => continue,
+        };
+        types.entry(name.clone()).or_insert_with(|| {
+            (
+                namespace.iter().map(|name| snake_case(name)).collect(),
+                kind,
+            )
+        });
+    }
+}
+
+/// A Rust type of a parameter or a result
+#[derive(Clone, PartialEq)]
+enum RustType {
+    String,
+    Number,
+    Bool,
+    Unit,
+    Value,
+    /// Path of an extern type like `TextEditor` or `js_sys::Promise`
+    Path(String),
+}
+
+struct Emitter<'a> {
+    host: &'a crate::HostModule,
+    types: Types,
+}
+
+impl Emitter<'_> {
+    /// The items of `declarations` in the module of `namespace`
+    fn namespace(
+        &self,
+        out: &mut String,
+        namespace: &[String],
+        declarations: &[crate::model::Declaration],
+    ) {
+        let js_namespace = js_namespace(namespace);
+        let mut imports = Vec::<String>::new();
+        let mut interfaces = Vec::<String>::new();
+        let mut rest = Vec::<String>::new();
+
+        let has_variables = declarations
+            .iter()
+            .any(|declaration| matches!(declaration, crate::model::Declaration::Variable { .. }));
+        if has_variables && !namespace.is_empty() {
+            let (name, parents) = namespace.split_last().unwrap();
+            let mut attrs = vec![format!("js_name = {}", name)];
+            if !parents.is_empty() {
+                attrs.push(format!("js_namespace = {}", js_namespace_of(parents)));
+            }
+            imports.push(format!(
+                "/// The namespace object of `{}`\n#[derive(Debug, Clone, PartialEq)]\n#[wasm_bindgen({})]\npub type Namespace;",
+                namespace.join("."),
+                attrs.join(", ")
+            ));
+        }
+
+        let mut function_names = Vec::<String>::new();
+        for declaration in declarations {
+            match declaration {
+                crate::model::Declaration::Namespace {
+                    name,
+                    doc,
+                    declarations,
+                } => {
+                    let mut inner = namespace.to_vec();
+                    inner.push(name.clone());
+                    let mut module = String::new();
+                    self.namespace(&mut module, &inner, declarations);
+                    rest.push(format!(
+                        "{}pub mod {} {{\n    #[allow(unused_imports)]\n    use super::*;\n{}}}",
+                        doc_comment(doc.as_ref()),
+                        rust_ident(&snake_case(name)),
+                        indent(&module)
+                    ));
+                }
+                crate::model::Declaration::Class {
+                    name,
+                    doc,
+                    extends,
+                    members,
+                    ..
+                } => {
+                    let mut attrs = Vec::<String>::new();
+                    if let Some(extends) =
+                        extends.as_ref().and_then(|extends| self.extends(extends))
+                    {
+                        attrs.push(format!("extends = {}", extends));
+                    }
+                    if let Some(js_namespace) = &js_namespace {
+                        attrs.push(format!("js_namespace = {}", js_namespace));
+                    }
+                    let mut items = vec![type_item(name, doc.as_ref(), &attrs)];
+                    items.extend(self.members(name, members, true));
+                    imports.push(items.join("\n"));
+                }
+                crate::model::Declaration::Interface {
+                    name,
+                    doc,
+                    extends,
+                    members,
+                    ..
+                } => {
+                    // these are passed as the `js_sys` types
+                    if JS_SYS_TYPES.iter().any(|(ts, _)| ts == name)
+                        || matches!(self.types.get(name.as_str()), Some((_, Kind::Callable)))
+                    {
+                        continue;
+                    }
+                    let attrs = extends
+                        .iter()
+                        .filter_map(|extends| self.extends(extends))
+                        .map(|extends| format!("extends = {}", extends))
+                        .collect::<Vec<_>>();
+                    let mut items = vec![type_item(name, doc.as_ref(), &attrs)];
+                    items.extend(self.members(name, members, false));
+                    interfaces.push(items.join("\n"));
+                }
+                crate::model::Declaration::Enum { name, doc, members } => {
+                    rest.push(enum_item(name, doc.as_ref(), members));
+                }
+                crate::model::Declaration::Function {
+                    name,
+                    doc,
+                    params,
+                    return_type,
+                    ..
+                } => {
+                    let rust_name = overload_name(&mut function_names, &snake_case(name));
+                    let mut attrs = Vec::<String>::new();
+                    if let Some(js_namespace) = &js_namespace {
+                        attrs.push(format!("js_namespace = {}", js_namespace));
+                    }
+                    imports.push(self.function(
+                        &rust_name,
+                        name,
+                        doc.as_ref(),
+                        attrs,
+                        None,
+                        params,
+                        return_type.as_ref(),
+                    ));
+                }
+                crate::model::Declaration::Variable {
+                    name, doc, type_, ..
+                } => {
+                    let rust_type = self.rust_type_of(type_.as_ref());
+                    if namespace.is_empty() {
+                        // the module itself can not be imported, so it is read once
+                        imports.push(format!(
+                            "{}#[wasm_bindgen(thread_local_v2, js_name = {})]\npub static {}: {};",
+                            doc_comment(doc.as_ref()),
+                            name,
+                            screaming_snake_case(name),
+                            static_type(&rust_type)
+                        ));
+                    } else {
+                        imports.push(format!(
+                            "{}#[wasm_bindgen(static_method_of = Namespace, getter, js_name = {})]\npub fn {}(){};",
+                            doc_comment(doc.as_ref()),
+                            name,
+                            rust_ident(&snake_case(name)),
+                            return_type(&rust_type)
+                        ));
+                    }
+                }
+                // aliases are resolved where they are used
+                crate::model::Declaration::TypeAlias { .. } => {}
+            }
+        }
+
+        let mut blocks = Vec::<String>::new();
+        if !imports.is_empty() {
+            blocks.push(format!(
+                "#[wasm_bindgen(module = {})]\nextern \"C\" {{\n{}}}",
+                crate::snippet::string_literal(&self.host.module_name),
+                indent(&imports.join("\n\n"))
+            ));
+        }
+        if !interfaces.is_empty() {
+            blocks.push(format!(
+                "#[wasm_bindgen]\nextern \"C\" {{\n{}}}",
+                indent(&interfaces.join("\n\n"))
+            ));
+        }
+        blocks.extend(rest);
+        for block in blocks {
+            out.push('\n');
+            out.push_str(&block);
+            out.push('\n');
+        }
+    }
+
+    /// Constructors, methods and properties of the class or interface `type_name`
+    fn members(
+        &self,
+        type_name: &str,
+        members: &[crate::model::Member],
+        is_class: bool,
+    ) -> Vec<String> {
+        let mut names = Vec::<String>::new();
+        let mut items = Vec::<String>::new();
+        for member in members {
+            match member {
+                crate::model::Member::Constructor { doc, params } if is_class => {
+                    let rust_name = overload_name(&mut names, "new");
+                    items.push(self.function(
+                        &rust_name,
+                        type_name,
+                        doc.as_ref(),
+                        vec!["constructor".to_string()],
+                        None,
+                        params,
+                        Some(&crate::model::Type::Reference {
+                            name: type_name.to_string(),
+                            type_args: vec![],
+                        }),
+                    ));
+                }
+                crate::model::Member::Method {
+                    name,
+                    doc,
+                    is_static,
+                    params,
+                    return_type,
+                    ..
+                } => {
+                    let rust_name = overload_name(&mut names, &snake_case(name));
+                    let (attrs, this) = if *is_static {
+                        (vec![format!("static_method_of = {}", type_name)], None)
+                    } else {
+                        (vec!["method".to_string()], Some(type_name))
+                    };
+                    items.push(self.function(
+                        &rust_name,
+                        name,
+                        doc.as_ref(),
+                        attrs,
+                        this,
+                        params,
+                        return_type.as_ref(),
+                    ));
+                }
+                crate::model::Member::Property {
+                    name,
+                    doc,
+                    is_static,
+                    is_optional,
+                    is_readonly,
+                    type_,
+                } => {
+                    let mut rust_type = self.rust_type_of(type_.as_ref());
+                    if *is_optional {
+                        rust_type.1 = true;
+                    }
+                    items.push(self.accessor(
+                        &mut names,
+                        type_name,
+                        name,
+                        doc.as_ref(),
+                        *is_static,
+                        Accessor::Getter(&rust_type),
+                    ));
+                    if !is_readonly {
+                        items.push(self.accessor(
+                            &mut names,
+                            type_name,
+                            name,
+                            None,
+                            *is_static,
+                            Accessor::Setter(&rust_type),
+                        ));
+                    }
+                }
+                crate::model::Member::Getter {
+                    name,
+                    doc,
+                    is_static,
+                    type_,
+                } => {
+                    let rust_type = self.rust_type_of(type_.as_ref());
+                    items.push(self.accessor(
+                        &mut names,
+                        type_name,
+                        name,
+                        doc.as_ref(),
+                        *is_static,
+                        Accessor::Getter(&rust_type),
+                    ));
+                }
+                crate::model::Member::Setter {
+                    name,
+                    doc,
+                    is_static,
+                    param,
+                } => {
+                    let rust_type = self.rust_type_of(param.type_.as_ref());
+                    items.push(self.accessor(
+                        &mut names,
+                        type_name,
+                        name,
+                        doc.as_ref(),
+                        *is_static,
+                        Accessor::Setter(&rust_type),
+                    ));
+                }
+                // call, construct and index signatures have no binding
+                _ => {}
+            }
+        }
+        items
+    }
+
+    fn accessor(
+        &self,
+        names: &mut Vec<String>,
+        type_name: &str,
+        name: &str,
+        doc: Option<&crate::model::Doc>,
+        is_static: bool,
+        accessor: Accessor,
+    ) -> String {
+        let (kind, rust_name, params, result) = match accessor {
+            Accessor::Getter(rust_type) => (
+                "getter",
+                overload_name(names, &snake_case(name)),
+                vec![],
+                return_type(rust_type),
+            ),
+            Accessor::Setter(rust_type) => (
+                "setter",
+                overload_name(names, &format!("set_{}", snake_case(name))),
+                vec![format!("value: {}", param_type(rust_type))],
+                String::new(),
+            ),
+        };
+        let (target, params) = if is_static {
+            (format!("static_method_of = {}", type_name), params)
+        } else {
+            let mut with_this = vec![format!("this: &{}", type_name)];
+            with_this.extend(params);
+            ("method".to_string(), with_this)
+        };
+        format!(
+            "{}#[wasm_bindgen({}, {}, js_name = {})]\npub fn {}({}){};",
+            doc_comment(doc),
+            target,
+            kind,
+            js_name(name),
+            rust_ident(&rust_name),
+            params.join(", "),
+            result
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn function(
+        &self,
+        rust_name: &str,
+        js: &str,
+        doc: Option<&crate::model::Doc>,
+        mut attrs: Vec<String>,
+        this: Option<&str>,
+        params: &[crate::model::Param],
+        result: Option<&crate::model::Type>,
+    ) -> String {
+        let mut rust_params = this
+            .map(|this| vec![format!("this: &{}", this)])
+            .unwrap_or_default();
+        let mut param_names = Vec::<String>::new();
+        for (index, param) in params.iter().enumerate() {
+            let name = overload_name(
+                &mut param_names,
+                &if param.name.is_empty() || param.name.starts_with(['{', '[']) {
+                    format!("arg{}", index)
+                } else {
+                    snake_case(&param.name)
+                },
+            );
+            if param.is_rest {
+                attrs.insert(0, "variadic".to_string());
+                rust_params.push(format!("{}: &js_sys::Array", rust_ident(&name)));
+                continue;
+            }
+            let mut rust_type = self.rust_type_of(param.type_.as_ref());
+            if param.is_optional {
+                rust_type.1 = true;
+            }
+            rust_params.push(format!("{}: {}", rust_ident(&name), param_type(&rust_type)));
+        }
+        if !attrs.iter().any(|attr| attr == "constructor") {
+            attrs.push(format!("js_name = {}", js_name(js)));
+        }
+        format!(
+            "{}#[wasm_bindgen({})]\npub fn {}({}){};",
+            doc_comment(doc),
+            attrs.join(", "),
+            rust_ident(rust_name),
+            rust_params.join(", "),
+            return_type(&self.rust_type_of(result))
+        )
+    }
+
+    /// The path of a class or interface to put in `extends`
+    fn extends(&self, extends: &crate::model::Type) -> Option<String> {
+        match extends {
+            crate::model::Type::Reference { name, .. } => match self.types.get(name.as_str()) {
+                Some((path, Kind::Type)) => Some(type_path(path, name)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// `None` is `void`
+    fn rust_type_of(&self, ts_type: Option<&crate::model::Type>) -> (RustType, bool) {
+        match ts_type {
+            Some(ts_type) => self.rust_type(ts_type, 0),
+            None => (RustType::Value, false),
+        }
+    }
+
+    /// The Rust type, and whether it can be `undefined`
+    fn rust_type(&self, ts_type: &crate::model::Type, depth: usize) -> (RustType, bool) {
+        // aliases referring to each other
+        if depth > 16 {
+            return (RustType::Value, false);
+        }
+        let rust_type = match ts_type {
+            crate::model::Type::Keyword { name } => match name.as_str() {
+                "string" => RustType::String,
+                "number" => RustType::Number,
+                "boolean" => RustType::Bool,
+                "void" | "undefined" => RustType::Unit,
+                _ => RustType::Value,
+            },
+            crate::model::Type::Literal { value } => match value {
+                serde_json::Value::String(_) => RustType::String,
+                serde_json::Value::Number(_) => RustType::Number,
+                serde_json::Value::Bool(_) => RustType::Bool,
+                _ => RustType::Value,
+            },
+            crate::model::Type::Reference { name, .. } => {
+                match JS_SYS_TYPES.iter().find(|(ts, _)| ts == name) {
+                    Some((_, rust)) => path(rust),
+                    None => match self.types.get(name.as_str()) {
+                        Some((module, Kind::Type)) => RustType::Path(type_path(module, name)),
+                        Some((_, Kind::Callable)) => path("js_sys::Function"),
+                        Some((_, Kind::Enum { is_string })) => {
+                            if *is_string {
+                                RustType::String
+                            } else {
+                                RustType::Number
+                            }
+                        }
+                        Some((_, Kind::Alias(target))) => return self.rust_type(target, depth + 1),
+                        // type parameters and globals
+                        None => RustType::Value,
+                    },
+                }
+            }
+            crate::model::Type::Array { .. } | crate::model::Type::Tuple { .. } => {
+                path("js_sys::Array")
+            }
+            crate::model::Type::Function { .. } => path("js_sys::Function"),
+            crate::model::Type::Object { .. } => path("js_sys::Object"),
+            crate::model::Type::Operator { operator, type_ } if operator == "readonly" => {
+                return self.rust_type(type_, depth + 1);
+            }
+            crate::model::Type::Union { types } => {
+                let mut optional = false;
+                let mut rust_types = Vec::<RustType>::new();
+                for ts_type in types {
+                    if matches!(ts_type, crate::model::Type::Keyword { name } if name == "undefined" || name == "null")
+                    {
+                        optional = true;
+                        continue;
+                    }
+                    let (rust_type, is_optional) = self.rust_type(ts_type, depth + 1);
+                    optional |= is_optional;
+                    if !rust_types.contains(&rust_type) {
+                        rust_types.push(rust_type);
+                    }
+                }
+                return match rust_types.as_slice() {
+                    [rust_type] => (rust_type.clone(), optional),
+                    _ => (RustType::Value, false),
+                };
+            }
+            _ => RustType::Value,
+        };
+        (rust_type, false)
+    }
+}
+
+/// Global types of TypeScript and their `js_sys` types
+const JS_SYS_TYPES: &[(&str, &str)] = &[
+    ("Thenable", "js_sys::Promise"),
+    ("Promise", "js_sys::Promise"),
+    ("PromiseLike", "js_sys::Promise"),
+    ("Array", "js_sys::Array"),
+    ("ReadonlyArray", "js_sys::Array"),
+    ("Uint8Array", "js_sys::Uint8Array"),
+    ("Function", "js_sys::Function"),
+    ("Error", "js_sys::Error"),
+    ("RegExp", "js_sys::RegExp"),
+    ("Date", "js_sys::Date"),
+    ("Map", "js_sys::Map"),
+    ("ReadonlyMap", "js_sys::Map"),
+    ("Set", "js_sys::Set"),
+    ("ReadonlySet", "js_sys::Set"),
+];
+
+enum Accessor<'a> {
+    Getter(&'a (RustType, bool)),
+    Setter(&'a (RustType, bool)),
+}
+
+fn path(path: &str) -> RustType {
+    RustType::Path(path.to_string())
+}
+
+fn param_type((rust_type, optional): &(RustType, bool)) -> String {
+    match (rust_type, optional) {
+        (RustType::String, false) => "&str".to_string(),
+        (RustType::String, true) => "Option<String>".to_string(),
+        (RustType::Number, false) => "f64".to_string(),
+        (RustType::Number, true) => "Option<f64>".to_string(),
+        (RustType::Bool, false) => "bool".to_string(),
+        (RustType::Bool, true) => "Option<bool>".to_string(),
+        (RustType::Unit | RustType::Value, _) => "&JsValue".to_string(),
+        (RustType::Path(path), false) => format!("&{}", path),
+        (RustType::Path(path), true) => format!("Option<&{}>", path),
+    }
+}
+
+/// ` -> T`, or nothing for `void`
+fn return_type((rust_type, optional): &(RustType, bool)) -> String {
+    let rust_type = match (rust_type, optional) {
+        (RustType::Unit, _) => return String::new(),
+        (RustType::String, false) => "String".to_string(),
+        (RustType::String, true) => "Option<String>".to_string(),
+        (RustType::Number, false) => "f64".to_string(),
+        (RustType::Number, true) => "Option<f64>".to_string(),
+        (RustType::Bool, false) => "bool".to_string(),
+        (RustType::Bool, true) => "Option<bool>".to_string(),
+        (RustType::Value, _) => "JsValue".to_string(),
+        (RustType::Path(path), false) => path.clone(),
+        (RustType::Path(path), true) => format!("Option<{}>", path),
+    };
+    format!(" -> {}", rust_type)
+}
+
+/// Statics hold JavaScript values only
+fn static_type((rust_type, optional): &(RustType, bool)) -> String {
+    match (rust_type, optional) {
+        (RustType::String, false) => "js_sys::JsString".to_string(),
+        (RustType::Number, false) => "f64".to_string(),
+        (RustType::Bool, false) => "bool".to_string(),
+        (RustType::Path(path), false) => path.clone(),
+        _ => "JsValue".to_string(),
+    }
+}
+
+fn type_item(name: &str, doc: Option<&crate::model::Doc>, attrs: &[String]) -> String {
+    format!(
+        "{}#[derive(Debug, Clone, PartialEq)]\n{}pub type {};",
+        doc_comment(doc),
+        if attrs.is_empty() {
+            String::new()
+        } else {
+            format!("#[wasm_bindgen({})]\n", attrs.join(", "))
+        },
+        name
+    )
+}
+
+/// Enums are structs with a constant per member,
+/// as wasm-bindgen passes their values as numbers and strings
+fn enum_item(
+    name: &str,
+    doc: Option<&crate::model::Doc>,
+    members: &[crate::model::EnumMember],
+) -> String {
+    let mut next = 0f64;
+    let consts = members
+        .iter()
+        .map(|member| {
+            let (rust_type, value) = match &member.value {
+                serde_json::Value::String(value) => ("&str", crate::snippet::string_literal(value)),
+                value => {
+                    let value = value.as_f64().unwrap_or(next);
+                    next = value + 1.0;
+                    ("f64", format!("{:?}", value))
+                }
+            };
+            format!(
+                "{}pub const {}: {} = {};",
+                doc_comment(member.doc.as_ref()),
+                screaming_snake_case(&member.name),
+                rust_type,
+                value
+            )
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "{}#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub struct {};\n\nimpl {} {{\n{}}}",
+        doc_comment(doc),
+        name,
+        name,
+        indent(&consts.join("\n"))
+    )
+}
+
+/// `window` or `["env", "clipboard"]`
+fn js_namespace(namespace: &[String]) -> Option<String> {
+    (!namespace.is_empty()).then(|| js_namespace_of(namespace))
+}
+
+fn js_namespace_of(namespace: &[String]) -> String {
+    match namespace {
+        [name] => name.clone(),
+        names => format!(
+            "[{}]",
+            names
+                .iter()
+                .map(|name| crate::snippet::string_literal(name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// JavaScript names which are not Rust identifiers are given as strings
+fn js_name(name: &str) -> String {
+    if rust_ident(name) == name {
+        name.to_string()
+    } else {
+        crate::snippet::string_literal(name)
+    }
+}
+
+fn type_path(module: &[String], name: &str) -> String {
+    module
+        .iter()
+        .map(|segment| rust_ident(segment))
+        .chain(std::iter::once(name.to_string()))
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// `name`, then `name_2`, `name_3`, ... for the overloads
+fn overload_name(names: &mut Vec<String>, name: &str) -> String {
+    let mut candidate = name.to_string();
+    let mut index = 1;
+    while names.contains(&candidate) {
+        index += 1;
+        candidate = format!("{}_{}", name, index);
+    }
+    names.push(candidate.clone());
+    candidate
+}
+
+/// `showInformationMessage` -> `show_information_message`, `getURI` -> `get_uri`
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut out = String::new();
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let previous = index.checked_sub(1).map(|index| chars[index]);
+            let next = chars.get(index + 1);
+            let starts_word = previous
+                .is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+                || (previous.is_some_and(|p| p.is_ascii_uppercase())
+                    && next.is_some_and(|n| n.is_ascii_lowercase()));
+            if starts_word && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() || *c == '_' {
+            out.push(*c);
+        } else {
+            out.push('_');
+        }
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) || out.is_empty() {
+        out.insert(0, '_');
+    }
+    out
+}
+
+fn screaming_snake_case(name: &str) -> String {
+    snake_case(name).to_ascii_uppercase()
+}
+
+/// Keywords become raw identifiers, or get `_` if they can not be raw
+fn rust_ident(name: &str) -> String {
+    match name {
+        "self" | "Self" | "super" | "crate" | "_" => format!("{}_", name),
+        "as" | "async" | "await" | "break" | "const" | "continue" | "dyn" | "else" | "enum"
+        | "extern" | "false" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match"
+        | "mod" | "move" | "mut" | "pub" | "ref" | "return" | "static" | "struct" | "trait"
+        | "true" | "type" | "unsafe" | "use" | "where" | "while" | "abstract" | "become"
+        | "box" | "do" | "final" | "gen" | "macro" | "override" | "priv" | "try" | "typeof"
+        | "unsized" | "virtual" | "yield" => format!("r#{}", name),
+        name => name.to_string(),
+    }
+}
+
+/// `///` lines of the description and the tags, and `#[deprecated]`.
+/// Code blocks without a language are `text`, so they are not doctests
+fn doc_comment(doc: Option<&crate::model::Doc>) -> String {
+    let Some(doc) = doc else {
+        return String::new();
+    };
+    let mut lines = doc
+        .description
+        .lines()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>();
+    if !doc.tags.is_empty() && lines.last().is_some_and(|line| !line.is_empty()) {
+        lines.push(String::new());
+    }
+    let mut deprecated = None;
+    for tag in &doc.tags {
+        if tag.tag == "deprecated" {
+            deprecated = Some(tag.text.split_whitespace().collect::<Vec<_>>().join(" "));
+        }
+        let head = match &tag.name {
+            Some(name) => format!("@{} {}", tag.tag, name),
+            None => format!("@{}", tag.tag),
+        };
+        let mut text = tag.text.lines();
+        lines.push(format!("{} {}", head, text.next().unwrap_or_default()));
+        lines.extend(text.map(|line| line.to_string()));
+    }
+    let mut in_code = false;
+    let mut out = String::new();
+    for line in lines {
+        let line = if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            if in_code && line.trim() == "```" {
+                line.replace("```", "```text")
+            } else {
+                line
+            }
+        } else {
+            line
+        };
+        out.push_str(format!("/// {}", line).trim_end());
+        out.push('\n');
+    }
+    if let Some(note) = deprecated {
+        if note.is_empty() {
+            out.push_str("#[deprecated]\n");
+        } else {
+            out.push_str(&format!(
+                "#[deprecated(note = {})]\n",
+                crate::snippet::string_literal(&note)
+            ));
+        }
+    }
+    out
+}
+
+/// Indent the lines by 4 spaces
+fn indent(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("    {}\n", line)
+            }
+        })
+        .collect()
+}
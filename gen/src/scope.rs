@@ -0,0 +1,162 @@
+//! Names which functions, blocks and declarations of an extension source bring into scope,
+//! so that a local name is not taken for an import of the same name
+
+pub fn type_param_names(
+    type_params: &Option<Box<swc_ecma_ast::TsTypeParamDecl>>,
+) -> std::collections::HashSet<String> {
+    type_params
+        .iter()
+        .flat_map(|type_params| &type_params.params)
+        .map(|param| param.name.sym.to_string())
+        .collect()
+}
+
+/// Names a pattern like `{ window, commands: [first] }` binds
+pub fn binding_names(pat: &swc_ecma_ast::Pat) -> std::collections::HashSet<String> {
+    struct BindingNames(std::collections::HashSet<String>);
+
+    impl swc_ecma_visit::Visit for BindingNames {
+        fn visit_binding_ident(&mut self, ident: &swc_ecma_ast::BindingIdent) {
+            self.0.insert(ident.id.sym.to_string());
+        }
+
+        // `{ window = fallback }`
+        fn visit_assign_pat_prop(&mut self, prop: &swc_ecma_ast::AssignPatProp) {
+            self.0.insert(prop.key.sym.to_string());
+        }
+
+        // default values are expressions, not bindings
+        fn visit_expr(&mut self, _: &swc_ecma_ast::Expr) {}
+    }
+
+    let mut names = BindingNames(std::collections::HashSet::new());
+    swc_ecma_visit::VisitWith::visit_with(pat, &mut names);
+    names.0
+}
+
+/// Type parameters, parameters and `var`s of a function
+pub fn function_names(function: &swc_ecma_ast::Function) -> std::collections::HashSet<String> {
+    let mut names = type_param_names(&function.type_params);
+    for param in &function.params {
+        names.extend(binding_names(&param.pat));
+    }
+    if let Some(body) = &function.body {
+        names.extend(var_names(body));
+    }
+    names
+}
+
+pub fn arrow_names(arrow: &swc_ecma_ast::ArrowExpr) -> std::collections::HashSet<String> {
+    let mut names = type_param_names(&arrow.type_params);
+    for param in &arrow.params {
+        names.extend(binding_names(param));
+    }
+    if let swc_ecma_ast::BlockStmtOrExpr::BlockStmt(body) = &*arrow.body {
+        names.extend(var_names(body));
+    }
+    names
+}
+
+pub fn constructor_names(
+    constructor: &swc_ecma_ast::Constructor,
+) -> std::collections::HashSet<String> {
+    let mut names = std::collections::HashSet::new();
+    for param in &constructor.params {
+        match param {
+            swc_ecma_ast::ParamOrTsParamProp::Param(param) => {
+                names.extend(binding_names(&param.pat))
+            }
+            swc_ecma_ast::ParamOrTsParamProp::TsParamProp(prop) => match &prop.param {
+                swc_ecma_ast::TsParamPropParam::Ident(ident) => {
+                    names.insert(ident.id.sym.to_string());
+                }
+                swc_ecma_ast::TsParamPropParam::Assign(assign) => {
+                    names.extend(binding_names(&assign.left))
+                }
+            },
+        }
+    }
+    if let Some(body) = &constructor.body {
+        names.extend(var_names(body));
+    }
+    names
+}
+
+pub fn catch_names(clause: &swc_ecma_ast::CatchClause) -> std::collections::HashSet<String> {
+    clause.param.as_ref().map(binding_names).unwrap_or_default()
+}
+
+pub fn for_names(stmt: &swc_ecma_ast::ForStmt) -> std::collections::HashSet<String> {
+    match &stmt.init {
+        Some(swc_ecma_ast::VarDeclOrExpr::VarDecl(var_decl)) => var_decl_names(var_decl),
+        _ => Default::default(),
+    }
+}
+
+pub fn for_head_names(head: &swc_ecma_ast::ForHead) -> std::collections::HashSet<String> {
+    match head {
+        swc_ecma_ast::ForHead::VarDecl(var_decl) => var_decl_names(var_decl),
+        _ => Default::default(),
+    }
+}
+
+pub fn var_decl_names(var_decl: &swc_ecma_ast::VarDecl) -> std::collections::HashSet<String> {
+    var_decl
+        .decls
+        .iter()
+        .flat_map(|declarator| binding_names(&declarator.name))
+        .collect()
+}
+
+/// Names `let`, `const`, functions, classes and types declare directly in `stmts`
+pub fn lexical_names(stmts: &[swc_ecma_ast::Stmt]) -> std::collections::HashSet<String> {
+    let mut names = std::collections::HashSet::new();
+    for stmt in stmts {
+        let swc_ecma_ast::Stmt::Decl(decl) = stmt else {
+            continue;
+        };
+        match decl {
+            swc_ecma_ast::Decl::Var(var_decl) => names.extend(var_decl_names(var_decl)),
+            swc_ecma_ast::Decl::Fn(fn_decl) => {
+                names.insert(fn_decl.ident.sym.to_string());
+            }
+            swc_ecma_ast::Decl::Class(class_decl) => {
+                names.insert(class_decl.ident.sym.to_string());
+            }
+            swc_ecma_ast::Decl::TsInterface(interface) => {
+                names.insert(interface.id.sym.to_string());
+            }
+            swc_ecma_ast::Decl::TsTypeAlias(alias) => {
+                names.insert(alias.id.sym.to_string());
+            }
+            swc_ecma_ast::Decl::TsEnum(enum_decl) => {
+                names.insert(enum_decl.id.sym.to_string());
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+/// Names `var` declares anywhere in a function body, which are scoped to the function
+fn var_names(body: &swc_ecma_ast::BlockStmt) -> std::collections::HashSet<String> {
+    struct VarNames(std::collections::HashSet<String>);
+
+    impl swc_ecma_visit::Visit for VarNames {
+        fn visit_var_decl(&mut self, var_decl: &swc_ecma_ast::VarDecl) {
+            if var_decl.kind == swc_ecma_ast::VarDeclKind::Var {
+                self.0.extend(var_decl_names(var_decl));
+            }
+        }
+
+        fn visit_function(&mut self, _: &swc_ecma_ast::Function) {}
+
+        fn visit_arrow_expr(&mut self, _: &swc_ecma_ast::ArrowExpr) {}
+
+        fn visit_class(&mut self, _: &swc_ecma_ast::Class) {}
+    }
+
+    let mut names = VarNames(std::collections::HashSet::new());
+    swc_ecma_visit::VisitWith::visit_with(body, &mut names);
+    names.0
+}
@@ -0,0 +1,216 @@
+//! The first version in which each declaration and member of the extension API appeared,
+//! computed from historical index.d.ts files
+
+/// Version of the JSON written for `History`
+pub const VERSION: u32 = 1;
+
+/// Availability of the declarations and members over the versions of index.d.ts.
+///
+/// As a `Transform`, appends `@since <version>` to the doc comment of every declaration
+/// and member which appeared after the oldest version and after its enclosing declaration.
+#[derive(serde::Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct History {
+    pub version: u32,
+    /// The versions of the history, oldest first
+    pub versions: Vec<String>,
+    /// The first version of every path (like `window.showQuickPick`)
+    /// which is not in the oldest version
+    pub since: std::collections::BTreeMap<String, String>,
+}
+
+impl History {
+    /// Build from the sources of index.d.ts keyed by version like `1.76.0`, in any order.
+    ///
+    /// Versions are shown as `major.minor`, the granularity of `engines.vscode` in practice.
+    /// The paths are of the module of `host`.
+    pub fn from_sources(
+        sources: &[(String, String)],
+        host: &crate::HostModule,
+    ) -> Result<Self, crate::Error> {
+        let mut sources = sources
+            .iter()
+            .map(|(version, source)| Ok((parse_version(version)?, source)))
+            .collect::<Result<Vec<_>, crate::Error>>()?;
+        sources.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut versions = Vec::<String>::new();
+        let mut seen = std::collections::HashSet::<String>::new();
+        let mut since = std::collections::BTreeMap::<String, String>::new();
+        for (index, (version, source)) in sources.iter().enumerate() {
+            let label = version_label(version);
+            for path in crate::diff::paths(source, host)? {
+                if seen.insert(path.clone()) && index != 0 {
+                    since.insert(path, label.clone());
+                }
+            }
+            versions.push(label);
+        }
+        Ok(History {
+            version: VERSION,
+            versions,
+            since,
+        })
+    }
+
+    /// The first version which has `path`, `None` for the oldest version or a path not in the history
+    pub fn since(&self, path: &str) -> Option<&str> {
+        self.since.get(path).map(|version| version.as_str())
+    }
+}
+
+/// `1.76.0` as `[1, 76, 0]`
+pub fn parse_version(version: &str) -> Result<Vec<u32>, crate::Error> {
+    version
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| crate::Error::InvalidVersion(version.to_string()))
+}
+
+fn version_label(version: &[u32]) -> String {
+    version
+        .iter()
+        .take(2)
+        .map(|part| part.to_string())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+impl crate::Transform for History {
+    fn transform(
+        &self,
+        mut decls: Vec<crate::pickup::ResultDeclWithComments>,
+        context: &mut crate::TransformContext,
+    ) -> Result<Vec<crate::pickup::ResultDeclWithComments>, crate::Error> {
+        self.annotate("", None, &mut decls, context.comments);
+        Ok(decls)
+    }
+}
+
+impl History {
+    fn annotate(
+        &self,
+        parent: &str,
+        parent_since: Option<&str>,
+        decls: &mut [crate::pickup::ResultDeclWithComments],
+        comments: &dyn swc_common::comments::Comments,
+    ) {
+        for result in decls {
+            let Some(name) = result.decl.name() else {
+                continue;
+            };
+            let path = crate::decl_path::join(parent, &name);
+            let since = self.since(&path);
+            if let Some(version) = since.filter(|version| Some(*version) != parent_since) {
+                result.comments = Some(with_since(
+                    result.comments.take().unwrap_or_default(),
+                    version,
+                ));
+            }
+            match &mut result.decl {
+                crate::pickup::ResultDecl::SubModule(sub_module) => {
+                    self.annotate(&path, since, &mut sub_module.decl_vec, comments)
+                }
+                crate::pickup::ResultDecl::Class(class) => {
+                    for member in &class.class.body {
+                        if let Some(segment) = crate::decl_path::class_member_segment(member) {
+                            self.annotate_member(
+                                &crate::decl_path::join(&path, &segment),
+                                since,
+                                swc_common::Spanned::span(member).lo,
+                                comments,
+                            );
+                        }
+                    }
+                }
+                crate::pickup::ResultDecl::TsInterface(interface) => {
+                    for element in &interface.body.body {
+                        if let Some(segment) = crate::decl_path::type_element_segment(element) {
+                            self.annotate_member(
+                                &crate::decl_path::join(&path, &segment),
+                                since,
+                                swc_common::Spanned::span(element).lo,
+                                comments,
+                            );
+                        }
+                    }
+                }
+                crate::pickup::ResultDecl::TsEnum(enum_decl) => {
+                    for member in &enum_decl.members {
+                        self.annotate_member(
+                            &crate::decl_path::join(
+                                &path,
+                                &crate::decl_path::enum_member_segment(member),
+                            ),
+                            since,
+                            member.span.lo,
+                            comments,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn annotate_member(
+        &self,
+        path: &str,
+        parent_since: Option<&str>,
+        pos: swc_common::BytePos,
+        comments: &dyn swc_common::comments::Comments,
+    ) {
+        if let Some(version) = self
+            .since(path)
+            .filter(|version| Some(*version) != parent_since)
+        {
+            let comment_vec = comments.take_leading(pos).unwrap_or_default();
+            comments.add_leading_comments(pos, with_since(comment_vec, version));
+        }
+    }
+}
+
+/// Add `@since` to the JSDoc in `comment_vec`, or a JSDoc with only `@since` when there is none
+fn with_since(
+    mut comment_vec: Vec<swc_common::comments::Comment>,
+    version: &str,
+) -> Vec<swc_common::comments::Comment> {
+    let doc = comment_vec.iter_mut().rev().find(|comment| {
+        comment.kind == swc_common::comments::CommentKind::Block && comment.text.starts_with('*')
+    });
+    match doc {
+        Some(doc) => {
+            if doc.text.contains("@since") {
+                return comment_vec;
+            }
+            // continue the ` * ` lines with the same indent
+            let indent = doc
+                .text
+                .lines()
+                .skip(1)
+                .filter_map(|line| {
+                    let trimmed = line.trim_start();
+                    trimmed
+                        .starts_with('*')
+                        .then(|| line[..line.len() - trimmed.len()].to_string())
+                })
+                .last()
+                .unwrap_or_else(|| " ".to_string());
+            doc.text = swc_atoms::Atom::from(format!(
+                "{}\n{}* @since {}\n{}",
+                doc.text.trim_end(),
+                indent,
+                version,
+                indent
+            ));
+        }
+        None => comment_vec.push(swc_common::comments::Comment {
+            span: swc_common::DUMMY_SP,
+            kind: swc_common::comments::CommentKind::Block,
+            text: swc_atoms::Atom::from(format!("* @since {} ", version)),
+        }),
+    }
+    comment_vec
+}
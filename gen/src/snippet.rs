@@ -0,0 +1,74 @@
+/// Start of the positions given to snippets,
+/// far beyond any position in the parsed index.d.ts.
+static NEXT_SNIPPET_POS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(1 << 30);
+
+/// Parse TypeScript code written by the generator itself into module items.
+///
+/// Every snippet gets its own range of positions,
+/// so its comments can live in the same `comments` as the index.d.ts.
+pub fn module_items(
+    code: &str,
+    comments: &dyn swc_common::comments::Comments,
+) -> Result<Vec<swc_ecma_ast::ModuleItem>, crate::Error> {
+    let start =
+        NEXT_SNIPPET_POS.fetch_add(code.len() as u32 + 1, std::sync::atomic::Ordering::Relaxed);
+    let lexer = swc_ecma_parser::lexer::Lexer::new(
+        swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsConfig::default()),
+        swc_ecma_ast::EsVersion::Es2022,
+        swc_ecma_parser::StringInput::new(
+            code,
+            swc_common::source_map::BytePos(start),
+            swc_common::source_map::BytePos(start + code.len() as u32),
+        ),
+        Some(comments),
+    );
+    let mut parser = swc_ecma_parser::Parser::new_from(lexer);
+    let module = parser
+        .parse_typescript_module()
+        .map_err(|_| crate::Error::ParseSnippetError(code.to_string()))?;
+    Ok(module.body)
+}
+
+/// Whether the type is `Event<T>`
+pub fn is_event(type_ann: Option<&swc_ecma_ast::TsTypeAnn>) -> bool {
+    matches!(
+        type_ann.map(|type_ann| &*type_ann.type_ann),
+        Some(swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+            type_name: swc_ecma_ast::TsEntityName::Ident(ident),
+            ..
+        })) if &*ident.sym == "Event"
+    )
+}
+
+/// `"value"` with the escapes of JSON, which TypeScript reads as well
+pub fn string_literal(value: &str) -> String {
+    serde_json::Value::String(value.to_string()).to_string()
+}
+
+/// `["a", "b"]`
+pub fn string_array(values: &[String]) -> String {
+    format!(
+        "[{}]",
+        values
+            .iter()
+            .map(|value| string_literal(value))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// `name` as the key of an object literal property, quoted unless it is an identifier
+pub fn property_key(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        string_literal(name)
+    }
+}
@@ -0,0 +1,344 @@
+//! The module split into a directory, so that Deno checks and caches only the files
+//! which an extension imports
+//!
+//! ```text
+//! mod.ts                 loaders, VSCodeAPI and the re-exports of the others
+//! types/TextEditor.ts    a class, interface, type alias or enum with the ones of the same name
+//! namespaces/window.ts   `export type WindowNamespace`, the type of `VSCodeAPI["window"]`
+//! enums/ViewColumn.ts    `export type ViewColumnEnum`, the type of `VSCodeAPI["ViewColumn"]`
+//! ```
+//!
+//! Every file imports the types it refers to from the others with `import type`.
+//! Only mod.ts refers to `VSCodeAPI`, so importing a type does not bring in the whole API.
+
+/// Split `module_block`, the generated module, into files.
+/// `result_vec` tells which declarations get their own file.
+/// Call this before emitting the module itself, which takes the comments
+pub fn files(
+    module_block: swc_ecma_ast::TsModuleBlock,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    comments: &swc_common::comments::SingleThreadedComments,
+    host: &crate::HostModule,
+) -> Result<Vec<crate::GeneratedFile>, crate::Error> {
+    let comments = crate::copy_comments(comments);
+    let type_names = result_vec
+        .iter()
+        .filter(|result| {
+            matches!(
+                result.decl,
+                crate::pickup::ResultDecl::Class(_)
+                    | crate::pickup::ResultDecl::TsInterface(_)
+                    | crate::pickup::ResultDecl::TsTypeAlias(_)
+                    | crate::pickup::ResultDecl::TsEnum(_)
+            )
+        })
+        .filter_map(|result| result.decl.name())
+        .collect::<Vec<_>>();
+    let namespace_names = result_vec
+        .iter()
+        .filter(|result| matches!(result.decl, crate::pickup::ResultDecl::SubModule(_)))
+        .filter_map(|result| result.decl.name())
+        .collect::<Vec<_>>();
+    let enum_names = result_vec
+        .iter()
+        .filter(|result| matches!(result.decl, crate::pickup::ResultDecl::TsEnum(_)))
+        .filter_map(|result| result.decl.name())
+        .collect::<Vec<_>>();
+
+    let mut files = vec![File::new(MOD)];
+    for mut item in module_block.body {
+        swc_ecma_visit::VisitMutWith::visit_mut_with(
+            &mut item,
+            &mut EnumObjects {
+                type_name: &host.type_name,
+                enum_names: &enum_names,
+            },
+        );
+        match declared_type(&item) {
+            Some((name, is_exported)) if type_names.contains(&name) || &*name == VALUE_OF => {
+                let path = format!("types/{}.ts", name);
+                let index = match files.iter().position(|file| file.path == path) {
+                    Some(index) => index,
+                    None => {
+                        files.push(File::new(&path));
+                        files.len() - 1
+                    }
+                };
+                if is_exported && !files[index].exports.contains(&name) {
+                    files[index].exports.push(name);
+                }
+                files[index].body.push(exported(item));
+            }
+            Some((name, _)) if *name == host.type_name => {
+                let (item, members) = take_members(item, &namespace_names, &enum_names, &comments);
+                files[0].body.push(item);
+                files.extend(members);
+            }
+            _ => files[0].body.push(item),
+        }
+    }
+
+    // every file but mod.ts is re-exported from mod.ts
+    let re_exports = files[1..]
+        .iter()
+        .filter(|file| !file.exports.is_empty())
+        .map(|file| {
+            format!(
+                "export type {{ {} }} from {};",
+                file.exports
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                crate::snippet::string_literal(&specifier(MOD, &file.path))
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let declared_in = files
+        .iter()
+        .flat_map(|file| {
+            file.body
+                .iter()
+                .filter_map(declared_type)
+                .map(|(name, _)| (name, file.path.clone()))
+        })
+        .collect::<std::collections::HashMap<_, _>>();
+
+    files
+        .iter()
+        .map(|file| {
+            let module_block = swc_ecma_ast::TsModuleBlock {
+                span: swc_common::Span::default(),
+                body: file.body.clone(),
+            };
+            let mut imports = std::collections::BTreeMap::<String, Vec<String>>::new();
+            for name in crate::decl_path::referenced_names(&module_block) {
+                match declared_in.get(&name) {
+                    Some(path) if *path != file.path => imports
+                        .entry(specifier(&file.path, path))
+                        .or_default()
+                        .push(name.to_string()),
+                    _ => {}
+                }
+            }
+            let mut header = imports
+                .into_iter()
+                .map(|(specifier, mut names)| {
+                    names.sort();
+                    format!(
+                        "import type {{ {} }} from {};",
+                        names.join(", "),
+                        crate::snippet::string_literal(&specifier)
+                    )
+                })
+                .collect::<Vec<_>>();
+            if file.path == MOD {
+                header.extend(re_exports.iter().cloned());
+            }
+            let code = crate::node_to_code_string(&module_block, &comments)?;
+            Ok(crate::GeneratedFile {
+                path: file.path.clone(),
+                code: if header.is_empty() {
+                    code
+                } else {
+                    format!("{}\n{}", header.join("\n"), code)
+                },
+            })
+        })
+        .collect()
+}
+
+const MOD: &str = "mod.ts";
+
+/// The helper of the enum types, which is not exported from mod.ts
+const VALUE_OF: &str = "ValueOf";
+
+struct File {
+    path: String,
+    /// Names mod.ts re-exports
+    exports: Vec<swc_atoms::JsWord>,
+    body: Vec<swc_ecma_ast::ModuleItem>,
+}
+
+impl File {
+    fn new(path: &str) -> Self {
+        File {
+            path: path.to_string(),
+            exports: vec![],
+            body: vec![],
+        }
+    }
+}
+
+/// The name of a type alias or interface, and whether it is exported
+fn declared_type(item: &swc_ecma_ast::ModuleItem) -> Option<(swc_atoms::JsWord, bool)> {
+    let (decl, is_exported) = match item {
+        swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(export_decl)) => {
+            (&export_decl.decl, true)
+        }
+        swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(decl)) => (decl, false),
+        _ => return None,
+    };
+    match decl {
+        swc_ecma_ast::Decl::TsTypeAlias(alias) => Some((alias.id.sym.clone(), is_exported)),
+        swc_ecma_ast::Decl::TsInterface(interface) => Some((interface.id.sym.clone(), is_exported)),
+        _ => None,
+    }
+}
+
+/// Other files import it, so the declaration is exported from its own file
+fn exported(item: swc_ecma_ast::ModuleItem) -> swc_ecma_ast::ModuleItem {
+    match item {
+        swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(decl)) => {
+            swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
+                swc_ecma_ast::ExportDecl {
+                    span: swc_common::Span::default(),
+                    decl,
+                },
+            ))
+        }
+        item => item,
+    }
+}
+
+/// Move the object types of the namespace and enum properties of the root type
+/// to `namespaces/<name>.ts` and `enums/<name>.ts`, leaving references to them
+fn take_members(
+    mut item: swc_ecma_ast::ModuleItem,
+    namespace_names: &[swc_atoms::JsWord],
+    enum_names: &[swc_atoms::JsWord],
+    comments: &dyn swc_common::comments::Comments,
+) -> (swc_ecma_ast::ModuleItem, Vec<File>) {
+    let mut files = Vec::<File>::new();
+    let swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
+        swc_ecma_ast::ExportDecl {
+            decl: swc_ecma_ast::Decl::TsTypeAlias(alias),
+            ..
+        },
+    )) = &mut item
+    else {
+        return (item, files);
+    };
+    let swc_ecma_ast::TsType::TsTypeLit(type_lit) = &mut *alias.type_ann else {
+        return (item, files);
+    };
+    for member in &mut type_lit.members {
+        let swc_ecma_ast::TsTypeElement::TsPropertySignature(property) = member else {
+            continue;
+        };
+        let swc_ecma_ast::Expr::Ident(key) = &*property.key else {
+            continue;
+        };
+        let (dir, suffix, kind) = if namespace_names.contains(&key.sym) {
+            ("namespaces", "Namespace", "namespace")
+        } else if enum_names.contains(&key.sym) {
+            ("enums", "Enum", "enum")
+        } else {
+            continue;
+        };
+        let Some(type_ann) = &mut property.type_ann else {
+            continue;
+        };
+        if !matches!(&*type_ann.type_ann, swc_ecma_ast::TsType::TsTypeLit(_)) {
+            continue;
+        }
+        let type_ident = swc_ecma_ast::Ident::new(
+            swc_atoms::JsWord::from(member_type_name(&key.sym, suffix)),
+            swc_common::Span::default(),
+        );
+        let type_lit = std::mem::replace(
+            &mut type_ann.type_ann,
+            Box::new(swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+                span: swc_common::Span::default(),
+                type_name: swc_ecma_ast::TsEntityName::Ident(type_ident.clone()),
+                type_params: None,
+            })),
+        );
+        let mut file = File::new(&format!("{}/{}.ts", dir, key.sym));
+        file.exports.push(type_ident.sym.clone());
+        file.body.push(swc_ecma_ast::ModuleItem::ModuleDecl(
+            swc_ecma_ast::ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
+                span: {
+                    let span = swc_common::Span::dummy_with_cmt();
+                    comments.add_leading(
+                        span.lo,
+                        swc_common::comments::Comment {
+                            span: swc_common::DUMMY_SP,
+                            kind: swc_common::comments::CommentKind::Block,
+                            text: swc_atoms::Atom::from(format!(
+                                "*\n * The `{}` {}\n ",
+                                key.sym, kind
+                            )),
+                        },
+                    );
+                    span
+                },
+                decl: swc_ecma_ast::Decl::TsTypeAlias(Box::new(swc_ecma_ast::TsTypeAliasDecl {
+                    span: swc_common::Span::default(),
+                    declare: false,
+                    id: type_ident,
+                    type_params: None,
+                    type_ann: type_lit,
+                })),
+            }),
+        ));
+        files.push(file);
+    }
+    (item, files)
+}
+
+/// `WindowNamespace` of `window`, `ViewColumnEnum` of `ViewColumn`
+fn member_type_name(name: &str, suffix: &str) -> String {
+    format!("{}{}", crate::decl_path::upper_first(name), suffix)
+}
+
+/// Replace `VSCodeAPI["ViewColumn"]` with `ViewColumnEnum`,
+/// so that the enum types do not import mod.ts
+struct EnumObjects<'a> {
+    type_name: &'a str,
+    enum_names: &'a [swc_atoms::JsWord],
+}
+
+impl swc_ecma_visit::VisitMut for EnumObjects<'_> {
+    fn visit_mut_ts_type(&mut self, ty: &mut swc_ecma_ast::TsType) {
+        if let swc_ecma_ast::TsType::TsIndexedAccessType(indexed) = ty {
+            if let (
+                swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+                    type_name: swc_ecma_ast::TsEntityName::Ident(object),
+                    type_params: None,
+                    ..
+                }),
+                swc_ecma_ast::TsType::TsLitType(swc_ecma_ast::TsLitType {
+                    lit: swc_ecma_ast::TsLit::Str(index),
+                    ..
+                }),
+            ) = (&*indexed.obj_type, &*indexed.index_type)
+            {
+                if &*object.sym == self.type_name && self.enum_names.contains(&index.value) {
+                    *ty = swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+                        span: swc_common::Span::default(),
+                        type_name: swc_ecma_ast::TsEntityName::Ident(swc_ecma_ast::Ident::new(
+                            swc_atoms::JsWord::from(member_type_name(&index.value, "Enum")),
+                            swc_common::Span::default(),
+                        )),
+                        type_params: None,
+                    });
+                    return;
+                }
+            }
+        }
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(ty, self);
+    }
+}
+
+/// Relative specifier of `to` from `from`, both of which are at most one directory deep
+fn specifier(from: &str, to: &str) -> String {
+    match from.rsplit_once('/') {
+        None => format!("./{}", to),
+        Some((dir, _)) => match to.strip_prefix(&format!("{}/", dir)) {
+            Some(sibling) => format!("./{}", sibling),
+            None => format!("../{}", to),
+        },
+    }
+}
@@ -0,0 +1,163 @@
+/// Rewrite every method signature into a readonly function-typed property.
+///
+/// TypeScript checks the parameters of method signatures bivariantly even under
+/// `strictFunctionTypes`, so provider callbacks with wrong parameter types compile.
+///
+/// ```ts
+/// with(line?: number): Position;
+/// with(change: { line?: number }): Position;
+/// ```
+///
+/// becomes
+///
+/// ```ts
+/// readonly with: ((line?: number) => Position) & ((change: { line?: number }) => Position);
+/// ```
+pub struct StrictFunctionTypes;
+
+impl swc_ecma_visit::VisitMut for StrictFunctionTypes {
+    fn visit_mut_ts_type_elements(&mut self, elements: &mut Vec<swc_ecma_ast::TsTypeElement>) {
+        swc_ecma_visit::VisitMutWith::visit_mut_children_with(elements, self);
+
+        let mut slots = Vec::<Slot>::new();
+        for element in std::mem::take(elements) {
+            let method = match element {
+                swc_ecma_ast::TsTypeElement::TsMethodSignature(method) if !method.computed => {
+                    method
+                }
+                other => {
+                    slots.push(Slot::Element(other));
+                    continue;
+                }
+            };
+            let Some(name) = key_name(&method.key) else {
+                slots.push(Slot::Element(
+                    swc_ecma_ast::TsTypeElement::TsMethodSignature(method),
+                ));
+                continue;
+            };
+            match slots.iter_mut().find_map(|slot| match slot {
+                Slot::Overloads(n, signatures) if *n == name => Some(signatures),
+                _ => None,
+            }) {
+                Some(signatures) => signatures.push(method),
+                None => slots.push(Slot::Overloads(name, vec![method])),
+            }
+        }
+        *elements = slots
+            .into_iter()
+            .map(|slot| match slot {
+                Slot::Element(element) => element,
+                Slot::Overloads(_, signatures) => {
+                    method_signatures_to_property_signature(signatures)
+                }
+            })
+            .collect();
+    }
+}
+
+enum Slot {
+    Element(swc_ecma_ast::TsTypeElement),
+    Overloads(swc_atoms::JsWord, Vec<swc_ecma_ast::TsMethodSignature>),
+}
+
+fn key_name(key: &swc_ecma_ast::Expr) -> Option<swc_atoms::JsWord> {
+    match key {
+        swc_ecma_ast::Expr::Ident(ident) => Some(ident.sym.clone()),
+        swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str)) => Some(str.value.clone()),
+        _ => None,
+    }
+}
+
+fn method_signatures_to_property_signature(
+    mut signatures: Vec<swc_ecma_ast::TsMethodSignature>,
+) -> swc_ecma_ast::TsTypeElement {
+    let first = signatures.remove(0);
+    if signatures.is_empty() {
+        return swc_ecma_ast::TsTypeElement::TsPropertySignature(
+            swc_ecma_ast::TsPropertySignature {
+                span: first.span,
+                readonly: true,
+                key: first.key.clone(),
+                computed: false,
+                optional: first.optional,
+                init: None,
+                params: vec![],
+                type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
+                    span: swc_common::Span::default(),
+                    type_ann: Box::new(method_signature_to_fn_type(
+                        &first,
+                        swc_common::Span::default(),
+                    )),
+                })),
+                type_params: None,
+            },
+        );
+    }
+    let optional = first.optional || signatures.iter().any(|signature| signature.optional);
+    // each overload keeps its own doc comment in front of its function type,
+    // so the key must not carry the position of the first one
+    let key = Box::new(match *first.key.clone() {
+        swc_ecma_ast::Expr::Ident(ident) => swc_ecma_ast::Expr::Ident(swc_ecma_ast::Ident {
+            span: swc_common::Span::default(),
+            ..ident
+        }),
+        key => key,
+    });
+    let types = std::iter::once(first)
+        .chain(signatures)
+        .map(|signature| {
+            Box::new(swc_ecma_ast::TsType::TsParenthesizedType(
+                swc_ecma_ast::TsParenthesizedType {
+                    span: swc_common::Span::default(),
+                    type_ann: Box::new(method_signature_to_fn_type(&signature, signature.span)),
+                },
+            ))
+        })
+        .collect();
+    swc_ecma_ast::TsTypeElement::TsPropertySignature(swc_ecma_ast::TsPropertySignature {
+        span: swc_common::Span::default(),
+        readonly: true,
+        key,
+        computed: false,
+        optional,
+        init: None,
+        params: vec![],
+        type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
+            span: swc_common::Span::default(),
+            type_ann: Box::new(swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+                swc_ecma_ast::TsUnionOrIntersectionType::TsIntersectionType(
+                    swc_ecma_ast::TsIntersectionType {
+                        span: swc_common::Span::default(),
+                        types,
+                    },
+                ),
+            )),
+        })),
+        type_params: None,
+    })
+}
+
+fn method_signature_to_fn_type(
+    signature: &swc_ecma_ast::TsMethodSignature,
+    span: swc_common::Span,
+) -> swc_ecma_ast::TsType {
+    swc_ecma_ast::TsType::TsFnOrConstructorType(swc_ecma_ast::TsFnOrConstructorType::TsFnType(
+        swc_ecma_ast::TsFnType {
+            span,
+            params: signature.params.clone(),
+            type_params: signature.type_params.clone(),
+            type_ann: signature.type_ann.clone().unwrap_or_else(|| {
+                Box::new(swc_ecma_ast::TsTypeAnn {
+                    span: swc_common::Span::default(),
+                    type_ann: Box::new(swc_ecma_ast::TsType::TsKeywordType(
+                        swc_ecma_ast::TsKeywordType {
+                            span: swc_common::Span::default(),
+                            kind: swc_ecma_ast::TsKeywordTypeKind::TsAnyKeyword,
+                        },
+                    )),
+                })
+            }),
+        },
+    ))
+}
@@ -0,0 +1,109 @@
+/// Remove the declarations and members whose doc comment has a `@deprecated` tag.
+///
+/// Overloaded functions and methods are removed only when every overload is deprecated.
+/// The removal goes through `Filter`, so deprecated types which are still referenced are kept.
+pub struct StripDeprecated;
+
+impl crate::Transform for StripDeprecated {
+    fn transform(
+        &self,
+        decls: Vec<crate::pickup::ResultDeclWithComments>,
+        context: &mut crate::TransformContext,
+    ) -> Result<Vec<crate::pickup::ResultDeclWithComments>, crate::Error> {
+        let mut paths = Vec::<(String, bool)>::new();
+        collect_deprecated(&mut paths, "", &decls, context.comments);
+        let exclude = paths
+            .into_iter()
+            .filter_map(|(path, is_deprecated)| is_deprecated.then_some(path))
+            .collect::<Vec<_>>();
+        if exclude.is_empty() {
+            return Ok(decls);
+        }
+
+        let (decls, diagnostics) = crate::filter::apply(
+            &crate::Filter {
+                include: vec![],
+                exclude: exclude.clone(),
+            },
+            decls,
+        );
+        // the filter keeps the deprecated types which kept declarations reference
+        let mut kept = Vec::<(String, bool)>::new();
+        collect_deprecated(&mut kept, "", &decls, context.comments);
+        context.diagnostics.extend(
+            exclude
+                .into_iter()
+                .filter(|path| !kept.iter().any(|(kept, _)| kept == path))
+                .map(|path| crate::Diagnostic::StrippedDeprecated { path }),
+        );
+        context.diagnostics.extend(diagnostics);
+        Ok(decls)
+    }
+}
+
+/// Record every path under `parent` with whether all of its declarations are deprecated
+fn collect_deprecated(
+    paths: &mut Vec<(String, bool)>,
+    parent: &str,
+    decls: &[crate::pickup::ResultDeclWithComments],
+    comments: &dyn swc_common::comments::Comments,
+) {
+    for result in decls {
+        let Some(name) = result.decl.name() else {
+            continue;
+        };
+        let path = crate::decl_path::join(parent, &name);
+        record(
+            paths,
+            path.clone(),
+            is_deprecated(result.comments.as_deref().unwrap_or_default()),
+        );
+        match &result.decl {
+            crate::pickup::ResultDecl::Class(class) => {
+                for member in &class.class.body {
+                    if let Some(name) = crate::decl_path::class_member_name(member) {
+                        record(
+                            paths,
+                            crate::decl_path::join(&path, &name),
+                            has_deprecated_comment(comments, swc_common::Spanned::span(member).lo),
+                        );
+                    }
+                }
+            }
+            crate::pickup::ResultDecl::TsInterface(interface) => {
+                for element in &interface.body.body {
+                    if let Some(name) = crate::decl_path::type_element_name(element) {
+                        record(
+                            paths,
+                            crate::decl_path::join(&path, &name),
+                            has_deprecated_comment(comments, swc_common::Spanned::span(element).lo),
+                        );
+                    }
+                }
+            }
+            crate::pickup::ResultDecl::SubModule(sub_module) => {
+                collect_deprecated(paths, &path, &sub_module.decl_vec, comments);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn record(paths: &mut Vec<(String, bool)>, path: String, is_deprecated: bool) {
+    match paths.iter_mut().find(|(p, _)| *p == path) {
+        Some((_, all_deprecated)) => *all_deprecated &= is_deprecated,
+        None => paths.push((path, is_deprecated)),
+    }
+}
+
+fn has_deprecated_comment(
+    comments: &dyn swc_common::comments::Comments,
+    pos: swc_common::BytePos,
+) -> bool {
+    is_deprecated(&comments.get_leading(pos).unwrap_or_default())
+}
+
+fn is_deprecated(comments: &[swc_common::comments::Comment]) -> bool {
+    crate::model::parse_doc(comments)
+        .is_some_and(|doc| doc.tags.iter().any(|tag| tag.tag == "deprecated"))
+}
@@ -0,0 +1,561 @@
+/// ```ts
+/// export type VSCodeAPITraceEvent = { kind: "call", path: "window.showInformationMessage", ... } | ...;
+/// export function traceVSCodeAPI(
+///   api: VSCodeAPI,
+///   sink: (event: VSCodeAPITraceEvent) => void,
+/// ): VSCodeAPI { ... }
+/// ```
+///
+/// The functions, events and classes to trace are listed in a table generated from the declarations,
+/// so the traced API is a `VSCodeAPI` itself.
+/// Another table lists the methods and events of the classes and interfaces,
+/// to trace the instances which calls, constructors and variables give.
+/// The names follow `host`, like `traceAzdata` and `AzdataTraceEvent`.
+pub fn module_items(
+    comments: &dyn swc_common::comments::Comments,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    host: &crate::HostModule,
+) -> Result<Vec<swc_ecma_ast::ModuleItem>, crate::Error> {
+    let (instance_shapes, traced_types) = instance_shapes(result_vec);
+    crate::snippet::module_items(
+        &format!(
+            r#"/**
+ * What {{@link trace{type_name}}} saw. `path` is like `window.showInformationMessage`,
+ * or `TextEditor.edit` for a member of an instance
+ */
+export type {type_name}TraceEvent =
+  | {{
+    readonly kind: "call";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly result: unknown;
+  }}
+  | {{
+    readonly kind: "throw";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly error: unknown;
+  }}
+  | {{
+    readonly kind: "construct";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly instance: unknown;
+  }}
+  | {{
+    readonly kind: "subscribe";
+    readonly path: string;
+  }}
+  | {{
+    readonly kind: "settle";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly status: "fulfilled" | "rejected";
+    readonly value: unknown;
+    readonly durationMs: number;
+  }};
+
+/**
+ * A function whose result, or what its Thenable resolves to, is an instance of a traced type
+ */
+type ResultTraceShape = {{ readonly returns: string }} | {{ readonly resolves: string }};
+
+type TraceShape = {{
+  readonly [name: string]:
+    | "function"
+    | "event"
+    | ResultTraceShape
+    | {{ readonly instance: string }}
+    | {{ readonly staticMethods: readonly string[] }}
+    | TraceShape;
+}};
+
+const moduleTraceShape: TraceShape = {shape};
+
+/**
+ * What to trace on the instances of the classes and interfaces, by their names
+ */
+const instanceTraceShapes: {{ readonly [type: string]: TraceShape }} = {instance_shapes};
+
+type Tracer = {{
+  readonly sink: (event: {type_name}TraceEvent) => void;
+  /**
+   * The traced Proxy of each instance, so that an instance stays one object
+   */
+  readonly instances: WeakMap<object, object>;
+}};
+
+/**
+ * The instances behind the traced Proxies
+ */
+const untracedInstances = new WeakMap<object, object>();
+
+/**
+ * The instance behind a traced Proxy, as the API is given its own instances
+ */
+function untraced(value: unknown): unknown {{
+  return (typeof value === "object" || typeof value === "function") && value !== null
+    ? untracedInstances.get(value) ?? value
+    : value;
+}}
+
+function traceFunction(
+  func: (...args: unknown[]) => unknown,
+  path: string,
+  resultShape: ResultTraceShape | undefined,
+  tracer: Tracer,
+): (...args: unknown[]) => unknown {{
+  return function (this: unknown, ...args: unknown[]): unknown {{
+    let result: unknown;
+    try {{
+      result = func.apply(untraced(this), args.map(untraced));
+    }} catch (error) {{
+      tracer.sink({{ kind: "throw", path, args, error }});
+      throw error;
+    }}
+    tracer.sink({{ kind: "call", path, args, result }});
+    const then = (result as PromiseLike<unknown> | null | undefined)?.then;
+    if (typeof then === "function") {{
+      const start = performance.now();
+      then.call(
+        result,
+        (value: unknown) =>
+          tracer.sink({{
+            kind: "settle",
+            path,
+            args,
+            status: "fulfilled",
+            value,
+            durationMs: performance.now() - start,
+          }}),
+        (reason: unknown) =>
+          tracer.sink({{
+            kind: "settle",
+            path,
+            args,
+            status: "rejected",
+            value: reason,
+            durationMs: performance.now() - start,
+          }}),
+      );
+      if (resultShape !== undefined && "resolves" in resultShape) {{
+        const type = resultShape.resolves;
+        return then.call(result, (value: unknown) => traceInstance(value, type, tracer));
+      }}
+    }}
+    return resultShape !== undefined && "returns" in resultShape
+      ? traceInstance(result, resultShape.returns, tracer)
+      : result;
+  }};
+}}
+
+function traceEvent(
+  event: (...args: unknown[]) => unknown,
+  path: string,
+  tracer: Tracer,
+): (...args: unknown[]) => unknown {{
+  return function (this: unknown, ...args: unknown[]): unknown {{
+    tracer.sink({{ kind: "subscribe", path }});
+    return event.apply(untraced(this), args);
+  }};
+}}
+
+function traceClass(
+  constructor: object,
+  path: string,
+  staticMethods: readonly string[],
+  tracer: Tracer,
+): object {{
+  return new Proxy(constructor, {{
+    construct(target, args, newTarget) {{
+      const instance = Reflect.construct(
+        target as new (...args: unknown[]) => object,
+        args.map(untraced),
+        newTarget,
+      );
+      tracer.sink({{ kind: "construct", path, args, instance }});
+      return traceInstance(instance, path, tracer) as object;
+    }},
+    get(target, name, receiver) {{
+      const value = Reflect.get(target, name, receiver);
+      return typeof name === "string" && staticMethods.includes(name) && typeof value === "function"
+        ? traceFunction(value as (...args: unknown[]) => unknown, `${{path}}.${{name}}`, undefined, tracer)
+        : value;
+    }},
+  }});
+}}
+
+/**
+ * The member `name` of `object` traced by `shape`.
+ * The same traced value is returned while the member is the same
+ */
+function traceMember(
+  object: object,
+  name: string | symbol,
+  path: string,
+  shape: TraceShape,
+  traced: Map<string, {{ readonly value: unknown; readonly traced: unknown }}>,
+  tracer: Tracer,
+): unknown {{
+  const value = Reflect.get(object, name, object);
+  const member = typeof name === "string" && Object.hasOwn(shape, name) ? shape[name] : undefined;
+  if (typeof name !== "string" || member === undefined || value === undefined || value === null) {{
+    return value;
+  }}
+  const cached = traced.get(name);
+  if (cached !== undefined && cached.value === value) {{
+    return cached.traced;
+  }}
+  const memberPath = path === "" ? name : `${{path}}.${{name}}`;
+  const tracedValue = member === "function"
+    ? traceFunction(value as (...args: unknown[]) => unknown, memberPath, undefined, tracer)
+    : member === "event"
+    ? traceEvent(value as (...args: unknown[]) => unknown, memberPath, tracer)
+    : ("returns" in member && typeof member.returns === "string") ||
+        ("resolves" in member && typeof member.resolves === "string")
+    ? traceFunction(
+      value as (...args: unknown[]) => unknown,
+      memberPath,
+      member as ResultTraceShape,
+      tracer,
+    )
+    : "instance" in member && typeof member.instance === "string"
+    ? traceInstance(value, member.instance, tracer)
+    : "staticMethods" in member && Array.isArray(member.staticMethods)
+    ? traceClass(value, memberPath, member.staticMethods, tracer)
+    : traceNamespace(value, memberPath, member as TraceShape, tracer);
+  traced.set(name, {{ value, traced: tracedValue }});
+  return tracedValue;
+}}
+
+/**
+ * The namespace is read when a member is, so variables like `window.activeTextEditor` stay current.
+ * It may be frozen, and a trap of a frozen target has to give its own properties,
+ * so the Proxy is of an empty object with the same prototype.
+ * The keys and descriptors come from the namespace, as configurable as the empty object has none of them,
+ * so that `Object.keys` and spreading see its members
+ */
+function traceNamespace(
+  namespace: object,
+  path: string,
+  shape: TraceShape,
+  tracer: Tracer,
+): object {{
+  const traced = new Map<string, {{ readonly value: unknown; readonly traced: unknown }}>();
+  return new Proxy(Object.create(Object.getPrototypeOf(namespace)), {{
+    get: (_, name) => traceMember(namespace, name, path, shape, traced, tracer),
+    set: (_, name, newValue) => Reflect.set(namespace, name, untraced(newValue), namespace),
+    has: (_, name) => Reflect.has(namespace, name),
+    ownKeys: () => Reflect.ownKeys(namespace),
+    getOwnPropertyDescriptor: (_, name) => {{
+      const descriptor = Reflect.getOwnPropertyDescriptor(namespace, name);
+      if (descriptor === undefined) {{
+        return undefined;
+      }}
+      return "value" in descriptor
+        ? {{ ...descriptor, value: traceMember(namespace, name, path, shape, traced, tracer), configurable: true }}
+        : {{ ...descriptor, configurable: true }};
+    }},
+  }});
+}}
+
+/**
+ * A Proxy of an instance of the class or interface `type`, whose paths start with `type`
+ */
+function traceInstance(value: unknown, type: string, tracer: Tracer): unknown {{
+  if (
+    (typeof value !== "object" && typeof value !== "function") || value === null ||
+    !Object.hasOwn(instanceTraceShapes, type)
+  ) {{
+    return value;
+  }}
+  const cached = tracer.instances.get(value);
+  if (cached !== undefined) {{
+    return cached;
+  }}
+  const proxy = traceNamespace(value, type, instanceTraceShapes[type], tracer);
+  tracer.instances.set(value, proxy);
+  untracedInstances.set(proxy, value);
+  return proxy;
+}}
+
+/**
+ * Wrap the API to pass every function call, constructor invocation and event subscription to `sink`,
+ * with the arguments and the result.
+ * When a call returns a Thenable, a `settle` event follows with how long it took.
+ * The methods and events of the instances which calls, constructors and variables give
+ * are traced too, and the instances are unwrapped when they are passed back to the API.
+ * The instances which the API passes to listeners are not traced.
+ *
+ * ```ts
+ * const vscode = trace{type_name}({import_function}()!, (event) => console.log(event));
+ * ```
+ */
+export function trace{type_name}(
+  api: {type_name},
+  sink: (event: {type_name}TraceEvent) => void,
+): {type_name} {{
+  return traceNamespace(api, "", moduleTraceShape, {{ sink, instances: new WeakMap() }}) as {type_name};
+}}
+"#,
+            shape = shape(result_vec, &traced_types),
+            type_name = host.type_name,
+            import_function = host.import_function,
+        ),
+        comments,
+    )
+}
+
+/// Object literal of what to trace in `result_vec`.
+/// Namespaces without anything to trace are left out
+fn shape(
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    traced_types: &std::collections::HashSet<String>,
+) -> String {
+    let mut names = Vec::<String>::new();
+    let mut properties = Vec::<String>::new();
+    for result in result_vec {
+        let Some(name) = result.decl.name() else {
+            continue;
+        };
+        let name = name.to_string();
+        // every overload of a function is a declaration
+        if names.contains(&name) {
+            continue;
+        }
+        let value = match &result.decl {
+            crate::pickup::ResultDecl::Fn(fn_decl) => {
+                result_shape(fn_decl.function.return_type.as_deref(), traced_types)
+            }
+            crate::pickup::ResultDecl::Var(var_decl) => {
+                match var_decl.decls.first().map(|decl| &decl.name) {
+                    Some(swc_ecma_ast::Pat::Ident(ident)) => {
+                        match variable_shape(ident.type_ann.as_deref(), traced_types) {
+                            Some(value) => value,
+                            None => continue,
+                        }
+                    }
+                    _ => continue,
+                }
+            }
+            crate::pickup::ResultDecl::Class(class) => {
+                let mut static_methods = Vec::<String>::new();
+                for member in &class.class.body {
+                    if let swc_ecma_ast::ClassMember::Method(method) = member {
+                        if method.is_static {
+                            if let Some(name) = crate::decl_path::class_member_name(member) {
+                                if !static_methods.contains(&name.to_string()) {
+                                    static_methods.push(name.to_string());
+                                }
+                            }
+                        }
+                    }
+                }
+                format!(
+                    "{{ staticMethods: {} }}",
+                    crate::snippet::string_array(&static_methods)
+                )
+            }
+            crate::pickup::ResultDecl::SubModule(sub_module) => {
+                let value = shape(&sub_module.decl_vec, traced_types);
+                if value == "{}" {
+                    continue;
+                }
+                value
+            }
+            crate::pickup::ResultDecl::TsEnum(_)
+            | crate::pickup::ResultDecl::TsInterface(_)
+            | crate::pickup::ResultDecl::TsTypeAlias(_) => continue,
+        };
+        properties.push(format!(
+            "{}: {}",
+            crate::snippet::property_key(&name),
+            value
+        ));
+        names.push(name);
+    }
+    if properties.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", properties.join(", "))
+    }
+}
+
+/// Object literal of the shapes of the instances of the top-level classes and interfaces,
+/// and the names of the types which have one.
+/// A type has a shape when it has a method or an event, or a property of a type which has one
+fn instance_shapes(
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+) -> (String, std::collections::HashSet<String>) {
+    let mut types = Vec::<(String, Vec<swc_ecma_ast::TsTypeElement>)>::new();
+    for result in result_vec {
+        let name = match &result.decl {
+            crate::pickup::ResultDecl::Class(class) => class.ident.sym.to_string(),
+            crate::pickup::ResultDecl::TsInterface(interface) => interface.id.sym.to_string(),
+            _ => continue,
+        };
+        if types.iter().any(|(type_name, _)| *type_name == name) {
+            continue;
+        }
+        if let Some(elements) = crate::type_decls::instance_elements(&name, result_vec) {
+            types.push((name, elements));
+        }
+    }
+
+    let mut traced_types = std::collections::HashSet::<String>::new();
+    loop {
+        let count = traced_types.len();
+        for (name, elements) in &types {
+            if !traced_types.contains(name)
+                && elements
+                    .iter()
+                    .any(|element| element_shape(element, &traced_types).is_some())
+            {
+                traced_types.insert(name.clone());
+            }
+        }
+        if traced_types.len() == count {
+            break;
+        }
+    }
+
+    let mut properties = Vec::<String>::new();
+    for (name, elements) in &types {
+        if !traced_types.contains(name) {
+            continue;
+        }
+        let mut member_names = Vec::<String>::new();
+        let mut members = Vec::<String>::new();
+        for element in elements {
+            let Some(member_name) = crate::decl_path::type_element_name(element) else {
+                continue;
+            };
+            let member_name = member_name.to_string();
+            if member_names.contains(&member_name) {
+                continue;
+            }
+            if let Some(value) = element_shape(element, &traced_types) {
+                members.push(format!(
+                    "{}: {}",
+                    crate::snippet::property_key(&member_name),
+                    value
+                ));
+                member_names.push(member_name);
+            }
+        }
+        properties.push(format!(
+            "{}: {{ {} }}",
+            crate::snippet::property_key(name),
+            members.join(", ")
+        ));
+    }
+    let shapes = if properties.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", properties.join(", "))
+    };
+    (shapes, traced_types)
+}
+
+/// What to trace of a member of an instance, `None` for nothing
+fn element_shape(
+    element: &swc_ecma_ast::TsTypeElement,
+    traced_types: &std::collections::HashSet<String>,
+) -> Option<String> {
+    match element {
+        swc_ecma_ast::TsTypeElement::TsMethodSignature(method) => {
+            Some(result_shape(method.type_ann.as_deref(), traced_types))
+        }
+        swc_ecma_ast::TsTypeElement::TsPropertySignature(property) => {
+            variable_shape(property.type_ann.as_deref(), traced_types)
+        }
+        swc_ecma_ast::TsTypeElement::TsGetterSignature(getter) => {
+            variable_shape(getter.type_ann.as_deref(), traced_types)
+        }
+        _ => None,
+    }
+}
+
+/// What to trace of a variable or property typed `type_ann`, `None` for nothing
+fn variable_shape(
+    type_ann: Option<&swc_ecma_ast::TsTypeAnn>,
+    traced_types: &std::collections::HashSet<String>,
+) -> Option<String> {
+    if crate::snippet::is_event(type_ann) {
+        return Some("\"event\"".to_string());
+    }
+    let ty = &*type_ann?.type_ann;
+    if let swc_ecma_ast::TsType::TsFnOrConstructorType(
+        swc_ecma_ast::TsFnOrConstructorType::TsFnType(_),
+    ) = ty
+    {
+        return Some("\"function\"".to_string());
+    }
+    instance_type(ty, traced_types)
+        .map(|name| format!("{{ instance: {} }}", crate::snippet::string_literal(&name)))
+}
+
+/// What to trace of the result of a function returning `return_type`
+fn result_shape(
+    return_type: Option<&swc_ecma_ast::TsTypeAnn>,
+    traced_types: &std::collections::HashSet<String>,
+) -> String {
+    let Some(return_type) = return_type.map(|type_ann| &*type_ann.type_ann) else {
+        return "\"function\"".to_string();
+    };
+    if let Some(name) = instance_type(return_type, traced_types) {
+        return format!("{{ returns: {} }}", crate::snippet::string_literal(&name));
+    }
+    if let swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+        type_name: swc_ecma_ast::TsEntityName::Ident(ident),
+        type_params: Some(type_params),
+        ..
+    }) = return_type
+    {
+        if let ("Thenable" | "Promise", [resolved]) = (&*ident.sym, type_params.params.as_slice()) {
+            if let Some(name) = instance_type(resolved, traced_types) {
+                return format!("{{ resolves: {} }}", crate::snippet::string_literal(&name));
+            }
+        }
+    }
+    "\"function\"".to_string()
+}
+
+/// The traced type which a value of `ty` is an instance of, like `TextEditor` of `TextEditor | undefined`
+fn instance_type(
+    ty: &swc_ecma_ast::TsType,
+    traced_types: &std::collections::HashSet<String>,
+) -> Option<String> {
+    match ty {
+        swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+            type_name: swc_ecma_ast::TsEntityName::Ident(ident),
+            ..
+        }) if traced_types.contains(&*ident.sym) => Some(ident.sym.to_string()),
+        swc_ecma_ast::TsType::TsParenthesizedType(paren) => {
+            instance_type(&paren.type_ann, traced_types)
+        }
+        swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+            swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(union),
+        ) => {
+            let types = union
+                .types
+                .iter()
+                .filter(|ty| {
+                    !matches!(
+                        &***ty,
+                        swc_ecma_ast::TsType::TsKeywordType(swc_ecma_ast::TsKeywordType {
+                            kind: swc_ecma_ast::TsKeywordTypeKind::TsUndefinedKeyword
+                                | swc_ecma_ast::TsKeywordTypeKind::TsNullKeyword,
+                            ..
+                        })
+                    )
+                })
+                .collect::<Vec<_>>();
+            match types.as_slice() {
+                [ty] => instance_type(ty, traced_types),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
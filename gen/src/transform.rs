@@ -0,0 +1,56 @@
+/// A pass over the declarations picked up from index.d.ts,
+/// run by `Generator` before the module is emitted.
+///
+/// Passes run in the order they are registered,
+/// after the built-in passes turned on by the `Generator` options.
+///
+/// ```
+/// /// Rename the `window` namespace to `ui`
+/// struct RenameWindow;
+///
+/// impl gen::Transform for RenameWindow {
+///     fn transform(
+///         &self,
+///         mut decls: Vec<gen::ResultDeclWithComments>,
+///         _context: &mut gen::TransformContext,
+///     ) -> Result<Vec<gen::ResultDeclWithComments>, gen::Error> {
+///         for result in &mut decls {
+///             if let gen::ResultDecl::SubModule(sub_module) = &mut result.decl {
+///                 if let swc_ecma_ast::TsModuleName::Ident(ident) = &mut sub_module.name {
+///                     if &*ident.sym == "window" {
+///                         ident.sym = "ui".into();
+///                     }
+///                 }
+///             }
+///         }
+///         Ok(decls)
+///     }
+/// }
+/// ```
+pub trait Transform {
+    fn transform(
+        &self,
+        decls: Vec<crate::pickup::ResultDeclWithComments>,
+        context: &mut TransformContext,
+    ) -> Result<Vec<crate::pickup::ResultDeclWithComments>, crate::Error>;
+}
+
+pub struct TransformContext<'a> {
+    /// Comments of the parsed index.d.ts, keyed by the start of the commented node.
+    /// The doc comments of members live here,
+    /// while those of declarations are in `ResultDeclWithComments::comments`
+    pub comments: &'a dyn swc_common::comments::Comments,
+    pub diagnostics: Vec<crate::Diagnostic>,
+}
+
+impl Transform for crate::Filter {
+    fn transform(
+        &self,
+        decls: Vec<crate::pickup::ResultDeclWithComments>,
+        context: &mut TransformContext,
+    ) -> Result<Vec<crate::pickup::ResultDeclWithComments>, crate::Error> {
+        let (decls, diagnostics) = crate::filter::apply(self, decls);
+        context.diagnostics.extend(diagnostics);
+        Ok(decls)
+    }
+}
@@ -1,4 +1,4 @@
-const VALUE_OF_IDENT: once_cell::sync::Lazy<swc_ecma_ast::Ident> =
+static VALUE_OF_IDENT: once_cell::sync::Lazy<swc_ecma_ast::Ident> =
     once_cell::sync::Lazy::new(|| {
         swc_ecma_ast::Ident::new(
             string_cache::Atom::from("ValueOf"),
@@ -53,19 +53,19 @@ pub fn value_of_type() -> swc_ecma_ast::ModuleItem {
 pub fn module_item_transform(
     module_item: &crate::pickup::ResultDeclWithComments,
     comments: &dyn swc_common::comments::Comments,
+    host: &crate::HostModule,
 ) -> Option<swc_ecma_ast::ModuleItem> {
     match &module_item.decl {
         crate::pickup::ResultDecl::Class(class) => Some(swc_ecma_ast::ModuleItem::ModuleDecl(
             swc_ecma_ast::ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
                 span: {
                     let span = swc_common::Span::dummy_with_cmt();
-                    match &module_item.comments {
-                        Some(comment_vec) => swc_common::comments::Comments::add_leading_comments(
+                    if let Some(comment_vec) = &module_item.comments {
+                        swc_common::comments::Comments::add_leading_comments(
                             &comments,
                             span.lo,
                             comment_vec.clone(),
-                        ),
-                        None => {}
+                        );
                     }
                     span
                 },
@@ -120,15 +120,12 @@ pub fn module_item_transform(
                 swc_ecma_ast::ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
                     span: {
                         let span = swc_common::Span::dummy_with_cmt();
-                        match &module_item.comments {
-                            Some(comment_vec) => {
-                                swc_common::comments::Comments::add_leading_comments(
-                                    &comments,
-                                    span.lo,
-                                    comment_vec.clone(),
-                                )
-                            }
-                            None => {}
+                        if let Some(comment_vec) = &module_item.comments {
+                            swc_common::comments::Comments::add_leading_comments(
+                                &comments,
+                                span.lo,
+                                comment_vec.clone(),
+                            );
                         }
                         span
                     },
@@ -141,15 +138,12 @@ pub fn module_item_transform(
                 swc_ecma_ast::ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
                     span: {
                         let span = swc_common::Span::dummy_with_cmt();
-                        match &module_item.comments {
-                            Some(comment_vec) => {
-                                swc_common::comments::Comments::add_leading_comments(
-                                    &comments,
-                                    span.lo,
-                                    comment_vec.clone(),
-                                )
-                            }
-                            None => {}
+                        if let Some(comment_vec) = &module_item.comments {
+                            swc_common::comments::Comments::add_leading_comments(
+                                &comments,
+                                span.lo,
+                                comment_vec.clone(),
+                            );
                         }
                         span
                     },
@@ -161,13 +155,12 @@ pub fn module_item_transform(
             swc_ecma_ast::ModuleDecl::ExportDecl(swc_ecma_ast::ExportDecl {
                 span: {
                     let span = swc_common::Span::dummy_with_cmt();
-                    match &module_item.comments {
-                        Some(comment_vec) => swc_common::comments::Comments::add_leading_comments(
+                    if let Some(comment_vec) = &module_item.comments {
+                        swc_common::comments::Comments::add_leading_comments(
                             &comments,
                             span.lo,
                             comment_vec.clone(),
-                        ),
-                        None => {}
+                        );
                     }
                     span
                 },
@@ -189,7 +182,7 @@ pub fn module_item_transform(
                                         swc_ecma_ast::TsTypeRef {
                                             span: swc_common::Span::default(),
                                             type_name: swc_ecma_ast::TsEntityName::Ident(
-                                                (*crate::ident::VS_CODE_API_IDENT).clone(),
+                                                host.type_ident(),
                                             ),
                                             type_params: None,
                                         },
@@ -215,7 +208,8 @@ pub fn module_item_transform(
     }
 }
 
-fn class_member_to_ts_type_element(
+/// The member of the instance type, `None` for static members and constructors
+pub fn class_member_to_ts_type_element(
     class_member: &swc_ecma_ast::ClassMember,
 ) -> Option<swc_ecma_ast::TsTypeElement> {
     match class_member {
@@ -264,3 +258,65 @@ fn class_member_to_ts_type_element(
         _ => None,
     }
 }
+
+/// Members of the instances of the top-level interface or class `name`, then of its bases.
+/// `None` when the module does not declare it, or it has call, construct or index signatures
+pub fn instance_elements(
+    name: &str,
+    root: &[crate::pickup::ResultDeclWithComments],
+) -> Option<Vec<swc_ecma_ast::TsTypeElement>> {
+    instance_elements_of(name, root, &mut vec![])
+}
+
+fn instance_elements_of(
+    name: &str,
+    root: &[crate::pickup::ResultDeclWithComments],
+    visited: &mut Vec<String>,
+) -> Option<Vec<swc_ecma_ast::TsTypeElement>> {
+    if visited.iter().any(|visited| visited == name) {
+        return Some(vec![]);
+    }
+    visited.push(name.to_string());
+    let mut found = false;
+    let mut elements = Vec::<swc_ecma_ast::TsTypeElement>::new();
+    let mut bases = Vec::<&swc_ecma_ast::Expr>::new();
+    for result in root {
+        match &result.decl {
+            crate::pickup::ResultDecl::TsInterface(interface) if &*interface.id.sym == name => {
+                elements.extend(interface.body.body.iter().cloned());
+                bases.extend(interface.extends.iter().map(|base| &*base.expr));
+            }
+            crate::pickup::ResultDecl::Class(class) if &*class.ident.sym == name => {
+                elements.extend(
+                    class
+                        .class
+                        .body
+                        .iter()
+                        .filter_map(class_member_to_ts_type_element),
+                );
+                bases.extend(class.class.super_class.as_deref());
+            }
+            _ => continue,
+        }
+        found = true;
+    }
+    if !found
+        || elements.iter().any(|element| {
+            matches!(
+                element,
+                swc_ecma_ast::TsTypeElement::TsCallSignatureDecl(_)
+                    | swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(_)
+                    | swc_ecma_ast::TsTypeElement::TsIndexSignature(_)
+            )
+        })
+    {
+        return None;
+    }
+    for base in bases {
+        let swc_ecma_ast::Expr::Ident(base_name) = base else {
+            return None;
+        };
+        elements.extend(instance_elements_of(&base_name.sym, root, visited)?);
+    }
+    Some(elements)
+}
@@ -0,0 +1,478 @@
+//! Uses of the extension API in the TypeScript sources of an extension
+//!
+//! Values are followed from `importVsCodeApi()`, `importVsCodeApiOrThrow()` and `importVsCodeApiPromises()`
+//! through variables, destructuring and member access,
+//! like `const vscode = importVsCodeApi()` and `const { window } = vscode`.
+//! The name of the import function is the one of the [`crate::HostModule`].
+//! Types are followed from the imports of the module which `importVsCodeApi` is imported from.
+//! Variables are tracked by name in the scopes of functions and blocks,
+//! so a local variable of the same name hides a binding of the API.
+
+/// A value or type of the extension API used at a position of a source
+#[derive(Debug, Clone, PartialEq)]
+pub struct Use {
+    /// Dotted path like `window.showQuickPick`, as far as it is written
+    pub path: String,
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+}
+
+impl Use {
+    /// `window`, `window.activeTextEditor`, `window.activeTextEditor.document` of `window.activeTextEditor.document`
+    pub fn prefixes(&self) -> impl Iterator<Item = &str> {
+        self.path
+            .match_indices('.')
+            .map(|(index, _)| &self.path[..index])
+            .chain(std::iter::once(self.path.as_str()))
+    }
+}
+
+/// The module specifiers imported for an import function by any of `sources`,
+/// by name or through a namespace import like `api.importVsCodeApi()`
+pub fn module_specifiers(
+    sources: &[(String, String)],
+    host: &crate::HostModule,
+) -> Result<Vec<String>, crate::Error> {
+    let mut specifiers = Vec::<String>::new();
+    for (file, source) in sources {
+        let module = parse(file, source)?;
+        let mut namespaces = NamespacesOfImportFunction {
+            host,
+            names: std::collections::HashSet::new(),
+        };
+        swc_ecma_visit::Visit::visit_module(&mut namespaces, &module);
+        for item in &module.body {
+            let swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::Import(import)) =
+                item
+            else {
+                continue;
+            };
+            let specifier = import.src.value.to_string();
+            let is_api = import.specifiers.iter().any(|specifier| match specifier {
+                swc_ecma_ast::ImportSpecifier::Namespace(namespace) => {
+                    namespaces.names.contains(&*namespace.local.sym)
+                }
+                _ => imported_name(specifier)
+                    .is_some_and(|name| is_import_function_name(host, &name)),
+            });
+            if is_api && !specifiers.contains(&specifier) {
+                specifiers.push(specifier);
+            }
+        }
+    }
+    Ok(specifiers)
+}
+
+/// Names `x` used like `x.importVsCodeApi` or `x.importVsCodeApiOrThrow`
+struct NamespacesOfImportFunction<'a> {
+    host: &'a crate::HostModule,
+    names: std::collections::HashSet<String>,
+}
+
+impl swc_ecma_visit::Visit for NamespacesOfImportFunction<'_> {
+    fn visit_member_expr(&mut self, member: &swc_ecma_ast::MemberExpr) {
+        if let (swc_ecma_ast::Expr::Ident(object), swc_ecma_ast::MemberProp::Ident(prop)) =
+            (&*member.obj, &member.prop)
+        {
+            if is_import_function_name(self.host, &prop.sym) {
+                self.names.insert(object.sym.to_string());
+            }
+        }
+        swc_ecma_visit::VisitWith::visit_children_with(member, self);
+    }
+}
+
+/// Uses of the API in `source`, whose imports of `module_specifiers` give the API
+pub fn uses(
+    file: &str,
+    source: &str,
+    module_specifiers: &[String],
+    host: &crate::HostModule,
+) -> Result<Vec<Use>, crate::Error> {
+    let module = parse(file, source)?;
+    let mut collector = Collector {
+        source,
+        host,
+        module_specifiers,
+        import_functions: std::collections::HashSet::new(),
+        scopes: vec![std::collections::HashMap::new()],
+        uses: vec![],
+    };
+    swc_ecma_visit::Visit::visit_module(&mut collector, &module);
+    Ok(collector.uses)
+}
+
+/// `importVsCodeApi` and its variants which throw or return Promise instead of Thenable
+fn is_import_function_name(host: &crate::HostModule, name: &str) -> bool {
+    name.strip_prefix(host.import_function.as_str())
+        .is_some_and(|suffix| ["", "OrThrow", "Promises"].contains(&suffix))
+}
+
+/// Start of the positions of a parsed source, as `BytePos(0)` is reserved
+pub(crate) const START: u32 = 1;
+
+/// Parse an extension source as TypeScript, or TSX by the extension of `file`
+pub(crate) fn parse(file: &str, source: &str) -> Result<swc_ecma_ast::Module, crate::Error> {
+    let lexer = swc_ecma_parser::lexer::Lexer::new(
+        swc_ecma_parser::Syntax::Typescript(swc_ecma_parser::TsConfig {
+            tsx: file.ends_with(".tsx") || file.ends_with(".jsx"),
+            ..Default::default()
+        }),
+        swc_ecma_ast::EsVersion::EsNext,
+        swc_ecma_parser::StringInput::new(
+            source,
+            swc_common::BytePos(START),
+            swc_common::BytePos(START + source.len() as u32),
+        ),
+        None,
+    );
+    swc_ecma_parser::Parser::new_from(lexer)
+        .parse_module()
+        .map_err(|error| {
+            crate::Error::ParseExtensionSourceError(format!("{}: {:?}", file, error.kind()))
+        })
+}
+
+/// 1-based line and column in characters of `pos` in a source parsed by `parse`
+pub(crate) fn line_column(source: &str, pos: swc_common::BytePos) -> (usize, usize) {
+    let before = &source[..(pos.0 - START) as usize];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+fn imported_name(specifier: &swc_ecma_ast::ImportSpecifier) -> Option<String> {
+    match specifier {
+        swc_ecma_ast::ImportSpecifier::Named(named) => Some(match &named.imported {
+            Some(swc_ecma_ast::ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+            Some(swc_ecma_ast::ModuleExportName::Str(str)) => str.value.to_string(),
+            None => named.local.sym.to_string(),
+        }),
+        _ => None,
+    }
+}
+
+struct Collector<'a> {
+    source: &'a str,
+    host: &'a crate::HostModule,
+    module_specifiers: &'a [String],
+    /// Local names of `importVsCodeApi` and its variants
+    import_functions: std::collections::HashSet<String>,
+    /// Names declared by the module, then by the enclosing functions and blocks, innermost last,
+    /// with the path of the part of the API they are bound to, if any.
+    /// The empty path is the whole API, like the namespace import of the module
+    scopes: Vec<std::collections::HashMap<String, Option<Vec<String>>>>,
+    uses: Vec<Use>,
+}
+
+impl Collector<'_> {
+    /// Path of the API which the variable `name` is bound to
+    fn binding(&self, name: &str) -> Option<Vec<String>> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+            .cloned()
+            .flatten()
+    }
+
+    /// Bind `name` in the innermost scope which declares it, or the module
+    fn set_binding(&mut self, name: String, path: Option<Vec<String>>) {
+        let index = self
+            .scopes
+            .iter()
+            .rposition(|scope| scope.contains_key(&name))
+            .unwrap_or(0);
+        self.scopes[index].insert(name, path);
+    }
+
+    fn with_scope(&mut self, names: std::collections::HashSet<String>, f: impl FnOnce(&mut Self)) {
+        self.scopes
+            .push(names.into_iter().map(|name| (name, None)).collect());
+        f(self);
+        self.scopes.pop();
+    }
+
+    /// Path of the API which `expr` evaluates to
+    fn resolve(&self, expr: &swc_ecma_ast::Expr) -> Option<Vec<String>> {
+        match expr {
+            swc_ecma_ast::Expr::Ident(ident) => self.binding(&ident.sym),
+            swc_ecma_ast::Expr::Call(call) => match &call.callee {
+                swc_ecma_ast::Callee::Expr(callee) => {
+                    self.is_import_function(callee).then(Vec::new)
+                }
+                _ => None,
+            },
+            swc_ecma_ast::Expr::Member(member) => self.resolve_member(member),
+            swc_ecma_ast::Expr::OptChain(opt_chain) => match &*opt_chain.base {
+                swc_ecma_ast::OptChainBase::Member(member) => self.resolve_member(member),
+                swc_ecma_ast::OptChainBase::Call(call) => {
+                    self.is_import_function(&call.callee).then(Vec::new)
+                }
+            },
+            swc_ecma_ast::Expr::Await(await_expr) => self.resolve(&await_expr.arg),
+            swc_ecma_ast::Expr::Paren(paren) => self.resolve(&paren.expr),
+            swc_ecma_ast::Expr::TsNonNull(non_null) => self.resolve(&non_null.expr),
+            swc_ecma_ast::Expr::TsAs(as_expr) => self.resolve(&as_expr.expr),
+            swc_ecma_ast::Expr::TsSatisfies(satisfies) => self.resolve(&satisfies.expr),
+            swc_ecma_ast::Expr::TsTypeAssertion(assertion) => self.resolve(&assertion.expr),
+            _ => None,
+        }
+    }
+
+    fn resolve_member(&self, member: &swc_ecma_ast::MemberExpr) -> Option<Vec<String>> {
+        let name = match &member.prop {
+            swc_ecma_ast::MemberProp::Ident(ident) => ident.sym.to_string(),
+            swc_ecma_ast::MemberProp::Computed(computed) => match &*computed.expr {
+                swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(str)) => str.value.to_string(),
+                _ => return None,
+            },
+            swc_ecma_ast::MemberProp::PrivateName(_) => return None,
+        };
+        let mut path = self.resolve(&member.obj)?;
+        path.push(name);
+        Some(path)
+    }
+
+    /// `importVsCodeApi` or `api.importVsCodeApi` of the namespace import, or one of their variants
+    fn is_import_function(&self, callee: &swc_ecma_ast::Expr) -> bool {
+        match callee {
+            swc_ecma_ast::Expr::Ident(ident) => {
+                self.import_functions.contains(&*ident.sym)
+                    && !self.scopes[1..]
+                        .iter()
+                        .any(|scope| scope.contains_key(&*ident.sym))
+            }
+            swc_ecma_ast::Expr::Member(member) => {
+                matches!(self.resolve_member(member).as_deref(), Some([name]) if is_import_function_name(self.host, name))
+            }
+            swc_ecma_ast::Expr::Paren(paren) => self.is_import_function(&paren.expr),
+            _ => false,
+        }
+    }
+
+    /// Bind the names in `pat` to the parts of the API at `path`
+    fn bind(&mut self, pat: &swc_ecma_ast::Pat, path: Vec<String>) {
+        match pat {
+            swc_ecma_ast::Pat::Ident(ident) => {
+                self.set_binding(ident.id.sym.to_string(), Some(path));
+            }
+            swc_ecma_ast::Pat::Object(object) => {
+                for prop in &object.props {
+                    let (key, value, span) = match prop {
+                        swc_ecma_ast::ObjectPatProp::KeyValue(key_value) => {
+                            let key = match &key_value.key {
+                                swc_ecma_ast::PropName::Ident(ident) => ident.sym.to_string(),
+                                swc_ecma_ast::PropName::Str(str) => str.value.to_string(),
+                                _ => continue,
+                            };
+                            (
+                                key,
+                                Some(&*key_value.value),
+                                swc_common::Spanned::span(&key_value.key),
+                            )
+                        }
+                        swc_ecma_ast::ObjectPatProp::Assign(assign) => {
+                            (assign.key.sym.to_string(), None, assign.key.span)
+                        }
+                        swc_ecma_ast::ObjectPatProp::Rest(_) => continue,
+                    };
+                    let mut prop_path = path.clone();
+                    prop_path.push(key.clone());
+                    self.push(&prop_path, span);
+                    match value {
+                        Some(value) => self.bind(value, prop_path),
+                        None => self.set_binding(key, Some(prop_path)),
+                    }
+                }
+            }
+            swc_ecma_ast::Pat::Assign(assign) => self.bind(&assign.left, path),
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, path: &[String], span: swc_common::Span) {
+        if path.is_empty() {
+            return;
+        }
+        let (line, column) = line_column(self.source, span.lo);
+        self.uses.push(Use {
+            path: path.join("."),
+            line,
+            column,
+        });
+    }
+
+    /// Path of a type name like `ExtensionContext` or `vscode.window.QuickPickItem`
+    fn resolve_type_name(&self, name: &swc_ecma_ast::TsEntityName) -> Option<Vec<String>> {
+        match name {
+            swc_ecma_ast::TsEntityName::Ident(ident) => self.binding(&ident.sym),
+            swc_ecma_ast::TsEntityName::TsQualifiedName(qualified) => {
+                let mut path = self.resolve_type_name(&qualified.left)?;
+                path.push(qualified.right.sym.to_string());
+                Some(path)
+            }
+        }
+    }
+}
+
+impl swc_ecma_visit::Visit for Collector<'_> {
+    fn visit_import_decl(&mut self, import: &swc_ecma_ast::ImportDecl) {
+        if !self
+            .module_specifiers
+            .iter()
+            .any(|specifier| *specifier == *import.src.value)
+        {
+            return;
+        }
+        for specifier in &import.specifiers {
+            match specifier {
+                swc_ecma_ast::ImportSpecifier::Named(named) => {
+                    let Some(name) = imported_name(specifier) else {
+                        continue;
+                    };
+                    if is_import_function_name(self.host, &name) {
+                        self.import_functions.insert(named.local.sym.to_string());
+                    } else {
+                        self.set_binding(named.local.sym.to_string(), Some(vec![name]));
+                    }
+                }
+                swc_ecma_ast::ImportSpecifier::Namespace(namespace) => {
+                    self.set_binding(namespace.local.sym.to_string(), Some(vec![]));
+                }
+                swc_ecma_ast::ImportSpecifier::Default(_) => {}
+            }
+        }
+    }
+
+    fn visit_var_declarator(&mut self, declarator: &swc_ecma_ast::VarDeclarator) {
+        swc_ecma_visit::Visit::visit_pat(self, &declarator.name);
+        let Some(init) = &declarator.init else {
+            return;
+        };
+        match self.resolve(init) {
+            Some(path) => {
+                swc_ecma_visit::Visit::visit_expr(self, init);
+                self.bind(&declarator.name, path);
+            }
+            None => {
+                swc_ecma_visit::Visit::visit_expr(self, init);
+                for name in crate::scope::binding_names(&declarator.name) {
+                    self.set_binding(name, None);
+                }
+            }
+        }
+    }
+
+    fn visit_assign_expr(&mut self, assign: &swc_ecma_ast::AssignExpr) {
+        swc_ecma_visit::Visit::visit_expr(self, &assign.right);
+        if let (Some(path), swc_ecma_ast::PatOrExpr::Pat(pat)) =
+            (self.resolve(&assign.right), &assign.left)
+        {
+            self.bind(pat, path);
+        }
+    }
+
+    fn visit_member_expr(&mut self, member: &swc_ecma_ast::MemberExpr) {
+        match self.resolve_member(member) {
+            Some(path) => {
+                self.push(&path, member.span);
+                // only computed keys may use the API further
+                let mut object = member;
+                loop {
+                    if let swc_ecma_ast::MemberProp::Computed(computed) = &object.prop {
+                        swc_ecma_visit::Visit::visit_expr(self, &computed.expr);
+                    }
+                    match &*object.obj {
+                        swc_ecma_ast::Expr::Member(inner) => object = inner,
+                        other => {
+                            swc_ecma_visit::Visit::visit_expr(self, other);
+                            break;
+                        }
+                    }
+                }
+            }
+            None => swc_ecma_visit::VisitWith::visit_children_with(member, self),
+        }
+    }
+
+    fn visit_class(&mut self, class: &swc_ecma_ast::Class) {
+        self.with_scope(crate::scope::type_param_names(&class.type_params), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(class, v)
+        });
+    }
+
+    fn visit_ts_interface_decl(&mut self, interface: &swc_ecma_ast::TsInterfaceDecl) {
+        self.with_scope(
+            crate::scope::type_param_names(&interface.type_params),
+            |v| swc_ecma_visit::VisitWith::visit_children_with(interface, v),
+        );
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, alias: &swc_ecma_ast::TsTypeAliasDecl) {
+        self.with_scope(crate::scope::type_param_names(&alias.type_params), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(alias, v)
+        });
+    }
+
+    fn visit_function(&mut self, function: &swc_ecma_ast::Function) {
+        self.with_scope(crate::scope::function_names(function), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(function, v)
+        });
+    }
+
+    fn visit_arrow_expr(&mut self, arrow: &swc_ecma_ast::ArrowExpr) {
+        self.with_scope(crate::scope::arrow_names(arrow), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(arrow, v)
+        });
+    }
+
+    fn visit_constructor(&mut self, constructor: &swc_ecma_ast::Constructor) {
+        self.with_scope(crate::scope::constructor_names(constructor), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(constructor, v)
+        });
+    }
+
+    fn visit_block_stmt(&mut self, block: &swc_ecma_ast::BlockStmt) {
+        self.with_scope(crate::scope::lexical_names(&block.stmts), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(block, v)
+        });
+    }
+
+    fn visit_catch_clause(&mut self, clause: &swc_ecma_ast::CatchClause) {
+        self.with_scope(crate::scope::catch_names(clause), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(clause, v)
+        });
+    }
+
+    fn visit_for_stmt(&mut self, stmt: &swc_ecma_ast::ForStmt) {
+        self.with_scope(crate::scope::for_names(stmt), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(stmt, v)
+        });
+    }
+
+    fn visit_for_in_stmt(&mut self, stmt: &swc_ecma_ast::ForInStmt) {
+        self.with_scope(crate::scope::for_head_names(&stmt.left), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(stmt, v)
+        });
+    }
+
+    fn visit_for_of_stmt(&mut self, stmt: &swc_ecma_ast::ForOfStmt) {
+        self.with_scope(crate::scope::for_head_names(&stmt.left), |v| {
+            swc_ecma_visit::VisitWith::visit_children_with(stmt, v)
+        });
+    }
+
+    fn visit_ts_type_ref(&mut self, type_ref: &swc_ecma_ast::TsTypeRef) {
+        if let Some(path) = self.resolve_type_name(&type_ref.type_name) {
+            self.push(&path, type_ref.span);
+        }
+        if let Some(type_params) = &type_ref.type_params {
+            swc_ecma_visit::Visit::visit_ts_type_param_instantiation(self, type_params);
+        }
+    }
+}
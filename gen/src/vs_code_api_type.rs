@@ -1,6 +1,7 @@
 pub fn module_item(
     comments: &dyn swc_common::comments::Comments,
-    result_vec: &Vec<crate::pickup::ResultDeclWithComments>,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
+    host: &crate::HostModule,
 ) -> swc_ecma_ast::ModuleItem {
     swc_ecma_ast::ModuleItem::ModuleDecl(swc_ecma_ast::ModuleDecl::ExportDecl(
         swc_ecma_ast::ExportDecl {
@@ -12,19 +13,14 @@ pub fn module_item(
                     swc_common::comments::Comment {
                         span: swc_common::DUMMY_SP,
                         kind: swc_common::comments::CommentKind::Block,
-                        text: swc_atoms::Atom::from(
-                            "*
- * Type Definition for Visual Studio Code 1.80 Extension API
- * See https://code.visualstudio.com/api for more information
- ",
-                        ),
+                        text: swc_atoms::Atom::from(host.doc_comment()),
                     },
                 );
 
                 span
             },
             decl: swc_ecma_ast::Decl::TsTypeAlias(Box::new(swc_ecma_ast::TsTypeAliasDecl {
-                id: (*crate::ident::VS_CODE_API_IDENT).clone(),
+                id: host.type_ident(),
                 declare: false,
                 span: swc_common::Span::default(),
                 type_ann: Box::new(result_decl_vec_to_ts_type(result_vec, comments)),
@@ -35,19 +31,14 @@ pub fn module_item(
 }
 
 fn result_decl_vec_to_ts_type(
-    result_vec: &Vec<crate::pickup::ResultDeclWithComments>,
+    result_vec: &[crate::pickup::ResultDeclWithComments],
     comments: &dyn swc_common::comments::Comments,
 ) -> swc_ecma_ast::TsType {
     swc_ecma_ast::TsType::TsTypeLit(swc_ecma_ast::TsTypeLit {
         span: swc_common::Span::default(),
         members: result_vec
             .iter()
-            .flat_map(|result| {
-                result_decl_to_ts_property_signature(result, comments)
-                    .iter()
-                    .map(|sig| sig.clone())
-                    .collect::<Vec<_>>()
-            })
+            .flat_map(|result| result_decl_to_ts_property_signature(result, comments))
             .collect(),
     })
 }
@@ -67,61 +58,37 @@ fn result_decl_to_ts_property_signature(
                 })
                 .collect::<Vec<_>>();
             let members_with_default_constructor = if members.is_empty() {
-                vec![
-                    swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(
-                        swc_ecma_ast::TsConstructSignatureDecl {
-                            span: {
-                                let span = swc_common::Span::dummy_with_cmt();
-                                match &result.comments {
-                                    Some(comment_vec) => {
-                                        swc_common::comments::Comments::add_leading_comments(
-                                            &comments,
-                                            span.lo,
-                                            comment_vec.clone(),
-                                        )
-                                    }
-                                    None => {}
-                                }
-                                span
-                            },
-                            params: vec![],
-                            type_params: class.class.type_params.clone(),
-                            type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
-                                span: swc_common::Span::default(),
-                                type_ann: Box::new(swc_ecma_ast::TsType::TsTypeRef(
-                                    swc_ecma_ast::TsTypeRef {
-                                        span: swc_common::Span::default(),
-                                        type_name: swc_ecma_ast::TsEntityName::Ident(class.ident.clone()),
-                                        type_params: match &class.class.type_params {
-                                            Some(p) => {
-                                                Some(Box::new(swc_ecma_ast::TsTypeParamInstantiation {
-                                                    span: swc_common::Span::default(),
-                                                    params: p
-                                                        .params
-                                                        .iter()
-                                                        .map(|param| {
-                                                            Box::new(swc_ecma_ast::TsType::TsTypeRef(
-                                                                swc_ecma_ast::TsTypeRef {
-                                                                    span: swc_common::Span::default(),
-                                                                    type_name:
-                                                                        swc_ecma_ast::TsEntityName::Ident(
-                                                                            param.name.clone(),
-                                                                        ),
-                                                                    type_params: None,
-                                                                },
-                                                            ))
-                                                        })
-                                                        .collect(),
-                                                }))
-                                            }
-                                            None => None,
-                                        },
-                                    },
-                                )),
-                            })),
+                vec![swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(
+                    swc_ecma_ast::TsConstructSignatureDecl {
+                        span: {
+                            let span = swc_common::Span::dummy_with_cmt();
+                            if let Some(comment_vec) = &result.comments {
+                                swc_common::comments::Comments::add_leading_comments(
+                                    &comments,
+                                    span.lo,
+                                    comment_vec.clone(),
+                                );
+                            }
+                            span
                         },
-                    )
-                ]
+                        params: vec![],
+                        type_params: class.class.type_params.clone(),
+                        type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
+                            span: swc_common::Span::default(),
+                            type_ann: Box::new(swc_ecma_ast::TsType::TsTypeRef(
+                                swc_ecma_ast::TsTypeRef {
+                                    span: swc_common::Span::default(),
+                                    type_name: swc_ecma_ast::TsEntityName::Ident(
+                                        class.ident.clone(),
+                                    ),
+                                    type_params: type_param_decl_to_instantiation(
+                                        &class.class.type_params,
+                                    ),
+                                },
+                            )),
+                        })),
+                    },
+                )]
             } else {
                 members
             };
@@ -130,15 +97,12 @@ fn result_decl_to_ts_property_signature(
                 swc_ecma_ast::TsPropertySignature {
                     span: {
                         let span = swc_common::Span::dummy_with_cmt();
-                        match &result.comments {
-                            Some(comment_vec) => {
-                                swc_common::comments::Comments::add_leading_comments(
-                                    &comments,
-                                    span.lo,
-                                    comment_vec.clone(),
-                                )
-                            }
-                            None => {}
+                        if let Some(comment_vec) = &result.comments {
+                            swc_common::comments::Comments::add_leading_comments(
+                                &comments,
+                                span.lo,
+                                comment_vec.clone(),
+                            );
                         }
                         span
                     },
@@ -166,15 +130,12 @@ fn result_decl_to_ts_property_signature(
                 swc_ecma_ast::TsMethodSignature {
                     span: {
                         let span = swc_common::Span::dummy_with_cmt();
-                        match &result.comments {
-                            Some(comment_vec) => {
-                                swc_common::comments::Comments::add_leading_comments(
-                                    &comments,
-                                    span.lo,
-                                    comment_vec.clone(),
-                                )
-                            }
-                            None => {}
+                        if let Some(comment_vec) = &result.comments {
+                            swc_common::comments::Comments::add_leading_comments(
+                                &comments,
+                                span.lo,
+                                comment_vec.clone(),
+                            );
                         }
                         span
                     },
@@ -201,15 +162,12 @@ fn result_decl_to_ts_property_signature(
                     swc_ecma_ast::TsPropertySignature {
                         span: {
                             let span = swc_common::Span::dummy_with_cmt();
-                            match &result.comments {
-                                Some(comment_vec) => {
-                                    swc_common::comments::Comments::add_leading_comments(
-                                        &comments,
-                                        span.lo,
-                                        comment_vec.clone(),
-                                    )
-                                }
-                                None => {}
+                            if let Some(comment_vec) = &result.comments {
+                                swc_common::comments::Comments::add_leading_comments(
+                                    &comments,
+                                    span.lo,
+                                    comment_vec.clone(),
+                                );
                             }
                             span
                         },
@@ -252,15 +210,12 @@ fn result_decl_to_ts_property_signature(
                 swc_ecma_ast::TsPropertySignature {
                     span: {
                         let span = swc_common::Span::dummy_with_cmt();
-                        match &result.comments {
-                            Some(comment_vec) => {
-                                swc_common::comments::Comments::add_leading_comments(
-                                    &comments,
-                                    span.lo,
-                                    comment_vec.clone(),
-                                )
-                            }
-                            None => {}
+                        if let Some(comment_vec) = &result.comments {
+                            swc_common::comments::Comments::add_leading_comments(
+                                &comments,
+                                span.lo,
+                                comment_vec.clone(),
+                            );
                         }
                         span
                     },
@@ -278,7 +233,7 @@ fn result_decl_to_ts_property_signature(
                                 members: ts_enum
                                     .members
                                     .iter()
-                                    .map(|member| enum_member_to_ts_type_element(member))
+                                    .map(enum_member_to_ts_type_element)
                                     .collect(),
                             },
                         )),
@@ -292,24 +247,30 @@ fn result_decl_to_ts_property_signature(
                 swc_ecma_ast::TsPropertySignature {
                     span: {
                         let span = swc_common::Span::dummy_with_cmt();
-                        match &result.comments {
-                            Some(comment_vec) => {
-                                swc_common::comments::Comments::add_leading_comments(
-                                    &comments,
-                                    span.lo,
-                                    comment_vec.clone(),
-                                )
-                            }
-                            None => {}
+                        if let Some(comment_vec) = &result.comments {
+                            swc_common::comments::Comments::add_leading_comments(
+                                &comments,
+                                span.lo,
+                                comment_vec.clone(),
+                            );
                         }
                         span
                     },
                     computed: false,
                     optional: false,
                     readonly: true,
-                    key: Box::new(swc_ecma_ast::Expr::Ident(
-                        sub_module.name.clone().ident().unwrap(),
-                    )),
+                    key: Box::new(match &sub_module.name {
+                        swc_ecma_ast::TsModuleName::Ident(ident) => {
+                            swc_ecma_ast::Expr::Ident(ident.clone())
+                        }
+                        swc_ecma_ast::TsModuleName::Str(str) => {
+                            swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Str(swc_ecma_ast::Str {
+                                span: swc_common::Span::default(),
+                                value: str.value.clone(),
+                                raw: None,
+                            }))
+                        }
+                    }),
                     init: None,
                     params: vec![],
                     type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
@@ -335,7 +296,7 @@ fn class_member_to_ts_type_element(
         swc_ecma_ast::ClassMember::Constructor(constructor) => {
             Some(swc_ecma_ast::TsTypeElement::TsConstructSignatureDecl(
                 swc_ecma_ast::TsConstructSignatureDecl {
-                    span: constructor.span.clone(),
+                    span: constructor.span,
                     params: constructor
                         .params
                         .iter()
@@ -350,30 +311,7 @@ fn class_member_to_ts_type_element(
                             swc_ecma_ast::TsTypeRef {
                                 span: swc_common::Span::default(),
                                 type_name: swc_ecma_ast::TsEntityName::Ident(class_name.clone()),
-                                type_params: match type_params {
-                                    Some(p) => {
-                                        Some(Box::new(swc_ecma_ast::TsTypeParamInstantiation {
-                                            span: swc_common::Span::default(),
-                                            params: p
-                                                .params
-                                                .iter()
-                                                .map(|param| {
-                                                    Box::new(swc_ecma_ast::TsType::TsTypeRef(
-                                                        swc_ecma_ast::TsTypeRef {
-                                                            span: swc_common::Span::default(),
-                                                            type_name:
-                                                                swc_ecma_ast::TsEntityName::Ident(
-                                                                    param.name.clone(),
-                                                                ),
-                                                            type_params: None,
-                                                        },
-                                                    ))
-                                                })
-                                                .collect(),
-                                        }))
-                                    }
-                                    None => None,
-                                },
+                                type_params: type_param_decl_to_instantiation(type_params),
                             },
                         )),
                     })),
@@ -473,7 +411,7 @@ fn enum_member_to_ts_type_element(
                                     span: swc_common::Span::default(),
                                     value: match &*unary_expr.arg {
                                         swc_ecma_ast::Expr::Lit(swc_ecma_ast::Lit::Num(num)) => {
-                                            num.value
+                                            -num.value
                                         }
                                         _ => todo!(),
                                     },
@@ -497,3 +435,24 @@ fn enum_member_to_ts_type_element(
         init: None,
     })
 }
+
+fn type_param_decl_to_instantiation(
+    type_params: &Option<Box<swc_ecma_ast::TsTypeParamDecl>>,
+) -> Option<Box<swc_ecma_ast::TsTypeParamInstantiation>> {
+    type_params.as_ref().map(|p| {
+        Box::new(swc_ecma_ast::TsTypeParamInstantiation {
+            span: swc_common::Span::default(),
+            params: p
+                .params
+                .iter()
+                .map(|param| {
+                    Box::new(swc_ecma_ast::TsType::TsTypeRef(swc_ecma_ast::TsTypeRef {
+                        span: swc_common::Span::default(),
+                        type_name: swc_ecma_ast::TsEntityName::Ident(param.name.clone()),
+                        type_params: None,
+                    }))
+                })
+                .collect(),
+        })
+    })
+}
@@ -0,0 +1,170 @@
+//! The module for the webview side, from the typings of `vscode-webview`.
+//! Webviews get their API by the global `acquireVsCodeApi` instead of `require("vscode")`
+
+/// Where `generate` reads the typings from by default
+pub const TYPES_URL: &str = "https://raw.githubusercontent.com/DefinitelyTyped/DefinitelyTyped/master/types/vscode-webview/index.d.ts";
+
+/// ```ts
+/// export interface WebviewApi<StateType> { ... }
+/// declare const acquireVsCodeApi: undefined | (<StateType = unknown>() => WebviewApi<StateType>);
+/// export function getWebviewApi<State = unknown, Message = unknown>():
+///   TypedWebviewApi<State, Message> | undefined { ... }
+/// ```
+///
+/// The types of `index_d_ts` are kept as they are, with their docs.
+pub fn generate(index_d_ts: &str) -> Result<String, crate::Error> {
+    let (module_items, comments) = crate::parse_d_ts(index_d_ts)?;
+    let result_vec = crate::pickup::pick_module_item(&module_items, &comments);
+
+    let acquire = result_vec
+        .iter()
+        .find_map(|result| match &result.decl {
+            crate::pickup::ResultDecl::Fn(fn_decl) if &*fn_decl.ident.sym == ACQUIRE => {
+                Some((result, fn_decl))
+            }
+            _ => None,
+        })
+        .ok_or(crate::Error::WebviewDeclarationNotFound(ACQUIRE))?;
+    if !result_vec.iter().any(|result| {
+        matches!(&result.decl, crate::pickup::ResultDecl::TsInterface(interface) if &*interface.id.sym == API_TYPE)
+    }) {
+        return Err(crate::Error::WebviewDeclarationNotFound(API_TYPE));
+    }
+
+    let module_block = swc_common::GLOBALS.set(&swc_common::Globals::default(), || {
+        let mut body = vec![declare_acquire(acquire.0, acquire.1, &comments)];
+        body.extend(crate::snippet::module_items(GET_WEBVIEW_API, &comments)?);
+        let host = crate::HostModule::default();
+        body.extend(
+            result_vec
+                .iter()
+                .filter(|result| result.decl.is_type_only())
+                .filter_map(|result| {
+                    crate::type_decls::module_item_transform(result, &comments, &host)
+                }),
+        );
+        Ok::<_, crate::Error>(swc_ecma_ast::TsModuleBlock {
+            span: swc_common::Span::default(),
+            body,
+        })
+    })?;
+
+    crate::node_to_code_string(&module_block, &comments)
+}
+
+const ACQUIRE: &str = "acquireVsCodeApi";
+
+const API_TYPE: &str = "WebviewApi";
+
+const GET_WEBVIEW_API: &str = r#"/**
+ * {@link WebviewApi} whose `postMessage` takes only `Message`,
+ * the messages the extension expects
+ */
+export type TypedWebviewApi<State, Message> = Omit<WebviewApi<State>, "postMessage"> & {
+  /**
+   * Post a message to the owner of the webview.
+   *
+   * @param message Data to post. Must be JSON serializable.
+   */
+  postMessage(message: Message): void;
+};
+
+let webviewApi: WebviewApi<unknown> | undefined = undefined;
+
+/**
+ * acquire webview API
+ *
+ * ```ts
+ * acquireVsCodeApi()
+ * ```
+ *
+ * Returns WebviewApi only within the webview.
+ * `acquireVsCodeApi` can be called only once, so the API is kept and returned from then on
+ */
+export function getWebviewApi<State = unknown, Message = unknown>():
+  | TypedWebviewApi<State, Message>
+  | undefined {
+  if (webviewApi === undefined) {
+    const acquireFunc = typeof acquireVsCodeApi === "function" ? acquireVsCodeApi : undefined;
+    webviewApi = acquireFunc === undefined ? undefined : acquireFunc();
+  }
+  return webviewApi as TypedWebviewApi<State, Message> | undefined;
+}
+"#;
+
+/// `declare const acquireVsCodeApi: undefined | (<StateType = unknown>() => WebviewApi<StateType>);`
+/// with the docs of the global function
+fn declare_acquire(
+    result: &crate::pickup::ResultDeclWithComments,
+    fn_decl: &swc_ecma_ast::FnDecl,
+    comments: &dyn swc_common::comments::Comments,
+) -> swc_ecma_ast::ModuleItem {
+    let fn_type = swc_ecma_ast::TsType::TsFnOrConstructorType(
+        swc_ecma_ast::TsFnOrConstructorType::TsFnType(swc_ecma_ast::TsFnType {
+            span: swc_common::Span::default(),
+            params: fn_decl
+                .function
+                .params
+                .iter()
+                .map(|param| crate::fn_to_type::pat_to_ts_fn_param(&param.pat))
+                .collect(),
+            type_params: fn_decl.function.type_params.clone(),
+            type_ann: fn_decl.function.return_type.clone().unwrap_or_else(|| {
+                Box::new(swc_ecma_ast::TsTypeAnn {
+                    span: swc_common::Span::default(),
+                    type_ann: Box::new(swc_ecma_ast::TsType::TsKeywordType(
+                        swc_ecma_ast::TsKeywordType {
+                            span: swc_common::Span::default(),
+                            kind: swc_ecma_ast::TsKeywordTypeKind::TsUnknownKeyword,
+                        },
+                    )),
+                })
+            }),
+        }),
+    );
+    swc_ecma_ast::ModuleItem::Stmt(swc_ecma_ast::Stmt::Decl(swc_ecma_ast::Decl::Var(Box::new(
+        swc_ecma_ast::VarDecl {
+            span: {
+                let span = swc_common::Span::dummy_with_cmt();
+                if let Some(comment_vec) = &result.comments {
+                    comments.add_leading_comments(span.lo, comment_vec.clone());
+                }
+                span
+            },
+            declare: true,
+            kind: swc_ecma_ast::VarDeclKind::Const,
+            decls: vec![swc_ecma_ast::VarDeclarator {
+                span: swc_common::Span::default(),
+                definite: false,
+                name: swc_ecma_ast::Pat::Ident(swc_ecma_ast::BindingIdent {
+                    id: fn_decl.ident.clone(),
+                    type_ann: Some(Box::new(swc_ecma_ast::TsTypeAnn {
+                        span: swc_common::Span::default(),
+                        type_ann: Box::new(swc_ecma_ast::TsType::TsUnionOrIntersectionType(
+                            swc_ecma_ast::TsUnionOrIntersectionType::TsUnionType(
+                                swc_ecma_ast::TsUnionType {
+                                    span: swc_common::Span::default(),
+                                    types: vec![
+                                        Box::new(swc_ecma_ast::TsType::TsKeywordType(
+                                            swc_ecma_ast::TsKeywordType {
+                                                span: swc_common::Span::default(),
+                                                kind: swc_ecma_ast::TsKeywordTypeKind::TsUndefinedKeyword,
+                                            },
+                                        )),
+                                        Box::new(swc_ecma_ast::TsType::TsParenthesizedType(
+                                            swc_ecma_ast::TsParenthesizedType {
+                                                span: swc_common::Span::default(),
+                                                type_ann: Box::new(fn_type),
+                                            },
+                                        )),
+                                    ],
+                                },
+                            ),
+                        )),
+                    })),
+                }),
+                init: None,
+            }],
+        },
+    ))))
+}
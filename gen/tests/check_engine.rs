@@ -0,0 +1,152 @@
+const V1_75: &str = r#"declare module 'vscode' {
+    export namespace window {
+        export function showInformationMessage(message: string): Thenable<string | undefined>;
+    }
+    export interface ExtensionContext {
+        readonly extensionPath: string;
+    }
+}
+"#;
+
+const V1_77: &str = r#"declare module 'vscode' {
+    export namespace window {
+        export function showInformationMessage(message: string): Thenable<string | undefined>;
+        export function showQuickPick(items: string[]): Thenable<string | undefined>;
+    }
+    export interface ExtensionContext {
+        readonly extensionPath: string;
+    }
+    export class TabInputText {
+        constructor(uri: string);
+    }
+}
+"#;
+
+const EXTENSION: &str = r#"import { type ExtensionContext, type TabInputText, importVsCodeApi } from "jsr:@narumincho/vscode";
+
+export function activate(context: ExtensionContext) {
+  const vscode = importVsCodeApi();
+  if (vscode === undefined) {
+    throw new Error("not in an extension");
+  }
+  const { window } = vscode;
+  window.showInformationMessage(context.extensionPath);
+  void window.showQuickPick(["a"]);
+  const input: TabInputText = new vscode.TabInputText("a");
+}
+"#;
+
+#[test]
+fn check_engine() {
+    let history = gen::since::History::from_sources(
+        &[
+            ("1.75.0".to_string(), V1_75.to_string()),
+            ("1.77.0".to_string(), V1_77.to_string()),
+        ],
+        &gen::HostModule::default(),
+    )
+    .unwrap();
+    let sources = [("src/extension.ts".to_string(), EXTENSION.to_string())];
+
+    let incompatibilities =
+        gen::check_engine::check(&history, "^1.75.0", &sources, &gen::HostModule::default())
+            .unwrap()
+            .into_iter()
+            .map(|incompatibility| incompatibility.to_string())
+            .collect::<Vec<_>>();
+    assert_eq!(
+        incompatibilities,
+        vec![
+            "src/extension.ts:10:8: window.showQuickPick requires VS Code 1.77",
+            "src/extension.ts:11:16: TabInputText requires VS Code 1.77",
+            "src/extension.ts:11:35: TabInputText requires VS Code 1.77",
+        ]
+    );
+
+    assert!(
+        gen::check_engine::check(&history, ">=1.77.0", &sources, &gen::HostModule::default())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn minimum_version() {
+    assert_eq!(
+        gen::check_engine::minimum_version("^1.75.0").unwrap(),
+        vec![1, 75]
+    );
+    assert_eq!(
+        gen::check_engine::minimum_version(">= 1.80.x < 2").unwrap(),
+        vec![1, 80]
+    );
+    assert!(gen::check_engine::minimum_version("latest").is_err());
+}
+
+#[test]
+fn import_function_variants() {
+    let history = gen::since::History::from_sources(
+        &[
+            ("1.75.0".to_string(), V1_75.to_string()),
+            ("1.77.0".to_string(), V1_77.to_string()),
+        ],
+        &gen::HostModule::default(),
+    )
+    .unwrap();
+    let sources = [
+        (
+            "src/throw.ts".to_string(),
+            "import { importVsCodeApiOrThrow } from \"jsr:@narumincho/vscode\";\n\nimportVsCodeApiOrThrow().window.showQuickPick([\"a\"]);\n".to_string(),
+        ),
+        (
+            "src/promises.ts".to_string(),
+            "import * as api from \"jsr:@narumincho/vscode\";\n\nawait api.importVsCodeApiPromises()?.window.showQuickPick([\"a\"]);\n".to_string(),
+        ),
+    ];
+
+    let incompatibilities =
+        gen::check_engine::check(&history, "^1.75.0", &sources, &gen::HostModule::default())
+            .unwrap()
+            .into_iter()
+            .map(|incompatibility| incompatibility.to_string())
+            .collect::<Vec<_>>();
+    assert_eq!(
+        incompatibilities,
+        vec![
+            "src/throw.ts:3:1: window.showQuickPick requires VS Code 1.77",
+            "src/promises.ts:3:7: window.showQuickPick requires VS Code 1.77",
+        ]
+    );
+}
+
+#[test]
+fn host_module() {
+    let host = gen::HostModule {
+        module_name: "azdata".to_string(),
+        type_name: "AzdataAPI".to_string(),
+        import_function: "importAzdata".to_string(),
+        ..gen::HostModule::default()
+    };
+    let history = gen::since::History::from_sources(
+        &[
+            ("1.75.0".to_string(), V1_75.replace("'vscode'", "'azdata'")),
+            ("1.77.0".to_string(), V1_77.replace("'vscode'", "'azdata'")),
+        ],
+        &host,
+    )
+    .unwrap();
+    let sources = [(
+        "src/extension.ts".to_string(),
+        "import { importAzdataOrThrow } from \"./azdata.ts\";\n\nimportAzdataOrThrow().window.showQuickPick([\"a\"]);\n".to_string(),
+    )];
+
+    let incompatibilities = gen::check_engine::check(&history, "^1.75.0", &sources, &host)
+        .unwrap()
+        .into_iter()
+        .map(|incompatibility| incompatibility.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        incompatibilities,
+        vec!["src/extension.ts:3:1: window.showQuickPick requires VS Code 1.77"]
+    );
+}
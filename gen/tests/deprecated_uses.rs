@@ -0,0 +1,123 @@
+const INDEX_D_TS: &str = r#"declare module 'vscode' {
+    export namespace workspace {
+        /**
+         * The folder which is open.
+         * @deprecated Use {@link workspace.workspaceFolders}
+         * instead.
+         */
+        export const rootPath: string | undefined;
+        export const workspaceFolders: readonly string[] | undefined;
+    }
+    /**
+     * @deprecated
+     */
+    export interface OldOptions {
+        readonly value: string;
+    }
+}
+"#;
+
+const EXTENSION: &str = r#"import * as api from "jsr:@narumincho/vscode";
+
+export function activate() {
+  const { workspace } = api.importVsCodeApi()!;
+  console.log(workspace.rootPath ?? workspace.workspaceFolders);
+  const options: api.OldOptions = { value: "" };
+}
+"#;
+
+#[test]
+fn deprecated_uses() {
+    let deprecated_uses = gen::deprecated_uses::check(
+        INDEX_D_TS,
+        &[("src/extension.ts".to_string(), EXTENSION.to_string())],
+        &gen::HostModule::default(),
+    )
+    .unwrap()
+    .into_iter()
+    .map(|deprecated_use| deprecated_use.to_string())
+    .collect::<Vec<_>>();
+    assert_eq!(
+        deprecated_uses,
+        vec![
+            "src/extension.ts:5:15: workspace.rootPath is deprecated: Use {@link workspace.workspaceFolders} instead.",
+            "src/extension.ts:6:18: OldOptions is deprecated",
+        ]
+    );
+}
+
+#[test]
+fn host_module() {
+    let host = gen::HostModule {
+        module_name: "azdata".to_string(),
+        type_name: "AzdataAPI".to_string(),
+        import_function: "importAzdata".to_string(),
+        ..gen::HostModule::default()
+    };
+    let deprecated_uses = gen::deprecated_uses::check(
+        r#"declare module 'azdata' {
+    export namespace connection {
+        /**
+         * @deprecated Use {@link connection.getConnections}
+         */
+        export function getActiveConnections(): Thenable<string[]>;
+        export function getConnections(): Thenable<string[]>;
+    }
+}
+"#,
+        &[(
+            "src/extension.ts".to_string(),
+            r#"import { importAzdataOrThrow } from "./azdata.ts";
+
+const azdata = importAzdataOrThrow();
+void azdata.connection.getActiveConnections();
+void azdata.connection.getConnections();
+"#
+            .to_string(),
+        )],
+        &host,
+    )
+    .unwrap()
+    .into_iter()
+    .map(|deprecated_use| deprecated_use.to_string())
+    .collect::<Vec<_>>();
+    assert_eq!(
+        deprecated_uses,
+        vec!["src/extension.ts:4:6: connection.getActiveConnections is deprecated: Use {@link connection.getConnections}"]
+    );
+}
+
+#[test]
+fn shadowed_bindings() {
+    let deprecated_uses = gen::deprecated_uses::check(
+        INDEX_D_TS,
+        &[(
+            "src/extension.ts".to_string(),
+            r#"import { importVsCodeApiOrThrow } from "jsr:@narumincho/vscode";
+
+const vscode = importVsCodeApiOrThrow();
+function local() {
+  const vscode = { workspace: { rootPath: "" } };
+  console.log(vscode.workspace.rootPath);
+}
+function parameter(workspace: { rootPath: string }, importVsCodeApiOrThrow: () => typeof vscode) {
+  console.log(workspace.rootPath, importVsCodeApiOrThrow().workspace.rootPath);
+}
+for (const { workspace } of [{ workspace: { rootPath: "" } }]) {
+  console.log(workspace.rootPath);
+}
+console.log(vscode.workspace.rootPath);
+"#
+            .to_string(),
+        )],
+        &gen::HostModule::default(),
+    )
+    .unwrap()
+    .into_iter()
+    .map(|deprecated_use| deprecated_use.to_string())
+    .collect::<Vec<_>>();
+    assert_eq!(
+        deprecated_uses,
+        vec!["src/extension.ts:14:13: workspace.rootPath is deprecated: Use {@link workspace.workspaceFolders} instead."]
+    );
+}
@@ -0,0 +1,84 @@
+const OLD: &str = r#"declare module 'vscode' {
+    export interface Memento {
+        get<T>(key: string): T | undefined;
+        /** The keys. */
+        keys(): readonly string[];
+    }
+    export namespace window {
+        export function show(message: string): void;
+        export function showLegacy(message: string): void;
+    }
+}
+"#;
+
+const NEW: &str = r#"declare module 'vscode' {
+    export interface Memento {
+        get<T>(key: string): T | undefined;
+        get<T>(key: string, defaultValue: T): T;
+        /**
+         * @deprecated Use {@link Memento.entries}.
+         */
+        keys(): readonly string[];
+        entries(): [string, unknown][];
+    }
+    export namespace window {
+        export function show(message: string): void;
+    }
+    export class Range {
+        constructor(start: number, end: number);
+    }
+}
+"#;
+
+#[test]
+fn api_diff() {
+    let api_diff = gen::diff::diff(OLD, NEW, &gen::HostModule::default()).unwrap();
+    assert_eq!(
+        api_diff
+            .added
+            .iter()
+            .map(|item| item.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Memento.entries", "Range"]
+    );
+    assert_eq!(
+        api_diff
+            .removed
+            .iter()
+            .map(|item| item.path.as_str())
+            .collect::<Vec<_>>(),
+        vec!["window.showLegacy"]
+    );
+    assert_eq!(
+        api_diff.changed,
+        vec![gen::diff::Change {
+            path: "Memento.get".to_string(),
+            kind: "method".to_string(),
+            old: vec!["get<T>(key: string): T | undefined;".to_string()],
+            new: vec![
+                "get<T>(key: string): T | undefined;".to_string(),
+                "get<T>(key: string, defaultValue: T): T;".to_string()
+            ],
+        }]
+    );
+    assert_eq!(
+        api_diff.deprecated,
+        vec![gen::diff::Deprecation {
+            path: "Memento.keys".to_string(),
+            kind: "method".to_string(),
+            message: "Use {@link Memento.entries}.".to_string(),
+        }]
+    );
+
+    let markdown = api_diff.to_markdown();
+    assert!(markdown.contains("## Added\n\n- method `Memento.entries`\n"));
+    assert!(markdown
+        .contains("## Deprecated\n\n- method `Memento.keys`: Use {@link Memento.entries}.\n"));
+}
+
+#[test]
+fn no_changes() {
+    let api_diff = gen::diff::diff(OLD, OLD, &gen::HostModule::default()).unwrap();
+    assert!(api_diff.is_empty());
+    assert_eq!(api_diff.to_markdown(), "No changes to the extension API.\n");
+}
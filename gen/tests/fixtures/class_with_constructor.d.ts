@@ -0,0 +1,10 @@
+declare module 'vscode' {
+	export class Position {
+		readonly line: number;
+		readonly character: number;
+		constructor(line: number, character: number);
+		isBefore(other: Position): boolean;
+		static compare(a: Position, b: Position): number;
+		static readonly zero: Position;
+	}
+}
@@ -0,0 +1,64 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    readonly Position: {
+        new(line: number, character: number): Position;
+        compare(a: Position, b: Position): number;
+        readonly zero: Position;
+    };
+};
+type ValueOf<T> = T[keyof T];
+export type Position = {
+    readonly line: number;
+    readonly character: number;
+    isBefore(other: Position): boolean;
+};
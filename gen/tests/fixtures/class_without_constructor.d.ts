@@ -0,0 +1,15 @@
+declare module 'vscode' {
+	export class CancellationTokenSource {
+		token: CancellationToken;
+		cancel(): void;
+		dispose(): void;
+	}
+
+	export class Disposable {
+		dispose(): any;
+	}
+
+	export interface CancellationToken {
+		isCancellationRequested: boolean;
+	}
+}
@@ -0,0 +1,62 @@
+declare module 'vscode' {
+	/**
+	 * The version of the editor.
+	 */
+	export const version: string;
+
+	/**
+	 * A selection.
+	 */
+	export class Selection {
+		/**
+		 * Create a selection.
+		 *
+		 * @param anchor A position.
+		 */
+		constructor(anchor: number);
+
+		/**
+		 * The position at which the selection starts.
+		 */
+		anchor: number;
+
+		/**
+		 * Whether the selection is reversed.
+		 */
+		isReversed(): boolean;
+	}
+
+	/**
+	 * How an editor is shown.
+	 */
+	export enum TextEditorRevealType {
+		/**
+		 * The range will be revealed with as little scrolling as possible.
+		 */
+		Default = 0
+	}
+
+	/**
+	 * Namespace for dealing with the current window.
+	 */
+	export namespace window {
+		/**
+		 * Show an information message.
+		 *
+		 * @param message The message to show.
+		 */
+		export function showInformationMessage(message: string): Thenable<string | undefined>;
+	}
+
+	/**
+	 * Options for a message.
+	 */
+	export interface MessageOptions {
+		/**
+		 * Indicates that this message should be modal.
+		 */
+		modal?: boolean;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
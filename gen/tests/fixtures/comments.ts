@@ -0,0 +1,104 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    /**
+	 * The version of the editor.
+	 */ readonly version: string;
+    /**
+	 * A selection.
+	 */ readonly Selection: {
+        /**
+		 * Create a selection.
+		 *
+		 * @param anchor A position.
+		 */ new(anchor: number): Selection;
+    };
+    /**
+	 * How an editor is shown.
+	 */ readonly TextEditorRevealType: {
+        /**
+		 * The range will be revealed with as little scrolling as possible.
+		 */ readonly Default: 0;
+    };
+    /**
+	 * Namespace for dealing with the current window.
+	 */ readonly window: {
+        /**
+		 * Show an information message.
+		 *
+		 * @param message The message to show.
+		 */ showInformationMessage(message: string): Thenable<string | undefined>;
+    };
+};
+type ValueOf<T> = T[keyof T];
+/**
+	 * A selection.
+	 */ export type Selection = {
+    /**
+		 * The position at which the selection starts.
+		 */ anchor: number;
+    /**
+		 * Whether the selection is reversed.
+		 */ isReversed(): boolean;
+};
+/**
+	 * How an editor is shown.
+	 */ export type TextEditorRevealType = ValueOf<VSCodeAPI["TextEditorRevealType"]>;
+/**
+	 * Options for a message.
+	 */ export interface MessageOptions {
+    /**
+		 * Indicates that this message should be modal.
+		 */ modal?: boolean;
+}
+export interface Thenable<T> extends PromiseLike<T> {
+}
@@ -0,0 +1,17 @@
+declare module 'vscode' {
+	export enum ViewColumn {
+		Active = -1,
+		Beside = -2,
+		One = 1,
+		Two = 2
+	}
+
+	export enum EndOfLine {
+		LF = 1,
+		CRLF = 2
+	}
+
+	export interface TextDocument {
+		eol: EndOfLine;
+	}
+}
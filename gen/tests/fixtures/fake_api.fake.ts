@@ -0,0 +1,221 @@
+import type { VSCodeAPI } from "./mod.ts";
+
+/**
+ * A function of the fake API, which records its calls
+ */
+export type FakeFunction<F extends (...args: never) => unknown> = F & {
+  /**
+   * Arguments of every call, oldest first
+   */
+  readonly calls: Parameters<F>[];
+  /**
+   * What the function does. Returns `undefined` until it is replaced
+   */
+  implementation: F;
+};
+
+/**
+ * An event of the fake API, which the test fires
+ */
+export type FakeEvent<T> = ((
+  listener: (e: T) => unknown,
+  thisArgs?: unknown,
+  disposables?: { dispose(): unknown }[],
+) => { dispose(): unknown }) & {
+  fire(data: T): void;
+  readonly listenerCount: number;
+};
+
+/**
+ * An instance of a class of the fake API
+ */
+export type FakeInstance = {
+  readonly constructorArgs: readonly unknown[];
+};
+
+const fakeMark = Symbol("fake");
+
+/**
+ * The type of the function follows where it is put, so the whole fake is checked against the API
+ */
+function fakeFunction<F extends (...args: never) => unknown>(name: string): FakeFunction<F> {
+  const fake = Object.assign(
+    function (this: unknown, ...args: unknown[]): unknown {
+      fake.calls.push(args);
+      return fake.implementation.apply(this, args);
+    },
+    {
+      calls: [] as unknown[][],
+      implementation: (..._args: unknown[]): unknown => undefined,
+      [fakeMark]: name,
+    },
+  );
+  return fake as unknown as FakeFunction<F>;
+}
+
+function fakeEvent<T>(name: string): FakeEvent<T> {
+  const listeners = new Set<(e: T) => unknown>();
+  return Object.assign(
+    (
+      listener: (e: T) => unknown,
+      thisArgs?: unknown,
+      disposables?: { dispose(): unknown }[],
+    ) => {
+      const bound = (e: T) => listener.call(thisArgs, e);
+      listeners.add(bound);
+      const disposable = { dispose: () => listeners.delete(bound) };
+      disposables?.push(disposable);
+      return disposable;
+    },
+    {
+      fire: (data: T) => {
+        for (const listener of [...listeners]) {
+          listener(data);
+        }
+      },
+      get listenerCount() {
+        return listeners.size;
+      },
+      [fakeMark]: name,
+    },
+  );
+}
+
+/**
+ * A variable which the test sets. It is `undefined` until then, whatever its type
+ */
+function unsetVariable<T>(): T {
+  return undefined as T;
+}
+
+/**
+ * Methods are recording functions and events are fireable on every instance.
+ * `fire` of a class with an `event` fires it, like EventEmitter
+ */
+function fakeClass<C>(name: string, members: {
+  readonly methods: readonly string[];
+  readonly events: readonly string[];
+  readonly staticMethods: readonly string[];
+  readonly staticEvents: readonly string[];
+}): C {
+  const instances: unknown[] = [];
+  const fake = class {
+    static readonly [fakeMark] = name;
+    static readonly instances = instances;
+    readonly constructorArgs: readonly unknown[];
+    constructor(...args: unknown[]) {
+      this.constructorArgs = args;
+      const self = this as unknown as Record<string, unknown>;
+      for (const method of members.methods) {
+        self[method] = fakeFunction(`${name}.${method}`);
+      }
+      for (const event of members.events) {
+        self[event] = fakeEvent(`${name}.${event}`);
+      }
+      if (members.methods.includes("fire") && members.events.includes("event")) {
+        (self.fire as FakeFunction<(data: unknown) => unknown>).implementation = (data) =>
+          (self.event as FakeEvent<unknown>).fire(data);
+      }
+      instances.push(this);
+    }
+  };
+  for (const method of members.staticMethods) {
+    Object.assign(fake, { [method]: fakeFunction(`${name}.${method}`) });
+  }
+  for (const event of members.staticEvents) {
+    Object.assign(fake, { [event]: fakeEvent(`${name}.${event}`) });
+  }
+  return fake as unknown as C;
+}
+
+function assertFake(value: unknown): void {
+  if (
+    (typeof value !== "function" && typeof value !== "object") || value === null ||
+    !(fakeMark in value)
+  ) {
+    throw new Error("not a part of the API created by createFakeVSCodeAPI");
+  }
+}
+
+/**
+ * The recording function of the fake API behind `func`
+ *
+ * ```ts
+ * const api = createFakeVSCodeAPI();
+ * asFake(api.window.showInformationMessage).implementation = () => Promise.resolve("OK");
+ * ```
+ */
+export function asFake<F extends (...args: never) => unknown>(func: F): FakeFunction<F> {
+  assertFake(func);
+  return func as FakeFunction<F>;
+}
+
+/**
+ * The fireable emitter of the fake API behind `event`
+ */
+export function asFakeEvent<T>(
+  event: (listener: (e: T) => unknown, ...rest: never[]) => unknown,
+): FakeEvent<T> {
+  assertFake(event);
+  return event as unknown as FakeEvent<T>;
+}
+
+/**
+ * Instances constructed from a class of the fake API, oldest first
+ */
+export function fakeInstances<T>(
+  fakeClass: abstract new (...args: never) => T,
+): readonly (T & FakeInstance)[] {
+  assertFake(fakeClass);
+  return (fakeClass as unknown as { instances: (T & FakeInstance)[] }).instances;
+}
+
+/**
+ * A fake of the API for unit tests outside the host.
+ * Every call creates a new fake
+ */
+export function createFakeVSCodeAPI(): VSCodeAPI {
+  return {
+    version: unsetVariable(),
+    Disposable: fakeClass("Disposable", { methods: ["dispose"], events: [], staticMethods: ["from"], staticEvents: [] }),
+    EventEmitter: fakeClass("EventEmitter", { methods: ["fire", "dispose"], events: ["event"], staticMethods: [], staticEvents: [] }),
+    DiagnosticSeverity: {
+      Error: 0,
+      Warning: 1,
+      Information: 2,
+      Hint: 3,
+    },
+    ViewColumn: {
+      Active: -1,
+      Beside: -2,
+      One: 1,
+      Two: 2,
+    },
+    env: {
+      appName: unsetVariable(),
+      clipboard: {
+        readText: fakeFunction("env.clipboard.readText"),
+        writeText: fakeFunction("env.clipboard.writeText"),
+      },
+    },
+    window: {
+      activeColumn: undefined,
+      tabGroups: {
+        activeTabGroup: {
+          isActive: unsetVariable(),
+          viewColumn: unsetVariable(),
+          activeTab: undefined,
+        },
+        onDidChangeTabGroups: fakeEvent("window.tabGroups.onDidChangeTabGroups"),
+        close: fakeFunction("window.tabGroups.close"),
+      },
+      watcher: {
+        onDidCreate: fakeEvent("window.watcher.onDidCreate"),
+        filter: fakeFunction("window.watcher.filter"),
+        dispose: fakeFunction("window.watcher.dispose"),
+      },
+      onDidChangeActiveColumn: fakeEvent("window.onDidChangeActiveColumn"),
+      showInformationMessage: fakeFunction("window.showInformationMessage"),
+    },
+  } satisfies VSCodeAPI;
+}
@@ -0,0 +1,121 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    readonly version: string;
+    readonly Disposable: {
+        from(...disposableLikes: {
+            dispose: () => any;
+        }[]): Disposable;
+        new(callOnDispose: () => any): Disposable;
+    };
+    readonly EventEmitter: {
+        new <T>(): EventEmitter<T>;
+    };
+    readonly DiagnosticSeverity: {
+        readonly Error: 0;
+        readonly Warning: 1;
+        readonly Information: 2;
+        readonly Hint: 3;
+    };
+    readonly ViewColumn: {
+        readonly Active: -1;
+        readonly Beside: -2;
+        readonly One: 1;
+        readonly Two: 2;
+    };
+    readonly env: {
+        readonly appName: string;
+        readonly clipboard: Clipboard;
+    };
+    readonly window: {
+        activeColumn: ViewColumn | undefined;
+        readonly tabGroups: TabGroups;
+        readonly watcher: FileSystemWatcher;
+        readonly onDidChangeActiveColumn: Event<ViewColumn | undefined>;
+        showInformationMessage(message: string): Thenable<string | undefined>;
+        showInformationMessage(message: string, ...items: string[]): Thenable<string | undefined>;
+    };
+};
+type ValueOf<T> = T[keyof T];
+export type Disposable = {
+    dispose(): any;
+};
+export type EventEmitter<T> = {
+    event: Event<T>;
+    fire(data: T): void;
+    dispose(): void;
+};
+export interface Event<T> {
+    (listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]) : Disposable;
+}
+export type DiagnosticSeverity = ValueOf<VSCodeAPI["DiagnosticSeverity"]>;
+export type ViewColumn = ValueOf<VSCodeAPI["ViewColumn"]>;
+export interface Clipboard {
+    readText(): Thenable<string>;
+    writeText(value: string): Thenable<void>;
+}
+export interface TabGroup {
+    readonly isActive: boolean;
+    readonly viewColumn: ViewColumn;
+    readonly activeTab?: string;
+}
+export interface TabGroups {
+    readonly activeTabGroup: TabGroup;
+    readonly onDidChangeTabGroups: Event<TabGroup[]>;
+    close(group: TabGroup): Thenable<boolean>;
+}
+export interface FileSystemWatcher extends Disposable {
+    readonly onDidCreate: Event<string>;
+    readonly filter: (path: string) => boolean;
+}
+export interface Thenable<T> extends PromiseLike<T> {
+}
@@ -0,0 +1,23 @@
+declare module 'vscode' {
+	export class EventEmitter<T> {
+		event: Event<T>;
+		fire(data: T): void;
+	}
+
+	export interface Event<T> {
+		(listener: (e: T) => any, thisArgs?: any): Disposable;
+	}
+
+	export interface Disposable {
+		dispose(): any;
+	}
+
+	export type ProviderResult<T> = T | undefined | null | Thenable<T | undefined | null>;
+
+	export class TreeItem<T extends string = string> {
+		constructor(label: T);
+		label: T;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
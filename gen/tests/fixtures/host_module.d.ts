@@ -0,0 +1,26 @@
+// args: --module-name=azdata --type-name=AzdataAPI --import-function=importAzdata --loader=require,inject --promise-facade
+declare module 'vscode' {
+	export const version: string;
+}
+
+declare module 'azdata' {
+	/**
+	 * The version of Azure Data Studio
+	 */
+	export const version: string;
+
+	export enum ConnectionOptionSpecialType {
+		connectionName = 0,
+		serverName = 1,
+	}
+
+	export namespace connection {
+		export function getCurrentConnection(): Thenable<ConnectionProfile | undefined>;
+	}
+
+	export interface ConnectionProfile {
+		serverName: string;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
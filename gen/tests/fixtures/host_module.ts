@@ -0,0 +1,156 @@
+declare const require: undefined | ((path: "azdata") => AzdataAPI);
+let injectedModule: AzdataAPI | undefined = undefined;
+/**
+ * Make {@link importAzdata} return `api`, like a fake in unit tests.
+ * `undefined` takes it back
+ */ export function setAzdata(api: AzdataAPI | undefined): void {
+    injectedModule = api;
+}
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => AzdataAPI | undefined;
+}> = [
+    {
+        name: "require(\"azdata\")",
+        load: (): AzdataAPI | undefined =>typeof require === "function" ? require("azdata") : undefined
+    },
+    {
+        name: "setAzdata(api)",
+        load: (): AzdataAPI | undefined =>injectedModule
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): AzdataAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import azdata API
+ *
+ * ```ts
+ * require("azdata")
+ * setAzdata(api)
+ * ```
+ *
+ * Returns AzdataAPI only within the host which provides azdata.
+ */ export function importAzdata(): AzdataAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importAzdata}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importAzdataOrThrow(): AzdataAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import azdata, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type AzdataAPI = {
+    /**
+	 * The version of Azure Data Studio
+	 */ readonly version: string;
+    readonly ConnectionOptionSpecialType: {
+        readonly connectionName: 0;
+        readonly serverName: 1;
+    };
+    readonly connection: {
+        getCurrentConnection(): Thenable<ConnectionProfile | undefined>;
+    };
+};
+type ValueOf<T> = T[keyof T];
+/**
+ * AzdataAPI whose functions return Promise instead of Thenable
+ */ export type AzdataAPIPromises = Omit<AzdataAPI, "connection"> & {
+    readonly connection: Omit<AzdataAPI["connection"], "getCurrentConnection"> & {
+        getCurrentConnection(): Promise<ConnectionProfile | undefined>;
+    };
+};
+const promisesProxies = new WeakMap<object, object>();
+const promisesTargets = new WeakMap<object, object>();
+const promisesCallbacks = new WeakMap<object, object>();
+/**
+ * A Proxy of `value` whose calls return Promise instead of Thenable,
+ * and whose properties and results are wrapped in the same way
+ */ function toPromises(value: unknown): unknown {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null) {
+        return value;
+    }
+    if (promisesTargets.has(value)) {
+        return value;
+    }
+    const cached = promisesProxies.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const target = value;
+    // the API object is frozen, and a trap of a frozen target has to give its own properties
+    const shadow = typeof target === "function" ? function() {} : Array.isArray(target) ? [] : Object.create(Object.getPrototypeOf(target));
+    const proxy = new Proxy(shadow, {
+        get: (_, name)=>{
+            const member = Reflect.get(target, name, target);
+            return name === "prototype" || name === "constructor" ? member : toPromises(member);
+        },
+        set: (_, name, newValue)=>Reflect.set(target, name, fromPromises(newValue), target),
+        has: (_, name)=>Reflect.has(target, name),
+        apply: (_, thisArg, args)=>resultToPromises(Reflect.apply(target as (...args: unknown[]) => unknown, fromPromises(thisArg), args.map(fromPromises))),
+        construct: (_, args)=>toPromises(Reflect.construct(target as new(...args: unknown[]) => object, args.map(fromPromises))) as object
+    });
+    promisesProxies.set(target, proxy);
+    promisesTargets.set(proxy, target);
+    return proxy;
+}
+function resultToPromises(result: unknown): unknown {
+    return typeof (result as PromiseLike<unknown> | null | undefined)?.then === "function" ? Promise.resolve(result).then(toPromises) : toPromises(result);
+}
+/**
+ * The original of a value wrapped by {@link toPromises}.
+ * A callback, like a listener of an event, gets its arguments wrapped
+ */ function fromPromises(value: unknown): unknown {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null) {
+        return value;
+    }
+    const target = promisesTargets.get(value);
+    if (target !== undefined) {
+        return target;
+    }
+    if (typeof value !== "function") {
+        return value;
+    }
+    const cached = promisesCallbacks.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const callback = function(this: unknown, ...args: unknown[]): unknown {
+        return fromPromises(Reflect.apply(value, this, args.map(toPromises)));
+    };
+    promisesCallbacks.set(value, callback);
+    return callback;
+}
+/**
+ * {@link importAzdata} whose functions return Promise instead of Thenable
+ */ export function importAzdataPromises(): AzdataAPIPromises | undefined {
+    const api = importAzdata();
+    return api === undefined ? undefined : toPromises(api) as AzdataAPIPromises;
+}
+export type ConnectionOptionSpecialType = ValueOf<AzdataAPI["ConnectionOptionSpecialType"]>;
+export interface ConnectionProfile {
+    serverName: string;
+}
+export interface Thenable<T> extends PromiseLike<T> {
+}
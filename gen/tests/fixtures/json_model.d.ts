@@ -0,0 +1,54 @@
+// args: --emit-json
+declare module 'vscode' {
+	/**
+	 * A position in a document.
+	 *
+	 * @see {@link Range}
+	 */
+	export class Position {
+		/**
+		 * @param line A zero-based line value.
+		 * @param character A zero-based character value.
+		 */
+		constructor(line: number, character: number);
+
+		readonly line: number;
+
+		/**
+		 * Derive a new position.
+		 *
+		 * @deprecated Use {@link Position.with} instead.
+		 * @returns A position.
+		 */
+		translate(lineDelta?: number, ...rest: number[]): Position;
+
+		static readonly origin: Position;
+	}
+
+	export enum Kind {
+		Text = 1,
+		Back = -1,
+	}
+
+	export interface Options<T extends string = string> extends Partial<Record<T, boolean>> {
+		/** The mode. */
+		mode?: 'fast' | 'slow' | 2;
+		readonly [key: string]: unknown;
+		(value: T): void;
+		tuple: [first: string, second?: number, ...rest: boolean[]];
+		mapped: { readonly [K in T]?: K };
+		pick: T extends `${infer U}` ? keyof U : never;
+		nested: {
+			/** Nested doc. */
+			inner: Array<T>[0] | null;
+		};
+	}
+
+	export type Handler = (this: void, e: typeof Position) => e is never;
+
+	export namespace window {
+		export function show<T>(message: string, callback: (value: T) => Thenable<T>): Thenable<T | undefined>;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
@@ -0,0 +1,102 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    /**
+	 * A position in a document.
+	 *
+	 * @see {@link Range}
+	 */ readonly Position: {
+        /**
+		 * @param line A zero-based line value.
+		 * @param character A zero-based character value.
+		 */ new(line: number, character: number): Position;
+        readonly origin: Position;
+    };
+    readonly Kind: {
+        readonly Text: 1;
+        readonly Back: -1;
+    };
+    readonly window: {
+        show<T>(message: string, callback: (value: T) => Thenable<T>): Thenable<T | undefined>;
+    };
+};
+type ValueOf<T> = T[keyof T];
+/**
+	 * A position in a document.
+	 *
+	 * @see {@link Range}
+	 */ export type Position = {
+    readonly line: number;
+    /**
+		 * Derive a new position.
+		 *
+		 * @deprecated Use {@link Position.with} instead.
+		 * @returns A position.
+		 */ translate(lineDelta?: number, ...rest: number[]): Position;
+};
+export type Kind = ValueOf<VSCodeAPI["Kind"]>;
+export interface Options<T extends string = string> extends Partial<Record<T, boolean>> {
+    /** The mode. */ mode?: 'fast' | 'slow' | 2;
+    readonly [key: string]: unknown;
+    (value: T) : void;
+    tuple: [first: string, second?: number, ...rest: boolean[]];
+    mapped: {
+        readonly [K in T]?: K;
+    };
+    pick: T extends `${infer U}` ? keyof U : never;
+    nested: {
+        /** Nested doc. */ inner: Array<T>[0] | null;
+    };
+}
+export type Handler = (this: void, e: typeof Position) => e is never;
+export interface Thenable<T> extends PromiseLike<T> {
+}
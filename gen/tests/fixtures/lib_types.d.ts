@@ -0,0 +1,16 @@
+declare module 'vscode' {
+	export class SemanticTokens {
+		readonly resultId: string | undefined;
+		readonly data: Uint32Array;
+		constructor(data: Uint32Array, resultId?: string);
+	}
+
+	export interface Buffers {
+		readonly floats: Float64Array;
+		readonly bigInts: BigInt64Array;
+		readonly shared: SharedArrayBuffer;
+		readonly view: DataView;
+		readonly format: Intl.DateTimeFormat;
+		readonly settled: PromiseSettledResult<number>;
+	}
+}
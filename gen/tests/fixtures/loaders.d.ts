@@ -0,0 +1,8 @@
+// args: --loader=inject,require,global-this-require,create-require
+declare module 'vscode' {
+	export const version: string;
+
+	export namespace window {
+		export function showInformationMessage(message: string): void;
+	}
+}
@@ -0,0 +1,96 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+let injectedModule: VSCodeAPI | undefined = undefined;
+/**
+ * Make {@link importVsCodeApi} return `api`, like a fake in unit tests.
+ * `undefined` takes it back
+ */ export function setVsCodeApi(api: VSCodeAPI | undefined): void {
+    injectedModule = api;
+}
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "setVsCodeApi(api)",
+        load: (): VSCodeAPI | undefined =>injectedModule
+    },
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    },
+    {
+        name: "globalThis.require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>{
+            const requireFunc = (globalThis as {
+                readonly require?: unknown;
+            }).require;
+            return typeof requireFunc === "function" ? requireFunc("vscode") : undefined;
+        }
+    },
+    {
+        name: "module.createRequire(...)(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>{
+            const process = (globalThis as {
+                readonly process?: {
+                    readonly cwd: () => string;
+                    readonly getBuiltinModule?: (id: string) => unknown;
+                };
+            }).process;
+            const nodeModule = process?.getBuiltinModule?.("node:module") as {
+                readonly createRequire?: (path: string) => (id: string) => unknown;
+            } | undefined;
+            return typeof nodeModule?.createRequire === "function" ? nodeModule.createRequire(`${process!.cwd()}/`)("vscode") as VSCodeAPI : undefined;
+        }
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * setVsCodeApi(api)
+ * require("vscode")
+ * globalThis.require("vscode")
+ * module.createRequire(...)("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    readonly version: string;
+    readonly window: {
+        showInformationMessage(message: string): void;
+    };
+};
+type ValueOf<T> = T[keyof T];
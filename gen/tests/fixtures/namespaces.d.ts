@@ -0,0 +1,18 @@
+declare module 'vscode' {
+	export const version: string;
+
+	export namespace env {
+		export const appName: string;
+		export let logLevel: number;
+		export function openExternal(target: string): Thenable<boolean>;
+	}
+
+	export namespace window {
+		export function showErrorMessage(message: string): Thenable<string | undefined>;
+		export namespace tabGroups {
+			export function close(tab: string): Thenable<boolean>;
+		}
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
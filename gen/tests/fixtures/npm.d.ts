@@ -0,0 +1,43 @@
+// args: --npm --trace --promise-facade --loader=require,create-require,inject
+declare module 'vscode' {
+	export const version: string;
+
+	export class Disposable {
+		static from(...disposableLikes: { dispose: () => any }[]): Disposable;
+		constructor(callOnDispose: () => any);
+		dispose(): any;
+	}
+
+	export class EventEmitter<T> {
+		event: Event<T>;
+		fire(data: T): void;
+		dispose(): void;
+	}
+
+	export interface Event<T> {
+		(listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]): Disposable;
+	}
+
+	export enum DiagnosticSeverity {
+		Error = 0,
+		Warning = 1,
+		Information = 2,
+		Hint = 3
+	}
+
+	export enum ViewColumn {
+		Active = -1,
+		Beside = -2,
+		One = 1,
+		Two = 2
+	}
+
+	export namespace window {
+		export let activeColumn: ViewColumn | undefined;
+		export const onDidChangeActiveColumn: Event<ViewColumn | undefined>;
+		export function showInformationMessage(message: string): Thenable<string | undefined>;
+		export function showInformationMessage(message: string, ...items: string[]): Thenable<string | undefined>;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
@@ -0,0 +1,298 @@
+"use strict";
+let injectedModule = undefined;
+function setVsCodeApi(api) {
+    injectedModule = api;
+}
+const moduleLoaders = [
+    {
+        name: "require(\"vscode\")",
+        load: ()=>typeof require === "function" ? require("vscode") : undefined
+    },
+    {
+        name: "module.createRequire(...)(\"vscode\")",
+        load: ()=>{
+            const process = (globalThis).process;
+            const nodeModule = process?.getBuiltinModule?.("node:module");
+            return typeof nodeModule?.createRequire === "function" ? nodeModule.createRequire(`${process.cwd()}/`)("vscode") : undefined;
+        }
+    },
+    {
+        name: "setVsCodeApi(api)",
+        load: ()=>injectedModule
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures) {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+function importVsCodeApi() {
+    return loadModule([]);
+}
+function importVsCodeApiOrThrow() {
+    const failures = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+const promisesProxies = new WeakMap();
+const promisesTargets = new WeakMap();
+const promisesCallbacks = new WeakMap();
+/**
+ * A Proxy of `value` whose calls return Promise instead of Thenable,
+ * and whose properties and results are wrapped in the same way
+ */ function toPromises(value) {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null) {
+        return value;
+    }
+    if (promisesTargets.has(value)) {
+        return value;
+    }
+    const cached = promisesProxies.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const target = value;
+    // the API object is frozen, and a trap of a frozen target has to give its own properties
+    const shadow = typeof target === "function" ? function() {} : Array.isArray(target) ? [] : Object.create(Object.getPrototypeOf(target));
+    const proxy = new Proxy(shadow, {
+        get: (_, name)=>{
+            const member = Reflect.get(target, name, target);
+            return name === "prototype" || name === "constructor" ? member : toPromises(member);
+        },
+        set: (_, name, newValue)=>Reflect.set(target, name, fromPromises(newValue), target),
+        has: (_, name)=>Reflect.has(target, name),
+        apply: (_, thisArg, args)=>resultToPromises(Reflect.apply(target, fromPromises(thisArg), args.map(fromPromises))),
+        construct: (_, args)=>toPromises(Reflect.construct(target, args.map(fromPromises)))
+    });
+    promisesProxies.set(target, proxy);
+    promisesTargets.set(proxy, target);
+    return proxy;
+}
+function resultToPromises(result) {
+    return typeof (result)?.then === "function" ? Promise.resolve(result).then(toPromises) : toPromises(result);
+}
+/**
+ * The original of a value wrapped by {@link toPromises}.
+ * A callback, like a listener of an event, gets its arguments wrapped
+ */ function fromPromises(value) {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null) {
+        return value;
+    }
+    const target = promisesTargets.get(value);
+    if (target !== undefined) {
+        return target;
+    }
+    if (typeof value !== "function") {
+        return value;
+    }
+    const cached = promisesCallbacks.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const callback = function(...args) {
+        return fromPromises(Reflect.apply(value, this, args.map(toPromises)));
+    };
+    promisesCallbacks.set(value, callback);
+    return callback;
+}
+function importVsCodeApiPromises() {
+    const api = importVsCodeApi();
+    return api === undefined ? undefined : toPromises(api);
+}
+const moduleTraceShape = {
+    Disposable: {
+        staticMethods: [
+            "from"
+        ]
+    },
+    EventEmitter: {
+        staticMethods: []
+    },
+    window: {
+        onDidChangeActiveColumn: "event",
+        showInformationMessage: "function"
+    }
+};
+/**
+ * What to trace on the instances of the classes and interfaces, by their names
+ */ const instanceTraceShapes = {
+    Disposable: {
+        dispose: "function"
+    },
+    EventEmitter: {
+        event: "event",
+        fire: "function",
+        dispose: "function"
+    }
+};
+/**
+ * The instances behind the traced Proxies
+ */ const untracedInstances = new WeakMap();
+/**
+ * The instance behind a traced Proxy, as the API is given its own instances
+ */ function untraced(value) {
+    return (typeof value === "object" || typeof value === "function") && value !== null ? untracedInstances.get(value) ?? value : value;
+}
+function traceFunction(func, path, resultShape, tracer) {
+    return function(...args) {
+        let result;
+        try {
+            result = func.apply(untraced(this), args.map(untraced));
+        } catch (error) {
+            tracer.sink({
+                kind: "throw",
+                path,
+                args,
+                error
+            });
+            throw error;
+        }
+        tracer.sink({
+            kind: "call",
+            path,
+            args,
+            result
+        });
+        const then = (result)?.then;
+        if (typeof then === "function") {
+            const start = performance.now();
+            then.call(result, (value)=>tracer.sink({
+                    kind: "settle",
+                    path,
+                    args,
+                    status: "fulfilled",
+                    value,
+                    durationMs: performance.now() - start
+                }), (reason)=>tracer.sink({
+                    kind: "settle",
+                    path,
+                    args,
+                    status: "rejected",
+                    value: reason,
+                    durationMs: performance.now() - start
+                }));
+            if (resultShape !== undefined && "resolves" in resultShape) {
+                const type = resultShape.resolves;
+                return then.call(result, (value)=>traceInstance(value, type, tracer));
+            }
+        }
+        return resultShape !== undefined && "returns" in resultShape ? traceInstance(result, resultShape.returns, tracer) : result;
+    };
+}
+function traceEvent(event, path, tracer) {
+    return function(...args) {
+        tracer.sink({
+            kind: "subscribe",
+            path
+        });
+        return event.apply(untraced(this), args);
+    };
+}
+function traceClass(constructor, path, staticMethods, tracer) {
+    return new Proxy(constructor, {
+        construct (target, args, newTarget) {
+            const instance = Reflect.construct(target, args.map(untraced), newTarget);
+            tracer.sink({
+                kind: "construct",
+                path,
+                args,
+                instance
+            });
+            return traceInstance(instance, path, tracer);
+        },
+        get (target, name, receiver) {
+            const value = Reflect.get(target, name, receiver);
+            return typeof name === "string" && staticMethods.includes(name) && typeof value === "function" ? traceFunction(value, `${path}.${name}`, undefined, tracer) : value;
+        }
+    });
+}
+/**
+ * The member `name` of `object` traced by `shape`.
+ * The same traced value is returned while the member is the same
+ */ function traceMember(object, name, path, shape, traced, tracer) {
+    const value = Reflect.get(object, name, object);
+    const member = typeof name === "string" && Object.hasOwn(shape, name) ? shape[name] : undefined;
+    if (typeof name !== "string" || member === undefined || value === undefined || value === null) {
+        return value;
+    }
+    const cached = traced.get(name);
+    if (cached !== undefined && cached.value === value) {
+        return cached.traced;
+    }
+    const memberPath = path === "" ? name : `${path}.${name}`;
+    const tracedValue = member === "function" ? traceFunction(value, memberPath, undefined, tracer) : member === "event" ? traceEvent(value, memberPath, tracer) : ("returns" in member && typeof member.returns === "string") || ("resolves" in member && typeof member.resolves === "string") ? traceFunction(value, memberPath, member, tracer) : "instance" in member && typeof member.instance === "string" ? traceInstance(value, member.instance, tracer) : "staticMethods" in member && Array.isArray(member.staticMethods) ? traceClass(value, memberPath, member.staticMethods, tracer) : traceNamespace(value, memberPath, member, tracer);
+    traced.set(name, {
+        value,
+        traced: tracedValue
+    });
+    return tracedValue;
+}
+/**
+ * The namespace is read when a member is, so variables like `window.activeTextEditor` stay current.
+ * It may be frozen, and a trap of a frozen target has to give its own properties,
+ * so the Proxy is of an empty object with the same prototype.
+ * The keys and descriptors come from the namespace, as configurable as the empty object has none of them,
+ * so that `Object.keys` and spreading see its members
+ */ function traceNamespace(namespace, path, shape, tracer) {
+    const traced = new Map();
+    return new Proxy(Object.create(Object.getPrototypeOf(namespace)), {
+        get: (_, name)=>traceMember(namespace, name, path, shape, traced, tracer),
+        set: (_, name, newValue)=>Reflect.set(namespace, name, untraced(newValue), namespace),
+        has: (_, name)=>Reflect.has(namespace, name),
+        ownKeys: ()=>Reflect.ownKeys(namespace),
+        getOwnPropertyDescriptor: (_, name)=>{
+            const descriptor = Reflect.getOwnPropertyDescriptor(namespace, name);
+            if (descriptor === undefined) {
+                return undefined;
+            }
+            return "value" in descriptor ? {
+                ...descriptor,
+                value: traceMember(namespace, name, path, shape, traced, tracer),
+                configurable: true
+            } : {
+                ...descriptor,
+                configurable: true
+            };
+        }
+    });
+}
+/**
+ * A Proxy of an instance of the class or interface `type`, whose paths start with `type`
+ */ function traceInstance(value, type, tracer) {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null || !Object.hasOwn(instanceTraceShapes, type)) {
+        return value;
+    }
+    const cached = tracer.instances.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const proxy = traceNamespace(value, type, instanceTraceShapes[type], tracer);
+    tracer.instances.set(value, proxy);
+    untracedInstances.set(proxy, value);
+    return proxy;
+}
+function traceVSCodeAPI(api, sink) {
+    return traceNamespace(api, "", moduleTraceShape, {
+        sink,
+        instances: new WeakMap()
+    });
+}
+exports.setVsCodeApi = setVsCodeApi;
+exports.importVsCodeApi = importVsCodeApi;
+exports.importVsCodeApiOrThrow = importVsCodeApiOrThrow;
+exports.importVsCodeApiPromises = importVsCodeApiPromises;
+exports.traceVSCodeAPI = traceVSCodeAPI;
@@ -0,0 +1,140 @@
+/**
+ * Make {@link importVsCodeApi} return `api`, like a fake in unit tests.
+ * `undefined` takes it back
+ */ export declare function setVsCodeApi(api: VSCodeAPI | undefined): void;
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * module.createRequire(...)("vscode")
+ * setVsCodeApi(api)
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export declare function importVsCodeApi(): VSCodeAPI | undefined;
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export declare function importVsCodeApiOrThrow(): VSCodeAPI;
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    readonly version: string;
+    readonly Disposable: {
+        from(...disposableLikes: {
+            dispose: () => any;
+        }[]): Disposable;
+        new(callOnDispose: () => any): Disposable;
+    };
+    readonly EventEmitter: {
+        new <T>(): EventEmitter<T>;
+    };
+    readonly DiagnosticSeverity: {
+        readonly Error: 0;
+        readonly Warning: 1;
+        readonly Information: 2;
+        readonly Hint: 3;
+    };
+    readonly ViewColumn: {
+        readonly Active: -1;
+        readonly Beside: -2;
+        readonly One: 1;
+        readonly Two: 2;
+    };
+    readonly window: {
+        activeColumn: ViewColumn | undefined;
+        readonly onDidChangeActiveColumn: Event<ViewColumn | undefined>;
+        showInformationMessage(message: string): Thenable<string | undefined>;
+        showInformationMessage(message: string, ...items: string[]): Thenable<string | undefined>;
+    };
+};
+type ValueOf<T> = T[keyof T];
+/**
+ * VSCodeAPI whose functions return Promise instead of Thenable
+ */ export type VSCodeAPIPromises = Omit<VSCodeAPI, "window"> & {
+    readonly window: Omit<VSCodeAPI["window"], "showInformationMessage"> & {
+        showInformationMessage(message: string): Promise<string | undefined>;
+        showInformationMessage(message: string, ...items: string[]): Promise<string | undefined>;
+    };
+};
+/**
+ * {@link importVsCodeApi} whose functions return Promise instead of Thenable
+ */ export declare function importVsCodeApiPromises(): VSCodeAPIPromises | undefined;
+/**
+ * What {@link traceVSCodeAPI} saw. `path` is like `window.showInformationMessage`,
+ * or `TextEditor.edit` for a member of an instance
+ */ export type VSCodeAPITraceEvent = {
+    readonly kind: "call";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly result: unknown;
+} | {
+    readonly kind: "throw";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly error: unknown;
+} | {
+    readonly kind: "construct";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly instance: unknown;
+} | {
+    readonly kind: "subscribe";
+    readonly path: string;
+} | {
+    readonly kind: "settle";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly status: "fulfilled" | "rejected";
+    readonly value: unknown;
+    readonly durationMs: number;
+};
+/**
+ * A function whose result, or what its Thenable resolves to, is an instance of a traced type
+ */ type ResultTraceShape = {
+    readonly returns: string;
+} | {
+    readonly resolves: string;
+};
+type TraceShape = {
+    readonly [name: string]: "function" | "event" | ResultTraceShape | {
+        readonly instance: string;
+    } | {
+        readonly staticMethods: readonly string[];
+    } | TraceShape;
+};
+type Tracer = {
+    readonly sink: (event: VSCodeAPITraceEvent) => void;
+    /**
+   * The traced Proxy of each instance, so that an instance stays one object
+   */ readonly instances: WeakMap<object, object>;
+};
+/**
+ * Wrap the API to pass every function call, constructor invocation and event subscription to `sink`,
+ * with the arguments and the result.
+ * When a call returns a Thenable, a `settle` event follows with how long it took.
+ * The methods and events of the instances which calls, constructors and variables give
+ * are traced too, and the instances are unwrapped when they are passed back to the API.
+ * The instances which the API passes to listeners are not traced.
+ *
+ * ```ts
+ * const vscode = traceVSCodeAPI(importVsCodeApi()!, (event) => console.log(event));
+ * ```
+ */ export declare function traceVSCodeAPI(api: VSCodeAPI, sink: (event: VSCodeAPITraceEvent) => void): VSCodeAPI;
+export type Disposable = {
+    dispose(): any;
+};
+export type EventEmitter<T> = {
+    event: Event<T>;
+    fire(data: T): void;
+    dispose(): void;
+};
+export interface Event<T> {
+    (listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]) : Disposable;
+}
+export type DiagnosticSeverity = ValueOf<VSCodeAPI["DiagnosticSeverity"]>;
+export type ViewColumn = ValueOf<VSCodeAPI["ViewColumn"]>;
+export interface Thenable<T> extends PromiseLike<T> {
+}
@@ -0,0 +1,2 @@
+import commonJs from "./index.cjs";
+export const { setVsCodeApi, importVsCodeApi, importVsCodeApiOrThrow, importVsCodeApiPromises, traceVSCodeAPI } = commonJs;
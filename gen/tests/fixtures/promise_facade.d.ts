@@ -0,0 +1,62 @@
+// args: --promise-facade
+declare module 'vscode' {
+	export const version: string;
+
+	export class Disposable {
+		constructor(callOnDispose: () => any);
+		dispose(): any;
+	}
+
+	export interface TextDocument {
+		readonly fileName: string;
+		/**
+		 * Save the underlying file.
+		 */
+		save(): Thenable<boolean>;
+	}
+
+	export interface TextEditor {
+		readonly document: TextDocument;
+		edit(callback: (editBuilder: string) => void): Thenable<boolean>;
+		edit(callback: (editBuilder: string) => void, options: { readonly undoStopBefore: boolean }): Thenable<boolean>;
+		hide(): void;
+	}
+
+	export interface NotebookEditor extends TextEditor {
+		readonly notebookType: string;
+	}
+
+	export class Memento<T> {
+		get(key: string): T | undefined;
+		update(key: string, value: T): Thenable<void>;
+	}
+
+	export interface Event<T> {
+		(listener: (e: T) => any): Disposable;
+	}
+
+	export namespace env {
+		export const appName: string;
+		export let logLevel: number;
+		export function openExternal(target: string): Thenable<boolean>;
+	}
+
+	export namespace window {
+		/**
+		 * The currently active editor.
+		 */
+		export let activeTextEditor: TextEditor | undefined;
+		export const onDidChangeActiveTextEditor: Event<TextEditor | undefined>;
+		export function showErrorMessage(message: string): Thenable<string | undefined>;
+		export function showTextDocument(document: TextDocument): Thenable<TextEditor>;
+		export namespace tabGroups {
+			export function close(tab: string): Thenable<boolean>;
+		}
+	}
+
+	export module 'extensions' {
+		export function activate(): Thenable<void>;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
@@ -0,0 +1,221 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    readonly version: string;
+    readonly Disposable: {
+        new(callOnDispose: () => any): Disposable;
+    };
+    readonly Memento: {
+        new <T>(): Memento<T>;
+    };
+    readonly env: {
+        readonly appName: string;
+        logLevel: number;
+        openExternal(target: string): Thenable<boolean>;
+    };
+    readonly window: {
+        /**
+		 * The currently active editor.
+		 */ activeTextEditor: TextEditor | undefined;
+        readonly onDidChangeActiveTextEditor: Event<TextEditor | undefined>;
+        showErrorMessage(message: string): Thenable<string | undefined>;
+        showTextDocument(document: TextDocument): Thenable<TextEditor>;
+        readonly tabGroups: {
+            close(tab: string): Thenable<boolean>;
+        };
+    };
+    readonly "extensions": {
+        activate(): Thenable<void>;
+    };
+};
+type ValueOf<T> = T[keyof T];
+/**
+ * VSCodeAPI whose functions return Promise instead of Thenable
+ */ export type VSCodeAPIPromises = Omit<VSCodeAPI, "env" | "window" | "extensions"> & {
+    readonly env: Omit<VSCodeAPI["env"], "openExternal"> & {
+        openExternal(target: string): Promise<boolean>;
+    };
+    readonly window: Omit<VSCodeAPI["window"], "activeTextEditor" | "onDidChangeActiveTextEditor" | "showErrorMessage" | "showTextDocument" | "tabGroups"> & {
+        /**
+		 * The currently active editor.
+		 */ activeTextEditor: TextEditorPromises | undefined;
+        readonly onDidChangeActiveTextEditor: Event<TextEditorPromises | undefined>;
+        showErrorMessage(message: string): Promise<string | undefined>;
+        showTextDocument(document: TextDocument): Promise<TextEditorPromises>;
+        readonly tabGroups: Omit<VSCodeAPI["window"]["tabGroups"], "close"> & {
+            close(tab: string): Promise<boolean>;
+        };
+    };
+    readonly 'extensions': Omit<VSCodeAPI["extensions"], "activate"> & {
+        activate(): Promise<void>;
+    };
+};
+/**
+ * {@link TextDocument} whose methods return Promise instead of Thenable
+ */ export type TextDocumentPromises = Omit<TextDocument, "save"> & {
+    /**
+		 * Save the underlying file.
+		 */ save(): Promise<boolean>;
+};
+/**
+ * {@link TextEditor} whose methods return Promise instead of Thenable
+ */ export type TextEditorPromises = Omit<TextEditor, "document" | "edit"> & {
+    readonly document: TextDocumentPromises;
+    edit(callback: (editBuilder: string) => void): Promise<boolean>;
+    edit(callback: (editBuilder: string) => void, options: {
+        readonly undoStopBefore: boolean;
+    }): Promise<boolean>;
+};
+/**
+ * {@link Memento} whose methods return Promise instead of Thenable
+ */ export type MementoPromises<T> = Omit<Memento<T>, "update"> & {
+    update(key: string, value: T): Promise<void>;
+};
+/**
+ * {@link NotebookEditor} whose methods return Promise instead of Thenable
+ */ export type NotebookEditorPromises = Omit<NotebookEditor, keyof TextEditor> & TextEditorPromises;
+const promisesProxies = new WeakMap<object, object>();
+const promisesTargets = new WeakMap<object, object>();
+const promisesCallbacks = new WeakMap<object, object>();
+/**
+ * A Proxy of `value` whose calls return Promise instead of Thenable,
+ * and whose properties and results are wrapped in the same way
+ */ function toPromises(value: unknown): unknown {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null) {
+        return value;
+    }
+    if (promisesTargets.has(value)) {
+        return value;
+    }
+    const cached = promisesProxies.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const target = value;
+    // the API object is frozen, and a trap of a frozen target has to give its own properties
+    const shadow = typeof target === "function" ? function() {} : Array.isArray(target) ? [] : Object.create(Object.getPrototypeOf(target));
+    const proxy = new Proxy(shadow, {
+        get: (_, name)=>{
+            const member = Reflect.get(target, name, target);
+            return name === "prototype" || name === "constructor" ? member : toPromises(member);
+        },
+        set: (_, name, newValue)=>Reflect.set(target, name, fromPromises(newValue), target),
+        has: (_, name)=>Reflect.has(target, name),
+        apply: (_, thisArg, args)=>resultToPromises(Reflect.apply(target as (...args: unknown[]) => unknown, fromPromises(thisArg), args.map(fromPromises))),
+        construct: (_, args)=>toPromises(Reflect.construct(target as new(...args: unknown[]) => object, args.map(fromPromises))) as object
+    });
+    promisesProxies.set(target, proxy);
+    promisesTargets.set(proxy, target);
+    return proxy;
+}
+function resultToPromises(result: unknown): unknown {
+    return typeof (result as PromiseLike<unknown> | null | undefined)?.then === "function" ? Promise.resolve(result).then(toPromises) : toPromises(result);
+}
+/**
+ * The original of a value wrapped by {@link toPromises}.
+ * A callback, like a listener of an event, gets its arguments wrapped
+ */ function fromPromises(value: unknown): unknown {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null) {
+        return value;
+    }
+    const target = promisesTargets.get(value);
+    if (target !== undefined) {
+        return target;
+    }
+    if (typeof value !== "function") {
+        return value;
+    }
+    const cached = promisesCallbacks.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const callback = function(this: unknown, ...args: unknown[]): unknown {
+        return fromPromises(Reflect.apply(value, this, args.map(toPromises)));
+    };
+    promisesCallbacks.set(value, callback);
+    return callback;
+}
+/**
+ * {@link importVsCodeApi} whose functions return Promise instead of Thenable
+ */ export function importVsCodeApiPromises(): VSCodeAPIPromises | undefined {
+    const api = importVsCodeApi();
+    return api === undefined ? undefined : toPromises(api) as VSCodeAPIPromises;
+}
+export type Disposable = {
+    dispose(): any;
+};
+export interface TextDocument {
+    readonly fileName: string;
+    /**
+		 * Save the underlying file.
+		 */ save(): Thenable<boolean>;
+}
+export interface TextEditor {
+    readonly document: TextDocument;
+    edit(callback: (editBuilder: string) => void): Thenable<boolean>;
+    edit(callback: (editBuilder: string) => void, options: {
+        readonly undoStopBefore: boolean;
+    }): Thenable<boolean>;
+    hide(): void;
+}
+export interface NotebookEditor extends TextEditor {
+    readonly notebookType: string;
+}
+export type Memento<T> = {
+    get(key: string): T | undefined;
+    update(key: string, value: T): Thenable<void>;
+};
+export interface Event<T> {
+    (listener: (e: T) => any) : Disposable;
+}
+export interface Thenable<T> extends PromiseLike<T> {
+}
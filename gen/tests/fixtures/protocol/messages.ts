@@ -0,0 +1,13 @@
+export interface Update {
+  readonly type: "update";
+  readonly text: string;
+}
+
+export type ExtensionToWebview =
+  | Update
+  | { readonly type: "reset" };
+
+export type WebviewToExtension =
+  | { readonly type: "ready" }
+  | { readonly type: "edit"; readonly text: string }
+  | ({ readonly type: "log" } & { readonly level: number });
@@ -0,0 +1,90 @@
+import type { ExtensionToWebview, WebviewToExtension } from "./messages.ts";
+
+/** The discriminants of {@link ExtensionToWebview} */
+const extensionToWebviewDiscriminants: readonly unknown[] = ["update", "reset"];
+
+/** The discriminants of {@link WebviewToExtension} */
+const webviewToExtensionDiscriminants: readonly unknown[] = ["ready", "edit", "log"];
+
+/**
+ * Whether `message` has a `type` of {@link ExtensionToWebview}.
+ * The other properties are not checked
+ */
+export function isExtensionToWebview(message: unknown): message is ExtensionToWebview {
+  return typeof message === "object" && message !== null &&
+    extensionToWebviewDiscriminants.includes((message as { readonly type?: unknown }).type);
+}
+
+/**
+ * Whether `message` has a `type` of {@link WebviewToExtension}.
+ * The other properties are not checked
+ */
+export function isWebviewToExtension(message: unknown): message is WebviewToExtension {
+  return typeof message === "object" && message !== null &&
+    webviewToExtensionDiscriminants.includes((message as { readonly type?: unknown }).type);
+}
+
+/**
+ * The side of the extension, around the `webview` of a `WebviewPanel` or `WebviewView`
+ *
+ * ```ts
+ * const messenger = createExtensionMessenger(panel.webview);
+ * messenger.onMessage((message) => { ... });
+ * ```
+ */
+export function createExtensionMessenger(webview: {
+  readonly postMessage: (message: unknown) => PromiseLike<boolean>;
+  readonly onDidReceiveMessage: (listener: (message: unknown) => unknown) => { dispose(): unknown };
+}): {
+  readonly post: (message: ExtensionToWebview) => PromiseLike<boolean>;
+  readonly onMessage: (
+    listener: (message: WebviewToExtension) => void,
+    onInvalid?: (message: unknown) => void,
+  ) => { dispose(): unknown };
+} {
+  return {
+    post: (message) => webview.postMessage(message),
+    onMessage: (listener, onInvalid) =>
+      webview.onDidReceiveMessage((message) => {
+        if (isWebviewToExtension(message)) {
+          listener(message);
+        } else {
+          onInvalid?.(message);
+        }
+      }),
+  };
+}
+
+/**
+ * The side of the webview, around the API of `getWebviewApi()` or `acquireVsCodeApi()`
+ *
+ * ```ts
+ * const messenger = createWebviewMessenger(getWebviewApi()!);
+ * messenger.post({ ... });
+ * ```
+ */
+export function createWebviewMessenger(api: {
+  readonly postMessage: (message: unknown) => void;
+}): {
+  readonly post: (message: WebviewToExtension) => void;
+  readonly onMessage: (
+    listener: (message: ExtensionToWebview) => void,
+    onInvalid?: (message: unknown) => void,
+  ) => { dispose(): void };
+} {
+  return {
+    post: (message) => api.postMessage(message),
+    onMessage: (listener, onInvalid) => {
+      const handler = (event: Event): void => {
+        const message = (event as MessageEvent).data;
+        if (isExtensionToWebview(message)) {
+          listener(message);
+        } else {
+          onInvalid?.(message);
+        }
+      };
+      globalThis.addEventListener("message", handler);
+      return { dispose: () => globalThis.removeEventListener("message", handler) };
+    },
+  };
+}
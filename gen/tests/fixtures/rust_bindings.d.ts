@@ -0,0 +1,89 @@
+// args: --emit-rust
+declare module 'vscode' {
+	export const version: string;
+
+	/**
+	 * Represents a type which can release resources.
+	 */
+	export class Disposable {
+		static from(...disposableLikes: { dispose: () => any }[]): Disposable;
+		constructor(callOnDispose: () => any);
+		dispose(): any;
+	}
+
+	export class Position {
+		readonly line: number;
+		readonly character: number;
+		constructor(line: number, character: number);
+		isBefore(other: Position): boolean;
+		translate(lineDelta?: number, characterDelta?: number): Position;
+		translate(change: { lineDelta?: number; characterDelta?: number }): Position;
+	}
+
+	export class Selection extends Position {
+		isReversed: boolean;
+	}
+
+	export interface Event<T> {
+		(listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]): Disposable;
+	}
+
+	export interface TextDocument {
+		readonly fileName: string;
+		readonly isDirty: boolean;
+		readonly version: number;
+		getText(range?: Position): string;
+		save(): Thenable<boolean>;
+	}
+
+	export interface TextEditor {
+		readonly document: TextDocument;
+		selection: Selection;
+		viewColumn: ViewColumn | undefined;
+	}
+
+	export enum ViewColumn {
+		Active = -1,
+		Beside = -2,
+		One = 1,
+		Two = 2
+	}
+
+	export enum ColorThemeKind {
+		Light = 1,
+		Dark = 2,
+		HighContrast = 3
+	}
+
+	export namespace window {
+		export let activeTextEditor: TextEditor | undefined;
+		export const onDidChangeActiveTextEditor: Event<TextEditor | undefined>;
+		/**
+		 * Show an information message to users.
+		 *
+		 * ```
+		 * window.showInformationMessage('hello');
+		 * ```
+		 *
+		 * @param message The message to show.
+		 * @returns A thenable that resolves to the selected item.
+		 */
+		export function showInformationMessage(message: string): Thenable<string | undefined>;
+		export function showInformationMessage(message: string, ...items: string[]): Thenable<string | undefined>;
+		/**
+		 * @deprecated Use {@link window.showInformationMessage} instead.
+		 */
+		export function showMessage(message: string): void;
+	}
+
+	export namespace env {
+		export const appName: string;
+		export function openExternal(target: string): Thenable<boolean>;
+		export namespace clipboard {
+			export function readText(): Thenable<string>;
+			export function writeText(value: string): Thenable<void>;
+		}
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
@@ -0,0 +1,164 @@
+//! Type Definition for Visual Studio Code 1.80 Extension API
+//! See https://code.visualstudio.com/api for more information
+//!
+//! Bindings of the `vscode` module for wasm-bindgen
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(module = "vscode")]
+extern "C" {
+    #[wasm_bindgen(thread_local_v2, js_name = version)]
+    pub static VERSION: js_sys::JsString;
+
+    /// Represents a type which can release resources.
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Disposable;
+    #[wasm_bindgen(variadic, static_method_of = Disposable, js_name = from)]
+    pub fn from(disposable_likes: &js_sys::Array) -> Disposable;
+    #[wasm_bindgen(constructor)]
+    pub fn new(call_on_dispose: &js_sys::Function) -> Disposable;
+    #[wasm_bindgen(method, js_name = dispose)]
+    pub fn dispose(this: &Disposable) -> JsValue;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Position;
+    #[wasm_bindgen(method, getter, js_name = line)]
+    pub fn line(this: &Position) -> f64;
+    #[wasm_bindgen(method, getter, js_name = character)]
+    pub fn character(this: &Position) -> f64;
+    #[wasm_bindgen(constructor)]
+    pub fn new(line: f64, character: f64) -> Position;
+    #[wasm_bindgen(method, js_name = isBefore)]
+    pub fn is_before(this: &Position, other: &Position) -> bool;
+    #[wasm_bindgen(method, js_name = translate)]
+    pub fn translate(this: &Position, line_delta: Option<f64>, character_delta: Option<f64>) -> Position;
+    #[wasm_bindgen(method, js_name = translate)]
+    pub fn translate_2(this: &Position, change: &js_sys::Object) -> Position;
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[wasm_bindgen(extends = Position)]
+    pub type Selection;
+    #[wasm_bindgen(method, getter, js_name = isReversed)]
+    pub fn is_reversed(this: &Selection) -> bool;
+    #[wasm_bindgen(method, setter, js_name = isReversed)]
+    pub fn set_is_reversed(this: &Selection, value: bool);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[derive(Debug, Clone, PartialEq)]
+    pub type TextDocument;
+    #[wasm_bindgen(method, getter, js_name = fileName)]
+    pub fn file_name(this: &TextDocument) -> String;
+    #[wasm_bindgen(method, getter, js_name = isDirty)]
+    pub fn is_dirty(this: &TextDocument) -> bool;
+    #[wasm_bindgen(method, getter, js_name = version)]
+    pub fn version(this: &TextDocument) -> f64;
+    #[wasm_bindgen(method, js_name = getText)]
+    pub fn get_text(this: &TextDocument, range: Option<&Position>) -> String;
+    #[wasm_bindgen(method, js_name = save)]
+    pub fn save(this: &TextDocument) -> js_sys::Promise;
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub type TextEditor;
+    #[wasm_bindgen(method, getter, js_name = document)]
+    pub fn document(this: &TextEditor) -> TextDocument;
+    #[wasm_bindgen(method, getter, js_name = selection)]
+    pub fn selection(this: &TextEditor) -> Selection;
+    #[wasm_bindgen(method, setter, js_name = selection)]
+    pub fn set_selection(this: &TextEditor, value: &Selection);
+    #[wasm_bindgen(method, getter, js_name = viewColumn)]
+    pub fn view_column(this: &TextEditor) -> Option<f64>;
+    #[wasm_bindgen(method, setter, js_name = viewColumn)]
+    pub fn set_view_column(this: &TextEditor, value: Option<f64>);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewColumn;
+
+impl ViewColumn {
+    pub const ACTIVE: f64 = -1.0;
+    pub const BESIDE: f64 = -2.0;
+    pub const ONE: f64 = 1.0;
+    pub const TWO: f64 = 2.0;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorThemeKind;
+
+impl ColorThemeKind {
+    pub const LIGHT: f64 = 1.0;
+    pub const DARK: f64 = 2.0;
+    pub const HIGH_CONTRAST: f64 = 3.0;
+}
+
+pub mod window {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[wasm_bindgen(module = "vscode")]
+    extern "C" {
+        /// The namespace object of `window`
+        #[derive(Debug, Clone, PartialEq)]
+        #[wasm_bindgen(js_name = window)]
+        pub type Namespace;
+
+        #[wasm_bindgen(static_method_of = Namespace, getter, js_name = activeTextEditor)]
+        pub fn active_text_editor() -> Option<TextEditor>;
+
+        #[wasm_bindgen(static_method_of = Namespace, getter, js_name = onDidChangeActiveTextEditor)]
+        pub fn on_did_change_active_text_editor() -> js_sys::Function;
+
+        /// Show an information message to users.
+        ///
+        /// ```text
+        /// window.showInformationMessage('hello');
+        /// ```
+        ///
+        /// @param message The message to show.
+        /// @returns A thenable that resolves to the selected item.
+        #[wasm_bindgen(js_namespace = window, js_name = showInformationMessage)]
+        pub fn show_information_message(message: &str) -> js_sys::Promise;
+
+        #[wasm_bindgen(variadic, js_namespace = window, js_name = showInformationMessage)]
+        pub fn show_information_message_2(message: &str, items: &js_sys::Array) -> js_sys::Promise;
+
+        /// @deprecated Use {@link window.showInformationMessage} instead.
+        #[deprecated(note = "Use {@link window.showInformationMessage} instead.")]
+        #[wasm_bindgen(js_namespace = window, js_name = showMessage)]
+        pub fn show_message(message: &str);
+    }
+}
+
+pub mod env {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[wasm_bindgen(module = "vscode")]
+    extern "C" {
+        /// The namespace object of `env`
+        #[derive(Debug, Clone, PartialEq)]
+        #[wasm_bindgen(js_name = env)]
+        pub type Namespace;
+
+        #[wasm_bindgen(static_method_of = Namespace, getter, js_name = appName)]
+        pub fn app_name() -> String;
+
+        #[wasm_bindgen(js_namespace = env, js_name = openExternal)]
+        pub fn open_external(target: &str) -> js_sys::Promise;
+    }
+
+    pub mod clipboard {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[wasm_bindgen(module = "vscode")]
+        extern "C" {
+            #[wasm_bindgen(js_namespace = ["env", "clipboard"], js_name = readText)]
+            pub fn read_text() -> js_sys::Promise;
+
+            #[wasm_bindgen(js_namespace = ["env", "clipboard"], js_name = writeText)]
+            pub fn write_text(value: &str) -> js_sys::Promise;
+        }
+    }
+}
@@ -0,0 +1,143 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    readonly version: string;
+    /**
+	 * Represents a type which can release resources.
+	 */ readonly Disposable: {
+        from(...disposableLikes: {
+            dispose: () => any;
+        }[]): Disposable;
+        new(callOnDispose: () => any): Disposable;
+    };
+    readonly Position: {
+        new(line: number, character: number): Position;
+    };
+    readonly Selection: {
+        new(): Selection;
+    };
+    readonly ViewColumn: {
+        readonly Active: -1;
+        readonly Beside: -2;
+        readonly One: 1;
+        readonly Two: 2;
+    };
+    readonly ColorThemeKind: {
+        readonly Light: 1;
+        readonly Dark: 2;
+        readonly HighContrast: 3;
+    };
+    readonly window: {
+        activeTextEditor: TextEditor | undefined;
+        readonly onDidChangeActiveTextEditor: Event<TextEditor | undefined>;
+        /**
+		 * Show an information message to users.
+		 *
+		 * ```
+		 * window.showInformationMessage('hello');
+		 * ```
+		 *
+		 * @param message The message to show.
+		 * @returns A thenable that resolves to the selected item.
+		 */ showInformationMessage(message: string): Thenable<string | undefined>;
+        showInformationMessage(message: string, ...items: string[]): Thenable<string | undefined>;
+        /**
+		 * @deprecated Use {@link window.showInformationMessage} instead.
+		 */ showMessage(message: string): void;
+    };
+    readonly env: {
+        readonly appName: string;
+        openExternal(target: string): Thenable<boolean>;
+        readonly clipboard: {
+            readText(): Thenable<string>;
+            writeText(value: string): Thenable<void>;
+        };
+    };
+};
+type ValueOf<T> = T[keyof T];
+/**
+	 * Represents a type which can release resources.
+	 */ export type Disposable = {
+    dispose(): any;
+};
+export type Position = {
+    readonly line: number;
+    readonly character: number;
+    isBefore(other: Position): boolean;
+    translate(lineDelta?: number, characterDelta?: number): Position;
+    translate(change: {
+        lineDelta?: number;
+        characterDelta?: number;
+    }): Position;
+};
+export type Selection = Position & {
+    isReversed: boolean;
+};
+export interface Event<T> {
+    (listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]) : Disposable;
+}
+export interface TextDocument {
+    readonly fileName: string;
+    readonly isDirty: boolean;
+    readonly version: number;
+    getText(range?: Position): string;
+    save(): Thenable<boolean>;
+}
+export interface TextEditor {
+    readonly document: TextDocument;
+    selection: Selection;
+    viewColumn: ViewColumn | undefined;
+}
+export type ViewColumn = ValueOf<VSCodeAPI["ViewColumn"]>;
+export type ColorThemeKind = ValueOf<VSCodeAPI["ColorThemeKind"]>;
+export interface Thenable<T> extends PromiseLike<T> {
+}
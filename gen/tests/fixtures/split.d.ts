@@ -0,0 +1,47 @@
+// args: --split
+declare module 'vscode' {
+	export const version: string;
+
+	/**
+	 * Represents a type which can release resources.
+	 */
+	export class Disposable {
+		constructor(callOnDispose: () => any);
+		dispose(): any;
+	}
+
+	export interface Event<T> {
+		(listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]): Disposable;
+	}
+
+	export class Position {
+		readonly line: number;
+		constructor(line: number, character: number);
+		isBefore(other: Position): boolean;
+	}
+
+	export interface TextEditor {
+		readonly selection: Position;
+		readonly viewColumn: ViewColumn | undefined;
+	}
+
+	export enum ViewColumn {
+		Active = -1,
+		One = 1
+	}
+
+	export namespace window {
+		export let activeTextEditor: TextEditor | undefined;
+		export const onDidChangeActiveTextEditor: Event<TextEditor | undefined>;
+		export function showInformationMessage(message: string): Thenable<string | undefined>;
+	}
+
+	export namespace env {
+		export const appName: string;
+		export namespace clipboard {
+			export function readText(): Thenable<string>;
+		}
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
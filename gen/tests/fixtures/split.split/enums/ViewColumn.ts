@@ -0,0 +1,6 @@
+/**
+ * The `ViewColumn` enum
+ */ export type ViewColumnEnum = {
+    readonly Active: -1;
+    readonly One: 1;
+};
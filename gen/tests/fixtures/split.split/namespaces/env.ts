@@ -0,0 +1,9 @@
+import type { Thenable } from "../types/Thenable.ts";
+/**
+ * The `env` namespace
+ */ export type EnvNamespace = {
+    readonly appName: string;
+    readonly clipboard: {
+        readText(): Thenable<string>;
+    };
+};
@@ -0,0 +1,10 @@
+import type { Event } from "../types/Event.ts";
+import type { TextEditor } from "../types/TextEditor.ts";
+import type { Thenable } from "../types/Thenable.ts";
+/**
+ * The `window` namespace
+ */ export type WindowNamespace = {
+    activeTextEditor: TextEditor | undefined;
+    readonly onDidChangeActiveTextEditor: Event<TextEditor | undefined>;
+    showInformationMessage(message: string): Thenable<string | undefined>;
+};
@@ -0,0 +1,5 @@
+/**
+	 * Represents a type which can release resources.
+	 */ export type Disposable = {
+    dispose(): any;
+};
@@ -0,0 +1,4 @@
+import type { Disposable } from "./Disposable.ts";
+export interface Event<T> {
+    (listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]) : Disposable;
+}
@@ -0,0 +1,4 @@
+export type Position = {
+    readonly line: number;
+    isBefore(other: Position): boolean;
+};
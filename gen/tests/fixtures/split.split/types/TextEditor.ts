@@ -0,0 +1,6 @@
+import type { Position } from "./Position.ts";
+import type { ViewColumn } from "./ViewColumn.ts";
+export interface TextEditor {
+    readonly selection: Position;
+    readonly viewColumn: ViewColumn | undefined;
+}
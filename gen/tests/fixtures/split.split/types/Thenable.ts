@@ -0,0 +1,2 @@
+export interface Thenable<T> extends PromiseLike<T> {
+}
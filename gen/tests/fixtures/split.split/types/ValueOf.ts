@@ -0,0 +1 @@
+export type ValueOf<T> = T[keyof T];
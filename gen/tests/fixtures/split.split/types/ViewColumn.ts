@@ -0,0 +1,3 @@
+import type { ViewColumnEnum } from "../enums/ViewColumn.ts";
+import type { ValueOf } from "./ValueOf.ts";
+export type ViewColumn = ValueOf<ViewColumnEnum>;
@@ -0,0 +1,20 @@
+// args: --strict-function-types
+declare module 'vscode' {
+	export namespace window {
+		export function showQuickPick(items: string[]): Thenable<string | undefined>;
+		export function showQuickPick<T extends QuickPickItem>(items: T[], canPickMany: true): Thenable<T[] | undefined>;
+	}
+
+	export interface QuickPickItem {
+		label: string;
+	}
+
+	export class Range {
+		constructor(start: number, end: number);
+		constructor(startLine: number, startCharacter: number, endLine: number, endCharacter: number);
+		with(start?: number, end?: number): Range;
+		with(change: { start?: number; end?: number }): Range;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
@@ -0,0 +1,57 @@
+// args: --strip-deprecated
+declare module 'vscode' {
+	export namespace workspace {
+		/**
+		 * @deprecated Use {@link workspace.workspaceFolders} instead.
+		 */
+		export const rootPath: string | undefined;
+
+		export const workspaceFolders: readonly string[] | undefined;
+
+		/**
+		 * @deprecated Use the overload taking an {@link OpenOptions}.
+		 */
+		export function open(path: string, preview: boolean): Thenable<void>;
+
+		export function open(path: string, options?: OpenOptions): Thenable<void>;
+
+		/**
+		 * @deprecated
+		 */
+		export function legacyOpen(options: LegacyOptions): Thenable<void>;
+	}
+
+	export interface OpenOptions {
+		preview?: boolean;
+
+		/**
+		 * @deprecated Ignored.
+		 */
+		background?: boolean;
+	}
+
+	/**
+	 * @deprecated
+	 */
+	export interface LegacyOptions {
+		preview: boolean;
+	}
+
+	/**
+	 * @deprecated Use {@link OpenOptions}.
+	 */
+	export interface ShowOptions {
+		column: number;
+	}
+
+	export class Panel {
+		show(options: ShowOptions): void;
+
+		/**
+		 * @deprecated Use {@link Panel.show}.
+		 */
+		reveal(): void;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
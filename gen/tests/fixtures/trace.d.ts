@@ -0,0 +1,57 @@
+// args: --trace
+declare module 'vscode' {
+	export const version: string;
+
+	export class Disposable {
+		static from(...disposableLikes: { dispose: () => any }[]): Disposable;
+		constructor(callOnDispose: () => any);
+		dispose(): any;
+	}
+
+	export class EventEmitter<T> {
+		event: Event<T>;
+		fire(data: T): void;
+		dispose(): void;
+	}
+
+	export interface Event<T> {
+		(listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]): Disposable;
+	}
+
+	export enum DiagnosticSeverity {
+		Error = 0,
+		Warning = 1,
+		Information = 2,
+		Hint = 3
+	}
+
+	export enum ViewColumn {
+		Active = -1,
+		Beside = -2,
+		One = 1,
+		Two = 2
+	}
+
+	export interface TextDocument {
+		readonly onDidSave: Event<void>;
+		getText(): string;
+	}
+
+	export interface TextEditor {
+		readonly document: TextDocument;
+		readonly viewColumn: ViewColumn | undefined;
+		edit(callback: (builder: string) => void): Thenable<boolean>;
+	}
+
+	export namespace window {
+		export let activeColumn: ViewColumn | undefined;
+		export let activeTextEditor: TextEditor | undefined;
+		export function showTextDocument(document: TextDocument): Thenable<TextEditor>;
+		export function createTextEditor(): TextEditor;
+		export const onDidChangeActiveColumn: Event<ViewColumn | undefined>;
+		export function showInformationMessage(message: string): Thenable<string | undefined>;
+		export function showInformationMessage(message: string, ...items: string[]): Thenable<string | undefined>;
+	}
+}
+
+interface Thenable<T> extends PromiseLike<T> { }
@@ -0,0 +1,374 @@
+declare const require: undefined | ((path: "vscode") => VSCodeAPI);
+const moduleLoaders: ReadonlyArray<{
+    readonly name: string;
+    readonly load: () => VSCodeAPI | undefined;
+}> = [
+    {
+        name: "require(\"vscode\")",
+        load: (): VSCodeAPI | undefined =>typeof require === "function" ? require("vscode") : undefined
+    }
+];
+/**
+ * Try the loaders in order. Why the others failed is pushed to `failures`
+ */ function loadModule(failures: string[]): VSCodeAPI | undefined {
+    for (const loader of moduleLoaders){
+        try {
+            const api = loader.load();
+            if (api !== undefined) {
+                return api;
+            }
+            failures.push(`${loader.name}: not available`);
+        } catch (error) {
+            failures.push(`${loader.name}: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+    return undefined;
+}
+/**
+ * import vscode API
+ *
+ * ```ts
+ * require("vscode")
+ * ```
+ *
+ * Returns VSCodeAPI only within the host which provides vscode.
+ */ export function importVsCodeApi(): VSCodeAPI | undefined {
+    return loadModule([]);
+}
+/**
+ * Like {@link importVsCodeApi}, but throws an error which tells what was tried
+ * instead of returning `undefined`
+ */ export function importVsCodeApiOrThrow(): VSCodeAPI {
+    const failures: string[] = [];
+    const api = loadModule(failures);
+    if (api === undefined) {
+        throw new Error(`Could not import vscode, which is only available within the host. Tried:\n${failures.map((failure)=>`- ${failure}`).join("\n")}`);
+    }
+    return api;
+}
+/**
+ * Type Definition for Visual Studio Code 1.80 Extension API
+ * See https://code.visualstudio.com/api for more information
+ */ export type VSCodeAPI = {
+    readonly version: string;
+    readonly Disposable: {
+        from(...disposableLikes: {
+            dispose: () => any;
+        }[]): Disposable;
+        new(callOnDispose: () => any): Disposable;
+    };
+    readonly EventEmitter: {
+        new <T>(): EventEmitter<T>;
+    };
+    readonly DiagnosticSeverity: {
+        readonly Error: 0;
+        readonly Warning: 1;
+        readonly Information: 2;
+        readonly Hint: 3;
+    };
+    readonly ViewColumn: {
+        readonly Active: -1;
+        readonly Beside: -2;
+        readonly One: 1;
+        readonly Two: 2;
+    };
+    readonly window: {
+        activeColumn: ViewColumn | undefined;
+        activeTextEditor: TextEditor | undefined;
+        showTextDocument(document: TextDocument): Thenable<TextEditor>;
+        createTextEditor(): TextEditor;
+        readonly onDidChangeActiveColumn: Event<ViewColumn | undefined>;
+        showInformationMessage(message: string): Thenable<string | undefined>;
+        showInformationMessage(message: string, ...items: string[]): Thenable<string | undefined>;
+    };
+};
+type ValueOf<T> = T[keyof T];
+/**
+ * What {@link traceVSCodeAPI} saw. `path` is like `window.showInformationMessage`,
+ * or `TextEditor.edit` for a member of an instance
+ */ export type VSCodeAPITraceEvent = {
+    readonly kind: "call";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly result: unknown;
+} | {
+    readonly kind: "throw";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly error: unknown;
+} | {
+    readonly kind: "construct";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly instance: unknown;
+} | {
+    readonly kind: "subscribe";
+    readonly path: string;
+} | {
+    readonly kind: "settle";
+    readonly path: string;
+    readonly args: readonly unknown[];
+    readonly status: "fulfilled" | "rejected";
+    readonly value: unknown;
+    readonly durationMs: number;
+};
+/**
+ * A function whose result, or what its Thenable resolves to, is an instance of a traced type
+ */ type ResultTraceShape = {
+    readonly returns: string;
+} | {
+    readonly resolves: string;
+};
+type TraceShape = {
+    readonly [name: string]: "function" | "event" | ResultTraceShape | {
+        readonly instance: string;
+    } | {
+        readonly staticMethods: readonly string[];
+    } | TraceShape;
+};
+const moduleTraceShape: TraceShape = {
+    Disposable: {
+        staticMethods: [
+            "from"
+        ]
+    },
+    EventEmitter: {
+        staticMethods: []
+    },
+    window: {
+        activeTextEditor: {
+            instance: "TextEditor"
+        },
+        showTextDocument: {
+            resolves: "TextEditor"
+        },
+        createTextEditor: {
+            returns: "TextEditor"
+        },
+        onDidChangeActiveColumn: "event",
+        showInformationMessage: "function"
+    }
+};
+/**
+ * What to trace on the instances of the classes and interfaces, by their names
+ */ const instanceTraceShapes: {
+    readonly [type: string]: TraceShape;
+} = {
+    Disposable: {
+        dispose: "function"
+    },
+    EventEmitter: {
+        event: "event",
+        fire: "function",
+        dispose: "function"
+    },
+    TextDocument: {
+        onDidSave: "event",
+        getText: "function"
+    },
+    TextEditor: {
+        document: {
+            instance: "TextDocument"
+        },
+        edit: "function"
+    }
+};
+type Tracer = {
+    readonly sink: (event: VSCodeAPITraceEvent) => void;
+    /**
+   * The traced Proxy of each instance, so that an instance stays one object
+   */ readonly instances: WeakMap<object, object>;
+};
+/**
+ * The instances behind the traced Proxies
+ */ const untracedInstances = new WeakMap<object, object>();
+/**
+ * The instance behind a traced Proxy, as the API is given its own instances
+ */ function untraced(value: unknown): unknown {
+    return (typeof value === "object" || typeof value === "function") && value !== null ? untracedInstances.get(value) ?? value : value;
+}
+function traceFunction(func: (...args: unknown[]) => unknown, path: string, resultShape: ResultTraceShape | undefined, tracer: Tracer): (...args: unknown[]) => unknown {
+    return function(this: unknown, ...args: unknown[]): unknown {
+        let result: unknown;
+        try {
+            result = func.apply(untraced(this), args.map(untraced));
+        } catch (error) {
+            tracer.sink({
+                kind: "throw",
+                path,
+                args,
+                error
+            });
+            throw error;
+        }
+        tracer.sink({
+            kind: "call",
+            path,
+            args,
+            result
+        });
+        const then = (result as PromiseLike<unknown> | null | undefined)?.then;
+        if (typeof then === "function") {
+            const start = performance.now();
+            then.call(result, (value: unknown)=>tracer.sink({
+                    kind: "settle",
+                    path,
+                    args,
+                    status: "fulfilled",
+                    value,
+                    durationMs: performance.now() - start
+                }), (reason: unknown)=>tracer.sink({
+                    kind: "settle",
+                    path,
+                    args,
+                    status: "rejected",
+                    value: reason,
+                    durationMs: performance.now() - start
+                }));
+            if (resultShape !== undefined && "resolves" in resultShape) {
+                const type = resultShape.resolves;
+                return then.call(result, (value: unknown)=>traceInstance(value, type, tracer));
+            }
+        }
+        return resultShape !== undefined && "returns" in resultShape ? traceInstance(result, resultShape.returns, tracer) : result;
+    };
+}
+function traceEvent(event: (...args: unknown[]) => unknown, path: string, tracer: Tracer): (...args: unknown[]) => unknown {
+    return function(this: unknown, ...args: unknown[]): unknown {
+        tracer.sink({
+            kind: "subscribe",
+            path
+        });
+        return event.apply(untraced(this), args);
+    };
+}
+function traceClass(constructor: object, path: string, staticMethods: readonly string[], tracer: Tracer): object {
+    return new Proxy(constructor, {
+        construct (target, args, newTarget) {
+            const instance = Reflect.construct(target as new(...args: unknown[]) => object, args.map(untraced), newTarget);
+            tracer.sink({
+                kind: "construct",
+                path,
+                args,
+                instance
+            });
+            return traceInstance(instance, path, tracer) as object;
+        },
+        get (target, name, receiver) {
+            const value = Reflect.get(target, name, receiver);
+            return typeof name === "string" && staticMethods.includes(name) && typeof value === "function" ? traceFunction(value as (...args: unknown[]) => unknown, `${path}.${name}`, undefined, tracer) : value;
+        }
+    });
+}
+/**
+ * The member `name` of `object` traced by `shape`.
+ * The same traced value is returned while the member is the same
+ */ function traceMember(object: object, name: string | symbol, path: string, shape: TraceShape, traced: Map<string, {
+    readonly value: unknown;
+    readonly traced: unknown;
+}>, tracer: Tracer): unknown {
+    const value = Reflect.get(object, name, object);
+    const member = typeof name === "string" && Object.hasOwn(shape, name) ? shape[name] : undefined;
+    if (typeof name !== "string" || member === undefined || value === undefined || value === null) {
+        return value;
+    }
+    const cached = traced.get(name);
+    if (cached !== undefined && cached.value === value) {
+        return cached.traced;
+    }
+    const memberPath = path === "" ? name : `${path}.${name}`;
+    const tracedValue = member === "function" ? traceFunction(value as (...args: unknown[]) => unknown, memberPath, undefined, tracer) : member === "event" ? traceEvent(value as (...args: unknown[]) => unknown, memberPath, tracer) : ("returns" in member && typeof member.returns === "string") || ("resolves" in member && typeof member.resolves === "string") ? traceFunction(value as (...args: unknown[]) => unknown, memberPath, member as ResultTraceShape, tracer) : "instance" in member && typeof member.instance === "string" ? traceInstance(value, member.instance, tracer) : "staticMethods" in member && Array.isArray(member.staticMethods) ? traceClass(value, memberPath, member.staticMethods, tracer) : traceNamespace(value, memberPath, member as TraceShape, tracer);
+    traced.set(name, {
+        value,
+        traced: tracedValue
+    });
+    return tracedValue;
+}
+/**
+ * The namespace is read when a member is, so variables like `window.activeTextEditor` stay current.
+ * It may be frozen, and a trap of a frozen target has to give its own properties,
+ * so the Proxy is of an empty object with the same prototype.
+ * The keys and descriptors come from the namespace, as configurable as the empty object has none of them,
+ * so that `Object.keys` and spreading see its members
+ */ function traceNamespace(namespace: object, path: string, shape: TraceShape, tracer: Tracer): object {
+    const traced = new Map<string, {
+        readonly value: unknown;
+        readonly traced: unknown;
+    }>();
+    return new Proxy(Object.create(Object.getPrototypeOf(namespace)), {
+        get: (_, name)=>traceMember(namespace, name, path, shape, traced, tracer),
+        set: (_, name, newValue)=>Reflect.set(namespace, name, untraced(newValue), namespace),
+        has: (_, name)=>Reflect.has(namespace, name),
+        ownKeys: ()=>Reflect.ownKeys(namespace),
+        getOwnPropertyDescriptor: (_, name)=>{
+            const descriptor = Reflect.getOwnPropertyDescriptor(namespace, name);
+            if (descriptor === undefined) {
+                return undefined;
+            }
+            return "value" in descriptor ? {
+                ...descriptor,
+                value: traceMember(namespace, name, path, shape, traced, tracer),
+                configurable: true
+            } : {
+                ...descriptor,
+                configurable: true
+            };
+        }
+    });
+}
+/**
+ * A Proxy of an instance of the class or interface `type`, whose paths start with `type`
+ */ function traceInstance(value: unknown, type: string, tracer: Tracer): unknown {
+    if ((typeof value !== "object" && typeof value !== "function") || value === null || !Object.hasOwn(instanceTraceShapes, type)) {
+        return value;
+    }
+    const cached = tracer.instances.get(value);
+    if (cached !== undefined) {
+        return cached;
+    }
+    const proxy = traceNamespace(value, type, instanceTraceShapes[type], tracer);
+    tracer.instances.set(value, proxy);
+    untracedInstances.set(proxy, value);
+    return proxy;
+}
+/**
+ * Wrap the API to pass every function call, constructor invocation and event subscription to `sink`,
+ * with the arguments and the result.
+ * When a call returns a Thenable, a `settle` event follows with how long it took.
+ * The methods and events of the instances which calls, constructors and variables give
+ * are traced too, and the instances are unwrapped when they are passed back to the API.
+ * The instances which the API passes to listeners are not traced.
+ *
+ * ```ts
+ * const vscode = traceVSCodeAPI(importVsCodeApi()!, (event) => console.log(event));
+ * ```
+ */ export function traceVSCodeAPI(api: VSCodeAPI, sink: (event: VSCodeAPITraceEvent) => void): VSCodeAPI {
+    return traceNamespace(api, "", moduleTraceShape, {
+        sink,
+        instances: new WeakMap()
+    }) as VSCodeAPI;
+}
+export type Disposable = {
+    dispose(): any;
+};
+export type EventEmitter<T> = {
+    event: Event<T>;
+    fire(data: T): void;
+    dispose(): void;
+};
+export interface Event<T> {
+    (listener: (e: T) => any, thisArgs?: any, disposables?: Disposable[]) : Disposable;
+}
+export type DiagnosticSeverity = ValueOf<VSCodeAPI["DiagnosticSeverity"]>;
+export type ViewColumn = ValueOf<VSCodeAPI["ViewColumn"]>;
+export interface TextDocument {
+    readonly onDidSave: Event<void>;
+    getText(): string;
+}
+export interface TextEditor {
+    readonly document: TextDocument;
+    readonly viewColumn: ViewColumn | undefined;
+    edit(callback: (builder: string) => void): Thenable<boolean>;
+}
+export interface Thenable<T> extends PromiseLike<T> {
+}
@@ -0,0 +1,59 @@
+/**
+     * Acquire an instance of the webview API.
+     *
+     * This may only be called once in a webview's context. Attempting to call `acquireVsCodeApi` after it has already
+     * been called will throw an exception.
+     *
+     * @template StateType Type of the persisted state stored for the webview.
+     */ declare const acquireVsCodeApi: undefined | (<StateType = unknown>() => WebviewApi<StateType>);
+/**
+ * {@link WebviewApi} whose `postMessage` takes only `Message`,
+ * the messages the extension expects
+ */ export type TypedWebviewApi<State, Message> = Omit<WebviewApi<State>, "postMessage"> & {
+    /**
+   * Post a message to the owner of the webview.
+   *
+   * @param message Data to post. Must be JSON serializable.
+   */ postMessage(message: Message): void;
+};
+let webviewApi: WebviewApi<unknown> | undefined = undefined;
+/**
+ * acquire webview API
+ *
+ * ```ts
+ * acquireVsCodeApi()
+ * ```
+ *
+ * Returns WebviewApi only within the webview.
+ * `acquireVsCodeApi` can be called only once, so the API is kept and returned from then on
+ */ export function getWebviewApi<State = unknown, Message = unknown>(): TypedWebviewApi<State, Message> | undefined {
+    if (webviewApi === undefined) {
+        const acquireFunc = typeof acquireVsCodeApi === "function" ? acquireVsCodeApi : undefined;
+        webviewApi = acquireFunc === undefined ? undefined : acquireFunc();
+    }
+    return webviewApi as TypedWebviewApi<State, Message> | undefined;
+}
+/**
+ * API exposed to webviews.
+ *
+ * @template StateType Type of the persisted state stored for the webview.
+ */ export interface WebviewApi<StateType> {
+    /**
+     * Post a message to the owner of the webview.
+     *
+     * @param message Data to post. Must be JSON serializable.
+     */ postMessage(message: unknown): void;
+    /**
+     * Get the persistent state stored for this webview.
+     *
+     * @return The current state or `undefined` if no state has been set.
+     */ getState(): StateType | undefined;
+    /**
+     * Set the persistent state stored for this webview.
+     *
+     * @param newState New persisted state. This must be a JSON serializable object. Can be retrieved
+     * using {@link getState}.
+     *
+     * @return The new state.
+     */ setState<T extends StateType | undefined>(newState: T): T;
+}
@@ -0,0 +1,491 @@
+//! Golden file tests.
+//!
+//! Every `tests/fixtures/<name>.d.ts` is generated with `gen::Generator`
+//! and compared with `tests/fixtures/<name>.ts`.
+//! A first line like `// args: --strict-function-types` turns on the options of the same name,
+//! `--loader=require,inject` selects the loaders
+//! and `--module-name=azdata` (also `--type-name=` and `--import-function=`) the host module.
+//! With `--emit-json` the model is compared with `tests/fixtures/<name>.json` too,
+//! with `--emit-fake` fake.ts with `tests/fixtures/<name>.fake.ts`
+//! with `--emit-rust` the bindings with `tests/fixtures/<name>.rs`
+//! with `--split` the files with `tests/fixtures/<name>.split/<path>`
+//! and with `--npm` the package with `tests/fixtures/<name>.npm/<path>`.
+//!
+//! Run `BLESS=1 cargo test` to write the current output as the expected one.
+
+fn generator<'a>(source: &'a str) -> gen::Generator<'a> {
+    let args = source
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("// args:"))
+        .unwrap_or_default()
+        .split_whitespace();
+    let mut host = gen::HostModule::default();
+    let generator = args.fold(
+        gen::Generator::from_source(source),
+        |generator, arg| match arg {
+            "--strict-function-types" => generator.strict_function_types(true),
+            "--any-to-unknown" => generator.any_to_unknown(true),
+            "--promise-facade" => generator.promise_facade(true),
+            "--trace" => generator.trace(true),
+            "--strip-deprecated" => generator.strip_deprecated(true),
+            "--emit-json" => generator.emit_model(true),
+            "--emit-fake" => generator.fake(true),
+            "--emit-rust" => generator.rust_bindings(true),
+            "--split" => generator.split(true),
+            "--npm" => generator.npm(gen::NpmPackage::default()),
+            _ if arg.starts_with("--loader=") => generator.loaders(
+                arg["--loader=".len()..]
+                    .split(',')
+                    .map(|name| name.parse().unwrap())
+                    .collect(),
+            ),
+            _ => {
+                let (name, value) = arg
+                    .split_once('=')
+                    .unwrap_or_else(|| panic!("unknown fixture option {}", arg));
+                match name {
+                    "--module-name" => host.module_name = value.to_string(),
+                    "--type-name" => host.type_name = value.to_string(),
+                    "--import-function" => host.import_function = value.to_string(),
+                    _ => panic!("unknown fixture option {}", arg),
+                }
+                generator
+            }
+        },
+    );
+    generator.host_module(host)
+}
+
+#[test]
+fn golden_files() {
+    let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let bless = std::env::var_os("BLESS").is_some();
+
+    let mut fixtures = std::fs::read_dir(&fixtures_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.to_string_lossy().ends_with(".d.ts"))
+        .collect::<Vec<_>>();
+    fixtures.sort();
+    assert!(!fixtures.is_empty());
+
+    let mut failures = Vec::<String>::new();
+    for fixture in fixtures {
+        let source = std::fs::read_to_string(&fixture).unwrap();
+        let generated = generator(&source).generate().unwrap();
+
+        let mut outputs = vec![(".ts".to_string(), generated.code)];
+        if let Some(model) = &generated.model {
+            outputs.push((
+                ".json".to_string(),
+                serde_json::to_string_pretty(model).unwrap() + "\n",
+            ));
+        }
+        if let Some(fake) = generated.fake {
+            outputs.push((".fake.ts".to_string(), fake));
+        }
+        if let Some(rust) = generated.rust {
+            outputs.push((".rs".to_string(), rust));
+        }
+        for file in generated.files {
+            outputs.push((format!(".split/{}", file.path), file.code));
+        }
+        for file in generated.npm {
+            outputs.push((format!(".npm/{}", file.path), file.code));
+        }
+        for (extension, actual) in outputs {
+            let expected_path = fixture.with_file_name(
+                fixture
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace(".d.ts", &extension),
+            );
+            if bless {
+                std::fs::create_dir_all(expected_path.parent().unwrap()).unwrap();
+                std::fs::write(&expected_path, &actual).unwrap();
+                continue;
+            }
+            let expected = std::fs::read_to_string(&expected_path).unwrap_or_default();
+            if expected != actual {
+                failures.push(format!(
+                    "{}\n--- expected\n{}\n--- actual\n{}",
+                    expected_path.display(),
+                    expected,
+                    actual
+                ));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "generated code differs from the golden files (run with BLESS=1 to update)\n\n{}",
+        failures.join("\n\n")
+    );
+}
+
+#[test]
+fn diagnostics() {
+    let source = "declare module 'vscode' {\n    export function f(value: any): void;\n}\n";
+    let generated = gen::Generator::from_source(source)
+        .any_to_unknown(true)
+        .generate()
+        .unwrap();
+    assert_eq!(
+        generated.diagnostics,
+        vec![gen::Diagnostic::AnyToUnknown {
+            path: "f.value".to_string()
+        }]
+    );
+}
+
+#[test]
+fn strip_deprecated_reports_removed_declarations() {
+    let source = "declare module 'vscode' {
+    /**
+     * @deprecated Use {@link OpenOptions}.
+     */
+    export interface ShowOptions {
+        column: number;
+    }
+    /**
+     * @deprecated
+     */
+    export interface LegacyOptions {
+        preview: boolean;
+    }
+    export class Panel {
+        show(options: ShowOptions): void;
+        /**
+         * Hides the items which have a `@deprecated` tag.
+         */
+        hideDeprecated(): void;
+        /**
+         * @deprecated Use {@link Panel.show}.
+         */
+        reveal(): void;
+    }
+}
+";
+    let generated = gen::Generator::from_source(source)
+        .strip_deprecated(true)
+        .generate()
+        .unwrap();
+    assert!(generated.code.contains("hideDeprecated"));
+    assert!(generated.code.contains("ShowOptions"));
+    assert_eq!(
+        generated.diagnostics,
+        vec![
+            gen::Diagnostic::StrippedDeprecated {
+                path: "LegacyOptions".to_string()
+            },
+            gen::Diagnostic::StrippedDeprecated {
+                path: "Panel.reveal".to_string()
+            },
+            // kept because Panel.show references it
+            gen::Diagnostic::ExcludedButReferenced {
+                name: "ShowOptions".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn keep_any_adds_to_the_defaults() {
+    let source = "declare module 'vscode' {
+    export function f(value: any): void;
+    export namespace commands {
+        export function registerCommand(command: string, callback: (...args: any[]) => any): void;
+    }
+}
+";
+    let kept = gen::Generator::from_source(source)
+        .any_to_unknown(true)
+        .keep_any(vec!["f".to_string()])
+        .generate()
+        .unwrap();
+    assert_eq!(kept.diagnostics, vec![]);
+
+    let replaced = gen::Generator::from_source(source)
+        .any_to_unknown(true)
+        .keep_any(vec!["f".to_string()])
+        .default_keep_any(false)
+        .generate()
+        .unwrap();
+    assert_eq!(
+        replaced.diagnostics,
+        vec![
+            gen::Diagnostic::AnyToUnknown {
+                path: "commands.registerCommand.callback.args".to_string()
+            },
+            gen::Diagnostic::AnyToUnknown {
+                path: "commands.registerCommand.callback".to_string()
+            }
+        ]
+    );
+}
+
+#[test]
+fn filter_removes_types_only_removed_declarations_reference() {
+    let source = r#"declare module 'vscode' {
+    export class NotebookData {
+        cells: NotebookCellKind[];
+    }
+    export enum NotebookCellKind {
+        Markup = 1,
+        Code = 2
+    }
+    export interface NotebookEditor {
+        readonly data: NotebookData;
+    }
+    export class Position {
+        readonly line: number;
+    }
+    export enum ViewColumn {
+        One = 1
+    }
+    export namespace notebooks {
+        export function open(data: NotebookData): NotebookEditor;
+    }
+}
+"#;
+    let generated = gen::Generator::from_source(source)
+        .filter(gen::Filter::from_toml(r#"exclude = ["notebooks"]"#).unwrap())
+        .generate()
+        .unwrap();
+    assert!(!generated.code.contains("NotebookData"));
+    assert!(!generated.code.contains("NotebookCellKind"));
+    assert!(!generated.code.contains("NotebookEditor"));
+    // nothing references them, so they are kept as values of the API
+    assert!(generated.code.contains("Position"));
+    assert!(generated.code.contains("ViewColumn"));
+}
+
+#[test]
+fn filter_follows_every_merged_declaration() {
+    let source = r#"declare module 'vscode' {
+    export interface QuickPickItem {
+        readonly kind: QuickPickItemKind;
+    }
+    export namespace QuickPickItem {
+        export function create(label: string): QuickPickItem;
+    }
+    export enum QuickPickItemKind {
+        Separator = -1,
+        Default = 0
+    }
+    export namespace notebooks {
+        export function kind(): QuickPickItemKind;
+    }
+    export namespace window {
+        export function showQuickPick(items: QuickPickItem[]): void;
+    }
+}
+"#;
+    let generated = gen::Generator::from_source(source)
+        .filter(gen::Filter::from_toml(r#"exclude = ["notebooks"]"#).unwrap())
+        .generate()
+        .unwrap();
+    assert!(!generated.code.contains("notebooks"));
+    assert!(generated.code.contains("Separator"));
+}
+
+#[test]
+fn overrides() {
+    let source = r#"declare module 'vscode' {
+    export interface Memento {
+        get<T>(key: string): T | undefined;
+    }
+    export namespace window {
+        export function show(message: string): void;
+        export function showLegacy(message: string): void;
+    }
+}
+"#;
+    let overrides = gen::Overrides::from_toml(
+        r#"
+[[override]]
+path = "Memento.get"
+replace = "get<T = unknown>(key: string, defaultValue?: T): T | undefined;"
+
+[[override]]
+path = "window.showLegacy"
+remove = true
+
+[[override]]
+path = "window"
+add = "export function hide(): void;"
+"#,
+    )
+    .unwrap();
+    let generated = gen::Generator::from_source(source)
+        .overrides(overrides)
+        .generate()
+        .unwrap();
+    assert!(generated
+        .code
+        .contains("get<T = unknown>(key: string, defaultValue?: T): T | undefined;"));
+    assert!(generated.code.contains("hide(): void;"));
+    assert!(!generated.code.contains("showLegacy"));
+    assert_eq!(generated.diagnostics, vec![]);
+
+    for (path, action) in [
+        ("window.showRemoved", "remove = true"),
+        (
+            "window.showRemoved",
+            "replace = \"export function showRemoved(): void;\"",
+        ),
+        ("removed", "add = \"export function hide(): void;\""),
+    ] {
+        let missing =
+            gen::Overrides::from_toml(&format!("[[override]]\npath = \"{}\"\n{}\n", path, action))
+                .unwrap();
+        let generated = gen::Generator::from_source(source)
+            .overrides(missing)
+            .generate()
+            .unwrap();
+        assert!(generated.code.contains("showLegacy"));
+        assert_eq!(
+            generated.diagnostics,
+            vec![gen::Diagnostic::OverrideTargetNotFound {
+                path: path.to_string()
+            }]
+        );
+    }
+    assert!(matches!(
+        gen::Overrides::from_toml("[[override]]\npath = \"window.showLegacy\"\nremove = false\n"),
+        Err(gen::Error::InvalidOverrides(_))
+    ));
+
+    let dangling = gen::Overrides::from_toml(
+        r#"
+[[override]]
+path = "Memento"
+add = "keys(): Missing[];"
+"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        gen::Generator::from_source(source)
+            .overrides(dangling)
+            .generate(),
+        Err(gen::Error::DanglingTypeReferences(_))
+    ));
+}
+
+#[test]
+fn qualified_names_resolve_every_segment() {
+    for (reference, dangling) in [
+        (
+            "readonly kind: TextDocument.Missing;",
+            "TextDocument.Missing",
+        ),
+        ("readonly column: ViewColumn.One;", "ViewColumn.One"),
+    ] {
+        let source = format!(
+            "declare module 'vscode' {{
+    export enum ViewColumn {{
+        One = 1
+    }}
+    export interface TextDocument {{
+        {}
+    }}
+}}
+",
+            reference
+        );
+        match gen::Generator::from_source(&source).generate() {
+            Err(gen::Error::DanglingTypeReferences(message)) => {
+                assert!(message.starts_with(dangling), "{}", message)
+            }
+            _ => panic!("{} is not reported", dangling),
+        }
+    }
+
+    let source = "declare module 'vscode' {
+    export interface Base {
+    }
+    export interface Derived extends missing.Base {
+    }
+}
+";
+    assert!(matches!(
+        gen::Generator::from_source(source).generate(),
+        Err(gen::Error::DanglingTypeReferences(message)) if message.starts_with("missing.Base")
+    ));
+}
+
+#[test]
+fn infer_names_are_in_scope_of_the_true_branch_only() {
+    let source = |alias: &str| {
+        format!(
+            "declare module 'vscode' {{
+    export interface Thenable<T> {{
+        then(onfulfilled: (value: T) => void): void;
+    }}
+    export type Unwrapped<T> = {};
+}}
+",
+            alias
+        )
+    };
+    assert!(gen::Generator::from_source(&source(
+        "T extends Thenable<infer U> ? (U extends Thenable<infer V> ? V : U) : T"
+    ))
+    .generate()
+    .is_ok());
+    for alias in [
+        "T extends Thenable<infer U> ? U : U",
+        "U extends Thenable<infer U> ? U : T",
+    ] {
+        assert!(
+            matches!(
+                gen::Generator::from_source(&source(alias)).generate(),
+                Err(gen::Error::DanglingTypeReferences(message)) if message.starts_with("U ")
+            ),
+            "{}",
+            alias
+        );
+    }
+}
+
+#[test]
+fn empty_source() {
+    assert!(matches!(
+        gen::Generator::from_source("").generate(),
+        Err(gen::Error::AmbientModuleNotFound(name)) if name == "vscode"
+    ));
+}
+
+#[test]
+fn missing_host_module() {
+    let source = "declare module 'vscode' {\n    export const version: string;\n}\n";
+    assert!(matches!(
+        gen::Generator::from_source(source)
+            .host_module(gen::HostModule {
+                module_name: "azdata".to_string(),
+                ..gen::HostModule::default()
+            })
+            .generate(),
+        Err(gen::Error::AmbientModuleNotFound(name)) if name == "azdata"
+    ));
+}
+
+/// The Rust bindings compile against wasm-bindgen and js-sys.
+/// Ignored by default as it downloads them: `cargo test -- --ignored`
+#[test]
+#[ignore]
+fn rust_bindings_compile() {
+    let status = std::process::Command::new(env!("CARGO"))
+        .args(["check", "--quiet", "--manifest-path"])
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/rust_bindings/Cargo.toml"
+        ))
+        .arg("--target-dir")
+        .arg(env!("CARGO_TARGET_TMPDIR"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
@@ -0,0 +1,172 @@
+const SOURCE: &str = r#"import * as vscode from "vscode";
+import { window, type TextEditor } from "vscode";
+import { helper } from "./helper";
+
+export function activate(context: vscode.ExtensionContext) {
+  const editor: TextEditor | undefined = window.activeTextEditor;
+  const commands: typeof vscode.commands = vscode.commands;
+  helper({ window });
+}
+
+class Provider implements vscode.TreeDataProvider<string> {
+  getTreeItem(element: string): vscode.TreeItem {
+    return new vscode.TreeItem(element);
+  }
+}
+
+export { window };
+"#;
+
+const EXPECTED: &str = r#"import { type ExtensionContext, type TextEditor, type VSCodeAPI, type TreeDataProvider, type TreeItem, importVsCodeApiOrThrow } from "jsr:@narumincho/vscode";
+import { helper } from "./helper";
+
+const vscode = importVsCodeApiOrThrow();
+
+export function activate(context: ExtensionContext) {
+  const editor: TextEditor | undefined = vscode.window.activeTextEditor;
+  const commands: VSCodeAPI["commands"] = vscode.commands;
+  helper({ window: vscode.window });
+}
+
+class Provider implements TreeDataProvider<string> {
+  getTreeItem(element: string): TreeItem {
+    return new vscode.TreeItem(element);
+  }
+}
+
+export { window };
+"#;
+
+#[test]
+fn migrate() {
+    let migration = gen::migrate::migrate(
+        "extension.ts",
+        SOURCE,
+        gen::migrate::DEFAULT_MODULE_SPECIFIER,
+        &gen::HostModule::default(),
+    )
+    .unwrap();
+    assert_eq!(migration.code, EXPECTED);
+    assert_eq!(
+        migration.notes,
+        vec![gen::migrate::Note {
+            line: 17,
+            column: 10,
+            message: "export of window imported from \"vscode\"".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn types_only() {
+    let migration = gen::migrate::migrate(
+        "types.ts",
+        "import type * as vscode from \"vscode\";\n\nexport type Item = vscode.QuickPickItem & { id: string };\n",
+        "https://deno.land/x/vscode/mod.ts",
+        &gen::HostModule::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        migration.code,
+        "import { type QuickPickItem } from \"https://deno.land/x/vscode/mod.ts\";\n\nexport type Item = QuickPickItem & { id: string };\n"
+    );
+    assert!(migration.notes.is_empty());
+}
+
+#[test]
+fn shadowed_imports() {
+    let migration = gen::migrate::migrate(
+        "shadow.ts",
+        r#"import { window, TextEditor } from "vscode";
+
+function show(window: string) {
+  console.log(window);
+}
+
+function first<TextEditor>(editors: TextEditor[]): TextEditor {
+  const { window: _, ...rest } = { window };
+  return editors[0];
+}
+
+for (const window of [1]) {
+  console.log(window);
+}
+
+export const editor: TextEditor | undefined = window.activeTextEditor;
+"#,
+        gen::migrate::DEFAULT_MODULE_SPECIFIER,
+        &gen::HostModule::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        migration.code,
+        r#"import { type TextEditor, importVsCodeApiOrThrow } from "jsr:@narumincho/vscode";
+
+const vscode = importVsCodeApiOrThrow();
+
+function show(window: string) {
+  console.log(window);
+}
+
+function first<TextEditor>(editors: TextEditor[]): TextEditor {
+  const { window: _, ...rest } = { window: vscode.window };
+  return editors[0];
+}
+
+for (const window of [1]) {
+  console.log(window);
+}
+
+export const editor: TextEditor | undefined = vscode.window.activeTextEditor;
+"#
+    );
+    assert!(migration.notes.is_empty());
+}
+
+#[test]
+fn require() {
+    let migration = gen::migrate::migrate(
+        "require.ts",
+        "const vscode = require(\"vscode\");\nconst lazy = import(\"vscode\");\n",
+        gen::migrate::DEFAULT_MODULE_SPECIFIER,
+        &gen::HostModule::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        migration.notes,
+        vec![
+            gen::migrate::Note {
+                line: 1,
+                column: 16,
+                message: "require(\"vscode\"): use importVsCodeApiOrThrow()".to_string(),
+            },
+            gen::migrate::Note {
+                line: 2,
+                column: 14,
+                message: "dynamic import of \"vscode\": use importVsCodeApiOrThrow()".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn host_module() {
+    let host = gen::HostModule {
+        module_name: "azdata".to_string(),
+        type_name: "AzdataAPI".to_string(),
+        import_function: "importAzdataApi".to_string(),
+        ..gen::HostModule::default()
+    };
+    let migration = gen::migrate::migrate(
+        "dashboard.ts",
+        "import * as azdata from \"azdata\";\nimport * as vscode from \"vscode\";\n\nexport const kind: typeof azdata.connection = azdata.connection;\n",
+        "./azdata.ts",
+        &host,
+    )
+    .unwrap();
+    assert_eq!(
+        migration.code,
+        "import { type AzdataAPI, importAzdataApiOrThrow } from \"./azdata.ts\";\nimport * as vscode from \"vscode\";\n\nconst azdata = importAzdataApiOrThrow();\n\nexport const kind: AzdataAPI[\"connection\"] = azdata.connection;\n"
+    );
+    assert!(migration.notes.is_empty());
+}
@@ -0,0 +1,55 @@
+//! `tests/fixtures/protocol/messages.ts` is generated with `gen::protocol::generate`
+//! and compared with `tests/fixtures/protocol/protocol.ts`.
+//!
+//! Run `BLESS=1 cargo test` to write the current output as the expected one.
+
+#[test]
+fn golden_file() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/protocol");
+    let actual = gen::protocol::generate(
+        "messages.ts",
+        &std::fs::read_to_string(dir.join("messages.ts")).unwrap(),
+        &gen::protocol::Options::default(),
+    )
+    .unwrap();
+    if std::env::var_os("BLESS").is_some() {
+        std::fs::write(dir.join("protocol.ts"), &actual).unwrap();
+        return;
+    }
+    assert_eq!(
+        std::fs::read_to_string(dir.join("protocol.ts")).unwrap_or_default(),
+        actual,
+        "run with BLESS=1 to update"
+    );
+}
+
+fn error(source: &str) -> String {
+    match gen::protocol::generate("messages.ts", source, &gen::protocol::Options::default()) {
+        Err(gen::Error::InvalidProtocol(message)) => message,
+        other => panic!("expected InvalidProtocol, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn invalid_unions() {
+    assert_eq!(
+        error("export type ExtensionToWebview = { type: \"a\" };\n"),
+        "messages.ts: no type alias WebviewToExtension"
+    );
+    assert_eq!(
+        error(
+            "export type ExtensionToWebview = { type: \"a\" };
+export type WebviewToExtension = { type: \"a\" } | { type: string };
+"
+        ),
+        "messages.ts:2:50: a member of WebviewToExtension without a literal `type`"
+    );
+    assert_eq!(
+        error(
+            "export type ExtensionToWebview = { type: \"a\" } | { type: \"a\"; text: string };
+export type WebviewToExtension = { type: \"a\" };
+"
+        ),
+        "messages.ts:1:50: ExtensionToWebview has `type` \"a\" twice"
+    );
+}
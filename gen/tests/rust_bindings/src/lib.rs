@@ -0,0 +1,5 @@
+//! Compiles tests/fixtures/rust_bindings.rs, the output of `--emit-rust`, against wasm-bindgen and js-sys.
+//! `#[path]` instead of `include!`, as the fixture has inner doc comments
+
+#[path = "../../fixtures/rust_bindings.rs"]
+pub mod bindings;
@@ -0,0 +1,59 @@
+const V1_75: &str = r#"declare module 'vscode' {
+    export interface Memento {
+        get<T>(key: string): T | undefined;
+    }
+}
+"#;
+
+const V1_77: &str = r#"declare module 'vscode' {
+    export interface Memento {
+        get<T>(key: string): T | undefined;
+        /**
+         * The stored keys.
+         */
+        keys(): readonly string[];
+    }
+    export class Range {
+        constructor(start: number, end: number);
+        readonly start: number;
+    }
+}
+"#;
+
+#[test]
+fn since() {
+    let history = gen::since::History::from_sources(
+        &[
+            ("1.77.0".to_string(), V1_77.to_string()),
+            ("1.75.2".to_string(), V1_75.to_string()),
+        ],
+        &gen::HostModule::default(),
+    )
+    .unwrap();
+    assert_eq!(history.versions, vec!["1.75", "1.77"]);
+    assert_eq!(history.since("Memento"), None);
+    assert_eq!(history.since("Memento.keys"), Some("1.77"));
+    assert_eq!(history.since("Range.start"), Some("1.77"));
+
+    let code = gen::Generator::from_source(V1_77)
+        .history(history)
+        .generate()
+        .unwrap()
+        .code;
+    assert!(code.contains(
+        "* The stored keys.\n         * @since 1.77\n         */ keys(): readonly string[];"
+    ));
+    // members of a declaration new in the same version are not annotated
+    assert_eq!(code.matches("@since").count(), 3);
+}
+
+#[test]
+fn invalid_version() {
+    assert!(matches!(
+        gen::since::History::from_sources(
+            &[("latest".to_string(), V1_75.to_string())],
+            &gen::HostModule::default()
+        ),
+        Err(gen::Error::InvalidVersion(_))
+    ));
+}
@@ -0,0 +1,28 @@
+//! `tests/fixtures/webview/index.d.ts` is generated with `gen::webview::generate`
+//! and compared with `tests/fixtures/webview/webview.ts`.
+//!
+//! Run `BLESS=1 cargo test` to write the current output as the expected one.
+
+#[test]
+fn golden_file() {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/webview");
+    let actual =
+        gen::webview::generate(&std::fs::read_to_string(dir.join("index.d.ts")).unwrap()).unwrap();
+    if std::env::var_os("BLESS").is_some() {
+        std::fs::write(dir.join("webview.ts"), &actual).unwrap();
+        return;
+    }
+    assert_eq!(
+        std::fs::read_to_string(dir.join("webview.ts")).unwrap_or_default(),
+        actual,
+        "run with BLESS=1 to update"
+    );
+}
+
+#[test]
+fn missing_acquire() {
+    assert!(matches!(
+        gen::webview::generate("export interface WebviewApi<StateType> {}\n"),
+        Err(gen::Error::WebviewDeclarationNotFound("acquireVsCodeApi"))
+    ));
+}